@@ -0,0 +1,309 @@
+use alloc::alloc::{alloc, dealloc, realloc, handle_alloc_error, Layout};
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+use core::marker::PhantomData;
+use crate::float::single::*;
+use crate::float::double::*;
+use crate::int::*;
+
+/// SIMD vector that can be viewed from a buffer of its lanes.
+///
+/// # Safety
+/// The vector must be valid for any bit pattern of its lanes, and, when its size matches ```LANES``` lanes, it must be laid out as an array of them
+pub unsafe trait Vector: Copy {
+    /// Type of every lane
+    type Lane: Copy;
+
+    /// Number of lanes
+    const LANES: usize;
+}
+
+macro_rules! impl_vector {
+    ($($ty:ident => [$($target:ident: $len:literal),+]),+) => {
+        $(
+            $(
+                unsafe impl Vector for $target {
+                    type Lane = $ty;
+                    const LANES: usize = $len;
+                }
+            )+
+        )+
+    };
+}
+
+impl_vector!(
+    f32 => [f32x2: 2, f32x3: 3, f32x4: 4, f32x6: 6, f32x8: 8, f32x10: 10, f32x12: 12, f32x14: 14, f32x16: 16, f32x24: 24, f32x32: 32],
+    f64 => [f64x2: 2, f64x3: 3, f64x4: 4, f64x6: 6, f64x8: 8, f64x10: 10, f64x12: 12, f64x14: 14, f64x16: 16, f64x24: 24, f64x32: 32],
+    i8 => [i8x16: 16, i8x32: 32],
+    u8 => [u8x16: 16, u8x32: 32],
+    i16 => [i16x8: 8, i16x16: 16],
+    u16 => [u16x8: 8, u16x16: 16],
+    i32 => [i32x4: 4, i32x8: 8],
+    u32 => [u32x4: 4, u32x8: 8],
+    i64 => [i64x2: 2, i64x4: 4, i64x8: 8, i64x16: 16],
+    u64 => [u64x2: 2, u64x4: 4, u64x8: 8, u64x16: 16]
+);
+
+/// Growable buffer whose memory is always aligned to ```ALIGN``` bytes, so that it can be read and written in whole aligned vectors
+pub struct AlignedVec<T, const ALIGN: usize> {
+    ptr: NonNull<T>,
+    len: usize,
+    cap: usize,
+    _phtm: PhantomData<T>
+}
+
+impl<T, const ALIGN: usize> AlignedVec<T, ALIGN> {
+    /// Creates a new, empty buffer. No memory is allocated until values are pushed into it
+    #[inline]
+    pub fn new () -> Self {
+        assert!(ALIGN.is_power_of_two(), "alignment must be a power of two");
+        assert!(ALIGN >= core::mem::align_of::<T>(), "alignment must be at least the alignment of the type");
+        assert!(core::mem::size_of::<T>() != 0, "zero-sized types aren't supported");
+
+        Self {
+            ptr: unsafe { NonNull::new_unchecked(ALIGN as *mut T) },
+            len: 0,
+            cap: 0,
+            _phtm: PhantomData
+        }
+    }
+
+    /// Creates a new, empty buffer with room for at least `capacity` values
+    #[inline]
+    pub fn with_capacity (capacity: usize) -> Self {
+        let mut this = Self::new();
+        this.reserve(capacity);
+        this
+    }
+
+    /// Returns the number of values in the buffer
+    #[inline(always)]
+    pub fn len (&self) -> usize {
+        self.len
+    }
+
+    /// Returns the number of values the buffer can hold without reallocating
+    #[inline(always)]
+    pub fn capacity (&self) -> usize {
+        self.cap
+    }
+
+    /// Returns `true` if the buffer has no values
+    #[inline(always)]
+    pub fn is_empty (&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a pointer to the start of the buffer, aligned to ```ALIGN``` bytes
+    #[inline(always)]
+    pub fn as_ptr (&self) -> *const T {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns a mutable pointer to the start of the buffer, aligned to ```ALIGN``` bytes
+    #[inline(always)]
+    pub fn as_mut_ptr (&mut self) -> *mut T {
+        self.ptr.as_ptr()
+    }
+
+    /// Returns the values of the buffer as a slice
+    #[inline(always)]
+    pub fn as_slice (&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len) }
+    }
+
+    /// Returns the values of the buffer as a mutable slice
+    #[inline(always)]
+    pub fn as_mut_slice (&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+    }
+
+    /// Reserves room for at least `additional` more values
+    pub fn reserve (&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required <= self.cap {
+            return
+        }
+
+        let cap = required.max(2 * self.cap).max(4);
+        let layout = Self::layout(cap);
+
+        let ptr = unsafe {
+            if self.cap == 0 {
+                alloc(layout)
+            } else {
+                realloc(self.ptr.as_ptr().cast(), Self::layout(self.cap), layout.size())
+            }
+        };
+
+        match NonNull::new(ptr.cast()) {
+            Some(ptr) => self.ptr = ptr,
+            None => handle_alloc_error(layout)
+        }
+
+        self.cap = cap;
+    }
+
+    /// Appends a value at the end of the buffer
+    #[inline]
+    pub fn push (&mut self, value: T) {
+        if self.len == self.cap {
+            self.reserve(1);
+        }
+
+        unsafe { self.as_mut_ptr().add(self.len).write(value) }
+        self.len += 1;
+    }
+
+    /// Removes the last value of the buffer and returns it, or `None` if it's empty
+    #[inline]
+    pub fn pop (&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None
+        }
+
+        self.len -= 1;
+        unsafe { Some(self.as_ptr().add(self.len).read()) }
+    }
+
+    /// Removes every value of the buffer, keeping its capacity
+    #[inline]
+    pub fn clear (&mut self) {
+        let values = self.as_mut_slice() as *mut [T];
+        self.len = 0;
+        unsafe { core::ptr::drop_in_place(values) }
+    }
+
+    /// Appends every value of the slice at the end of the buffer
+    pub fn extend_from_slice (&mut self, values: &[T]) where T: Clone {
+        self.reserve(values.len());
+        for value in values {
+            self.push(value.clone())
+        }
+    }
+
+    /// Returns the buffer as a slice of vectors, followed by the values that don't fill a whole vector
+    #[inline]
+    pub fn as_vectors<V: Vector<Lane = T>> (&self) -> (&[V], &[T]) {
+        Self::check_vector::<V>();
+        let chunks = self.len / V::LANES;
+
+        unsafe {
+            (
+                core::slice::from_raw_parts(self.as_ptr().cast(), chunks),
+                core::slice::from_raw_parts(self.as_ptr().add(chunks * V::LANES), self.len % V::LANES)
+            )
+        }
+    }
+
+    /// Returns the buffer as a mutable slice of vectors, followed by the values that don't fill a whole vector
+    #[inline]
+    pub fn as_vectors_mut<V: Vector<Lane = T>> (&mut self) -> (&mut [V], &mut [T]) {
+        Self::check_vector::<V>();
+        let chunks = self.len / V::LANES;
+        let ptr = self.as_mut_ptr();
+
+        unsafe {
+            (
+                core::slice::from_raw_parts_mut(ptr.cast(), chunks),
+                core::slice::from_raw_parts_mut(ptr.add(chunks * V::LANES), self.len % V::LANES)
+            )
+        }
+    }
+
+    #[inline(always)]
+    fn check_vector<V: Vector<Lane = T>> () {
+        assert!(ALIGN >= core::mem::align_of::<V>(), "buffer alignment is smaller than the alignment of the vector");
+        assert_eq!(core::mem::size_of::<V>(), V::LANES * core::mem::size_of::<T>(), "vector has padding lanes");
+    }
+
+    #[inline(always)]
+    fn layout (cap: usize) -> Layout {
+        let size = cap.checked_mul(core::mem::size_of::<T>()).expect("capacity overflow");
+        Layout::from_size_align(size, ALIGN).expect("capacity overflow")
+    }
+}
+
+impl<T, const ALIGN: usize> Drop for AlignedVec<T, ALIGN> {
+    fn drop (&mut self) {
+        self.clear();
+        if self.cap != 0 {
+            unsafe { dealloc(self.ptr.as_ptr().cast(), Self::layout(self.cap)) }
+        }
+    }
+}
+
+impl<T, const ALIGN: usize> Deref for AlignedVec<T, ALIGN> {
+    type Target = [T];
+
+    #[inline(always)]
+    fn deref (&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const ALIGN: usize> DerefMut for AlignedVec<T, ALIGN> {
+    #[inline(always)]
+    fn deref_mut (&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const ALIGN: usize> Default for AlignedVec<T, ALIGN> {
+    #[inline(always)]
+    fn default () -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const ALIGN: usize> Clone for AlignedVec<T, ALIGN> {
+    #[inline]
+    fn clone (&self) -> Self {
+        Self::from(self.as_slice())
+    }
+}
+
+impl<T: core::fmt::Debug, const ALIGN: usize> core::fmt::Debug for AlignedVec<T, ALIGN> {
+    fn fmt (&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const ALIGN: usize> PartialEq for AlignedVec<T, ALIGN> {
+    #[inline(always)]
+    fn eq (&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Clone, const ALIGN: usize> From<&[T]> for AlignedVec<T, ALIGN> {
+    #[inline]
+    fn from (values: &[T]) -> Self {
+        let mut this = Self::new();
+        this.extend_from_slice(values);
+        this
+    }
+}
+
+impl<T, const ALIGN: usize> Extend<T> for AlignedVec<T, ALIGN> {
+    fn extend<I: IntoIterator<Item = T>> (&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for value in iter {
+            self.push(value)
+        }
+    }
+}
+
+impl<T, const ALIGN: usize> FromIterator<T> for AlignedVec<T, ALIGN> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>> (iter: I) -> Self {
+        let mut this = Self::new();
+        this.extend(iter);
+        this
+    }
+}
+
+unsafe impl<T: Send, const ALIGN: usize> Send for AlignedVec<T, ALIGN> {}
+unsafe impl<T: Sync, const ALIGN: usize> Sync for AlignedVec<T, ALIGN> {}
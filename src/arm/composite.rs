@@ -7,6 +7,12 @@ use super::*;
 impl_composite!(
     (f32x4 => 4, f32x2 => 2) as f32x6: f32,
     (f32x4 => 4, f32x4 => 4) as f32x8: f32,
+    (f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x10: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x12: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x14: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x16: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x24: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x32: f32,
 
     (f64x2 => 2, f64x2 => 2) as f64x4: f64,
     (f64x4 => 4, f64x2 => 2) as f64x6: f64,
@@ -14,15 +20,21 @@ impl_composite!(
     (f64x6 => 6, f64x4 => 4) as f64x10: f64,
     (f64x6 => 6, f64x6 => 6) as f64x12: f64,
     (f64x8 => 8, f64x6 => 6) as f64x14: f64,
-    (f64x8 => 8, f64x8 => 8) as f64x16: f64
+    (f64x8 => 8, f64x8 => 8) as f64x16: f64,
+    (f64x8 => 8, f64x8 => 8, f64x8 => 8) as f64x24: f64,
+    (f64x8 => 8, f64x8 => 8, f64x8 => 8, f64x8 => 8) as f64x32: f64
 );
 
-impl_composite!(
-    (f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x10: f32,
-    (f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x12: f32
+impl_padded!(
+    f32x4 as f32x3: f32,
+    f64x4 as f64x3: f64
 );
 
-impl_composite!(
-    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x14: f32,
-    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x16: f32
-);
\ No newline at end of file
+impl_xor_lanes!(
+    [f32x4 => 4; 2] as f32x8,
+    [f32x4 => 4; 4] as f32x16,
+
+    [f64x2 => 2; 2] as f64x4,
+    [f64x4 => 4; 2] as f64x8,
+    [f64x8 => 8; 2] as f64x16
+);
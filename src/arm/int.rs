@@ -0,0 +1,397 @@
+use core::ops::*;
+use llml_simd_proc::*;
+use core::mem::transmute;
+use crate::float::single::{f32x4, f32x8};
+use crate::float::double::{f64x2, f64x4, f64x8, f64x16};
+arch_use!();
+
+macro_rules! impl_int {
+    (@arith $target:ident, $ty:ident, $suffix:ident, $($trait:ident, $fun:ident => $op:ident),+) => {
+        $(
+            impl $trait for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $fun (self, rhs: Self) -> Self::Output {
+                    unsafe { Self(concat_idents!($op, _, $suffix)(self.0, rhs.0)) }
+                }
+            }
+
+            impl_scal_arith!($target, $ty, $trait, $fun);
+        )*
+    };
+
+    (@other $suffix:ident, $($fun:ident => $op:ident: $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a vector with the ", $docs, " of each lane")]
+            #[inline(always)]
+            pub fn $fun (self, rhs: Self) -> Self {
+                unsafe { Self(concat_idents!($op, _, $suffix)(self.0, rhs.0)) }
+            }
+        )*
+    };
+
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`,
+    // by extracting the bytes of `fill` followed by those of the vector itself
+    (@shift_up $ty:ident) => {
+        #[inline(always)]
+        pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+            unsafe {
+                let (v, fill) : (uint8x16_t, uint8x16_t) = (transmute(self.0), transmute(fill.0));
+                Self(transmute(match lanes * core::mem::size_of::<$ty>() {
+                    1 => vextq_u8::<15>(fill, v),
+                    2 => vextq_u8::<14>(fill, v),
+                    4 => vextq_u8::<12>(fill, v),
+                    8 => vextq_u8::<8>(fill, v),
+                    _ => unreachable!()
+                }))
+            }
+        }
+    };
+
+    ($([$ty:ident;$len:literal] as $target:ident: $og:ident as $suffix:ident),+) => {
+        $(
+            #[allow(non_camel_case_types)]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Assign)]
+            #[assign_targets(Add, Sub, BitAnd, BitOr, BitXor)]
+            #[assign_rhs(Self, $ty)]
+            pub struct $target(pub(crate) $og);
+            impl_int!(
+                @arith $target, $ty, $suffix,
+                Add, add => vaddq,
+                Sub, sub => vsubq,
+                BitAnd, bitand => vandq,
+                BitOr, bitor => vorrq,
+                BitXor, bitxor => veorq
+            );
+
+            impl Not for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn not (self) -> Self::Output {
+                    unsafe { Self(concat_idents!(vmvnq, _, $suffix)(self.0)) }
+                }
+            }
+
+            impl $target {
+                /// Loads values from the pointer into the SIMD vector
+                #[inline(always)]
+                pub unsafe fn load (ptr: *const $ty) -> Self {
+                    Self(concat_idents!(vld1q, _, $suffix)(ptr))
+                }
+
+                impl_int!(
+                    @other $suffix,
+                    vmin => vminq: "smallest/minimum value",
+                    vmax => vmaxq: "biggest/maximum value"
+                );
+
+                impl_int!(@shift_up $ty);
+                impl_scan!(@int $ty, $len);
+            }
+
+            impl From<$ty> for $target {
+                #[inline(always)]
+                fn from (x: $ty) -> Self {
+                    unsafe { Self(concat_idents!(vdupq_n, _, $suffix)(x)) }
+                }
+            }
+        )*
+    };
+
+    (@small $($target:ident => $wide:ident: $suffix:ident, $shrn:ident::<$bits:literal>),+) => {
+        $(
+            impl $target {
+                impl_int!(
+                    @other $suffix,
+                    saturating_add => vqaddq: "saturating addition",
+                    saturating_sub => vqsubq: "saturating subtraction",
+                    avg => vrhaddq: "rounded up average"
+                );
+
+                /// Returns a vector with the high half of the widened product of each lane
+                #[inline(always)]
+                pub fn mulhi (self, rhs: Self) -> Self {
+                    unsafe {
+                        let lo = concat_idents!(vmull, _, $suffix)(concat_idents!(vget_low, _, $suffix)(self.0), concat_idents!(vget_low, _, $suffix)(rhs.0));
+                        let hi = concat_idents!(vmull, _, $suffix)(concat_idents!(vget_high, _, $suffix)(self.0), concat_idents!(vget_high, _, $suffix)(rhs.0));
+                        Self(concat_idents!(vcombine, _, $suffix)($shrn::<$bits>(lo), $shrn::<$bits>(hi)))
+                    }
+                }
+
+                /// Widens the lanes into two vectors, with the lower and upper halves of the original vector respectively
+                #[inline(always)]
+                pub fn widen (self) -> ($wide, $wide) {
+                    unsafe {
+                        (
+                            $wide(concat_idents!(vmovl, _, $suffix)(concat_idents!(vget_low, _, $suffix)(self.0))),
+                            $wide(concat_idents!(vmovl, _, $suffix)(concat_idents!(vget_high, _, $suffix)(self.0)))
+                        )
+                    }
+                }
+            }
+        )*
+    };
+
+    (@narrow $($target:ident => $narrow:ident: $suffix:ident as $nsuffix:ident),+) => {
+        $(
+            impl $target {
+                /// Narrows the lanes of both vectors into one, saturating the values that don't fit
+                #[inline(always)]
+                pub fn narrow (self, rhs: Self) -> $narrow {
+                    unsafe {
+                        let lo = concat_idents!(vqmovn, _, $suffix)(self.0);
+                        let hi = concat_idents!(vqmovn, _, $suffix)(rhs.0);
+                        $narrow(concat_idents!(vcombine, _, $nsuffix)(lo, hi))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(
+    [i8;16] as i8x16: int8x16_t as s8,
+    [u8;16] as u8x16: uint8x16_t as u8,
+    [i16;8] as i16x8: int16x8_t as s16,
+    [u16;8] as u16x8: uint16x8_t as u16,
+    [i32;4] as i32x4: int32x4_t as s32,
+    [u32;4] as u32x4: uint32x4_t as u32
+);
+
+impl_int!(
+    @small
+    i8x16 => i16x8: s8, vshrn_n_s16::<8>,
+    u8x16 => u16x8: u8, vshrn_n_u16::<8>,
+    i16x8 => i32x4: s16, vshrn_n_s32::<16>,
+    u16x8 => u32x4: u16, vshrn_n_u32::<16>
+);
+
+impl_int!(
+    @narrow
+    i16x8 => i8x16: s16 as s8,
+    u16x8 => u8x16: u16 as u8,
+    i32x4 => i16x8: s32 as s16,
+    u32x4 => u16x8: u32 as u16
+);
+
+impl i32x4 {
+    /// Converts every lane into a single-precision float
+    #[inline(always)]
+    pub fn to_f32 (self) -> f32x4 {
+        unsafe { f32x4(vcvtq_f32_s32(self.0)) }
+    }
+}
+
+impl u32x4 {
+    /// Converts every lane into a single-precision float
+    #[inline(always)]
+    pub fn to_f32 (self) -> f32x4 {
+        unsafe { f32x4(vcvtq_f32_u32(self.0)) }
+    }
+}
+
+impl_int_composite!(
+    i8x16 as i8x32: i8,
+    u8x16 as u8x32: u8,
+    i16x8 as i16x16: i16,
+    u16x8 as u16x16: u16,
+    i32x4 as i32x8: i32,
+    u32x4 as u32x8: u32
+);
+
+impl_int_composite!(
+    @small
+    i8x32 => i16x16,
+    u8x32 => u16x16,
+    i16x16 => i32x8,
+    u16x16 => u32x8
+);
+
+impl_int_composite!(
+    @narrow
+    i16x16 => i8x32,
+    u16x16 => u8x32,
+    i32x8 => i16x16,
+    u32x8 => u16x16
+);
+
+impl_int_composite!(
+    @float
+    i32x8 => to_f32 -> f32x8: f32, "single-precision",
+    u32x8 => to_f32 -> f32x8: f32, "single-precision"
+);
+
+macro_rules! impl_int64 {
+    (@cmp $suffix:ident, $($fun:ident => $op:ident: $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a mask with every bit set on the lanes where `self` is ", $docs, " `rhs`, and cleared otherwise")]
+            #[inline(always)]
+            pub fn $fun (self, rhs: Self) -> Self {
+                unsafe { Self(transmute(concat_idents!($op, _, $suffix)(self.0, rhs.0))) }
+            }
+        )*
+    };
+
+    ($([$ty:ident;$len:literal] as $target:ident => $float:ident: $og:ident as $suffix:ident),+) => {
+        $(
+            #[allow(non_camel_case_types)]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Assign)]
+            #[assign_targets(Add, Sub, BitAnd, BitOr, BitXor)]
+            #[assign_rhs(Self, $ty)]
+            pub struct $target(pub(crate) $og);
+            impl_int!(
+                @arith $target, $ty, $suffix,
+                Add, add => vaddq,
+                Sub, sub => vsubq,
+                BitAnd, bitand => vandq,
+                BitOr, bitor => vorrq,
+                BitXor, bitxor => veorq
+            );
+
+            impl Not for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn not (self) -> Self::Output {
+                    self ^ !(0 as $ty)
+                }
+            }
+
+            impl Mul for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul (self, rhs: Self) -> Self::Output {
+                    // there's no 64-bit multiplication, so it's done lane by lane
+                    let (alpha, beta) = (self.into_array(), rhs.into_array());
+                    Self::from([alpha[0].wrapping_mul(beta[0]), alpha[1].wrapping_mul(beta[1])])
+                }
+            }
+
+            impl_scal_arith!($target, $ty, Mul, mul);
+
+            impl Shl<u32> for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shl (self, rhs: u32) -> Self::Output {
+                    unsafe { Self(concat_idents!(vshlq, _, $suffix)(self.0, vdupq_n_s64((rhs & 63) as i64))) }
+                }
+            }
+
+            impl Shr<u32> for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shr (self, rhs: u32) -> Self::Output {
+                    // negative amounts shift to the right, arithmetically for signed lanes
+                    unsafe { Self(concat_idents!(vshlq, _, $suffix)(self.0, vdupq_n_s64(-((rhs & 63) as i64)))) }
+                }
+            }
+
+            impl Shl for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shl (self, rhs: Self) -> Self::Output {
+                    unsafe { Self(concat_idents!(vshlq, _, $suffix)(self.0, transmute((rhs & 63).0))) }
+                }
+            }
+
+            impl Shr for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shr (self, rhs: Self) -> Self::Output {
+                    unsafe { Self(concat_idents!(vshlq, _, $suffix)(self.0, vnegq_s64(transmute((rhs & 63).0)))) }
+                }
+            }
+
+            impl $target {
+                /// Loads values from the pointer into the SIMD vector
+                #[inline(always)]
+                pub unsafe fn load (ptr: *const $ty) -> Self {
+                    Self(concat_idents!(vld1q, _, $suffix)(ptr))
+                }
+
+                impl_int!(@shift_up $ty);
+                impl_scan!(@int $ty, $len);
+                impl_scan!(@prod);
+
+                impl_int64!(
+                    @cmp $suffix,
+                    veq => vceqq: "equal to",
+                    vlt => vcltq: "less than",
+                    vle => vcleq: "less than or equal to",
+                    vgt => vcgtq: "greater than",
+                    vge => vcgeq: "greater than or equal to"
+                );
+
+                /// Returns a mask with every bit set on the lanes where `self` is not equal to `rhs`, and cleared otherwise
+                #[inline(always)]
+                pub fn vne (self, rhs: Self) -> Self {
+                    !self.veq(rhs)
+                }
+
+                /// Returns a vector with the smallest/minimum value of each lane
+                #[inline(always)]
+                pub fn vmin (self, rhs: Self) -> Self {
+                    unsafe { Self(concat_idents!(vbslq, _, $suffix)(concat_idents!(vcltq, _, $suffix)(self.0, rhs.0), self.0, rhs.0)) }
+                }
+
+                /// Returns a vector with the biggest/maximum value of each lane
+                #[inline(always)]
+                pub fn vmax (self, rhs: Self) -> Self {
+                    unsafe { Self(concat_idents!(vbslq, _, $suffix)(concat_idents!(vcgtq, _, $suffix)(self.0, rhs.0), self.0, rhs.0)) }
+                }
+
+                /// Converts every lane into a double-precision float
+                #[inline(always)]
+                pub fn to_f64 (self) -> $float {
+                    unsafe { $float(concat_idents!(vcvtq_f64, _, $suffix)(self.0)) }
+                }
+
+                /// Converts every lane of a double-precision float vector into an integer, truncating towards zero and saturating the values that don't fit (`NaN` becomes `0`)
+                #[inline(always)]
+                pub fn from_f64 (x: $float) -> Self {
+                    unsafe { Self(concat_idents!(vcvtq, _, $suffix, _f64)(x.0)) }
+                }
+            }
+
+            impl From<$ty> for $target {
+                #[inline(always)]
+                fn from (x: $ty) -> Self {
+                    unsafe { Self(concat_idents!(vdupq_n, _, $suffix)(x)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_int64!(
+    [i64;2] as i64x2 => f64x2: int64x2_t as s64,
+    [u64;2] as u64x2 => f64x2: uint64x2_t as u64
+);
+
+impl_int_composite!(
+    i64x2 as i64x4: i64,
+    u64x2 as u64x4: u64,
+    i64x4 as i64x8: i64,
+    u64x4 as u64x8: u64,
+    i64x8 as i64x16: i64,
+    u64x8 as u64x16: u64
+);
+
+impl_int_composite!(
+    @wide
+    i64x4: i64 => f64x4(i64x2, f64x2),
+    u64x4: u64 => f64x4(u64x2, f64x2),
+    i64x8: i64 => f64x8(i64x4, f64x4),
+    u64x8: u64 => f64x8(u64x4, f64x4),
+    i64x16: i64 => f64x16(i64x8, f64x8),
+    u64x16: u64 => f64x16(u64x8, f64x8)
+);
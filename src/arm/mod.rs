@@ -10,4 +10,4 @@ macro_rules! arch_use {
     };
 }
 
-flat_mod!(straight, composite);
\ No newline at end of file
+flat_mod!(straight, composite, int);
\ No newline at end of file
@@ -36,6 +36,7 @@ macro_rules! impl_arith {
     };
 }
 
+#[cfg_attr(feature = "deterministic", allow(unused_macros))]
 macro_rules! impl_hoz_fns {
     ($ty:ident, $($fun:ident $(as $name:ident)? $(with $tag:ident)?: $docs:expr),+) => {
         $(
@@ -84,6 +85,7 @@ macro_rules! impl_self_fns {
     };
 }
 
+#[cfg_attr(feature = "deterministic", allow(unused_macros))]
 macro_rules! impl_other_fns {
     ($ty:ident, $($fun:ident $(as $name:ident)? $(with $tag:ident)?: $docs:expr),+) => {
         $(
@@ -114,7 +116,7 @@ macro_rules! impl_straight {
             #[allow(non_camel_case_types)]
             #[repr(transparent)]
             #[derive(Copy, Assign)]
-            #[assign_targets(Add, Sub, Mul, Div)]
+            #[assign_targets(Add, Sub, Mul, Div, Rem)]
             #[assign_rhs(Self, $ty)]
             pub struct $name(pub(crate) $og);
 
@@ -126,6 +128,8 @@ macro_rules! impl_straight {
                 Div, div $(with $tag)?
             );
 
+            impl_rem!($name, $ty);
+
             impl Neg for $name {
                 type Output = Self;
 
@@ -144,12 +148,32 @@ macro_rules! impl_straight {
                     Self(concat_idents!(vld1, $($tag,)? _, $ty)(ptr))
                 }
 
+                /// Loads values from the pointer into the SIMD vector.
+                /// The pointer must be aligned to the alignment of the vector, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn load_aligned (ptr: *const $ty) -> Self {
+                    // NEON loads have no alignment requirement, so the regular one is used
+                    debug_assert_eq!(ptr as usize % core::mem::align_of::<Self>(), 0, "pointer is not aligned");
+                    Self::load(ptr)
+                }
+
+                /// Stores the values of the vector into the pointer.
+                /// The pointer must be aligned to the alignment of the vector, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn store_aligned (self, ptr: *mut $ty) {
+                    debug_assert_eq!(ptr as usize % core::mem::align_of::<Self>(), 0, "pointer is not aligned");
+                    concat_idents!(vst1, $($tag,)? _, $ty)(ptr, self.0)
+                }
+
                 impl_self_fns!(
                     $ty,
                     abs $(with $tag)?: "absolute values",
-                    sqrt $(with $tag)?: "square roots"
+                    sqrt $(with $tag)?: "square roots",
+                    rnd as trunc $(with $tag)?: "integer parts",
+                    rndm as floor $(with $tag)?: "floors"
                 );
 
+                #[cfg(not(feature = "deterministic"))]
                 impl_hoz_fns!(
                     $ty,
                     min $(with $tag)?: "Gets the smallest/minimum value of the vector",
@@ -157,16 +181,59 @@ macro_rules! impl_straight {
                     add as sum $(with $tag)?: "Sums up all the values inside the vector"
                 );
 
+                #[cfg(not(feature = "deterministic"))]
                 impl_other_fns!(
                     $ty,
                     min as vmin $(with $tag)?: "smallest/minimum value",
                     max as vmax $(with $tag)?: "biggest/maximum value"
                 );
 
+                /// Returns a vector with the step function (`0` if smaller than `rhs`, `1` otherwise) of each lane
+                #[inline(always)]
+                pub fn step (self, rhs: Self) -> Self {
+                    unsafe {
+                        let cmp : $og_mask = transmute(concat_idents!(vcge, $($tag,)? _, $ty)(self.0, rhs.0));
+                        let one : $og_mask = transmute(Self::from(1 as $ty).0);
+                        Self(transmute(cmp & one))
+                    }
+                }
+
+                impl_interp_fns!($ty);
+                impl_euclid_fns!($ty);
+
+                #[inline(always)]
+                pub(crate) fn cmp_lt (self, rhs: Self) -> Self {
+                    unsafe { Self(transmute(concat_idents!(vclt, $($tag,)? _, $ty)(self.0, rhs.0))) }
+                }
+
+                #[inline(always)]
+                pub(crate) fn cmp_eq (self, rhs: Self) -> Self {
+                    unsafe { Self(transmute(concat_idents!(vceq, $($tag,)? _, $ty)(self.0, rhs.0))) }
+                }
+
+                #[inline(always)]
+                pub(crate) fn bit_and (self, rhs: Self) -> Self {
+                    unsafe { Self(transmute(concat_idents!(vand, $($tag,)? _, $mask)(transmute(self.0), transmute(rhs.0)))) }
+                }
+
+                #[inline(always)]
+                pub(crate) fn bit_or (self, rhs: Self) -> Self {
+                    unsafe { Self(transmute(concat_idents!(vorr, $($tag,)? _, $mask)(transmute(self.0), transmute(rhs.0)))) }
+                }
+
+                // NEON clears the bits of its second operand instead
+                #[inline(always)]
+                pub(crate) fn bit_andnot (self, rhs: Self) -> Self {
+                    unsafe { Self(transmute(concat_idents!(vbic, $($tag,)? _, $mask)(transmute(rhs.0), transmute(self.0)))) }
+                }
+
+                impl_mask_fns!($ty);
+
                 /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
                 /// # Compatibility
                 /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
                 /// For the rest of targets, a regular multiplication and addition are performed
+                #[cfg(not(feature = "deterministic"))]
                 #[inline(always)]
                 pub fn mul_add (self, rhs: Self, add: Self) -> Self {
                     unsafe { Self(concat_idents!(vfma, $($tag,)? _, $ty)(add.0, rhs.0, self.0)) }
@@ -201,6 +268,7 @@ macro_rules! impl_straight {
 
 impl f32x2 {
     /// Multiplies all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn prod (self) -> f32 {
         unsafe { vdups_lane_f32::<0>(self.0) * vdups_lane_f32::<1>(self.0) }
@@ -209,6 +277,7 @@ impl f32x2 {
 
 impl f32x4 {
     /// Multiplies all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn prod (self) -> f32 {
         unsafe {
@@ -222,6 +291,7 @@ impl f32x4 {
 
 impl f64x2 {
     /// Multiplies all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn prod (self) -> f64 {
         unsafe { vdupd_laneq_f64::<0>(self.0) * vdupd_laneq_f64::<1>(self.0) }
@@ -232,4 +302,101 @@ impl_straight!(
     (float32x2_t => u64) as f32x2, [f32 => u32; 2],
     (float32x4_t => u128) as f32x4, [f32 => u32; 4] with q,
     (float64x2_t => u128) as f64x2, [f64 => u64; 2] with q
-);
\ No newline at end of file
+);
+// The sorting networks pair every lane `i` with the lane `i ^ j`
+impl f32x4 {
+    #[inline(always)]
+    pub(crate) fn xor_lanes (self, j: usize) -> Self {
+        unsafe {
+            match j {
+                1 => Self(vrev64q_f32(self.0)),
+                2 => Self(vextq_f32::<2>(self.0, self.0)),
+                _ => unreachable!()
+            }
+        }
+    }
+}
+
+impl f64x2 {
+    #[inline(always)]
+    pub(crate) fn xor_lanes (self, j: usize) -> Self {
+        match j {
+            1 => unsafe { Self(vextq_f64::<1>(self.0, self.0)) },
+            _ => unreachable!()
+        }
+    }
+}
+
+// The lanes moving up are extracted from `fill` followed by the vector itself
+impl f32x2 {
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+    #[inline(always)]
+    pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+        match lanes {
+            1 => unsafe { Self(vext_f32::<1>(fill.0, self.0)) },
+            _ => unreachable!()
+        }
+    }
+
+    impl_scan!(@float f32, 2);
+}
+
+impl f32x4 {
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+    #[inline(always)]
+    pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+        unsafe {
+            match lanes {
+                1 => Self(vextq_f32::<3>(fill.0, self.0)),
+                2 => Self(vextq_f32::<2>(fill.0, self.0)),
+                _ => unreachable!()
+            }
+        }
+    }
+
+    impl_scan!(@float f32, 4);
+}
+
+impl f64x2 {
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+    #[inline(always)]
+    pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+        match lanes {
+            1 => unsafe { Self(vextq_f64::<1>(fill.0, self.0)) },
+            _ => unreachable!()
+        }
+    }
+
+    impl_scan!(@float f64, 2);
+}
+
+// The deterministic reductions split the quadword registers in their doubleword halves
+#[cfg(feature = "deterministic")]
+impl f32x2 {
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f32) -> [Self; 1] {
+        [self]
+    }
+
+    impl_reduce_pairs!(f32);
+}
+
+#[cfg(feature = "deterministic")]
+impl f32x4 {
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f32) -> [f32x2; 2] {
+        unsafe { [f32x2(vget_low_f32(self.0)), f32x2(vget_high_f32(self.0))] }
+    }
+
+    impl_reduce_pairs!(f32);
+}
+
+#[cfg(feature = "deterministic")]
+impl f64x2 {
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f64) -> [Self; 1] {
+        [self]
+    }
+
+    impl_reduce_pairs!(f64);
+}
@@ -0,0 +1,98 @@
+macro_rules! impl_convert {
+    ($from:ident <=> $to:ident: $($ty:ident),+) => {
+        $(
+            impl From<$from::$ty> for $to::$ty {
+                #[inline(always)]
+                fn from (x: $from::$ty) -> Self {
+                    Self::from(x.into_array())
+                }
+            }
+
+            impl From<$to::$ty> for $from::$ty {
+                #[inline(always)]
+                fn from (x: $to::$ty) -> Self {
+                    Self::from(x.into_array())
+                }
+            }
+        )+
+    };
+}
+
+/// Naive implementation with arrays, available on every target
+pub mod naive {
+    pub use crate::naive::{
+        f32x2, f32x3, f32x4, f32x6, f32x8, f32x10, f32x12, f32x14, f32x16, f32x24, f32x32,
+        f64x2, f64x3, f64x4, f64x6, f64x8, f64x10, f64x12, f64x14, f64x16, f64x24, f64x32
+    };
+}
+
+/// x86/x86_64 SSE (128-bit) implementation
+#[cfg(all(not(any(feature = "force_naive", feature = "portable_simd")), any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))]
+pub mod sse {
+    pub use crate::x86::sse::*;
+    pub use crate::x86::special::f32x2;
+}
+
+/// x86/x86_64 AVX (128-bit to 256-bit) implementation
+#[cfg(all(not(any(feature = "force_naive", feature = "portable_simd")), any(target_arch = "x86", target_arch = "x86_64"), feature = "use_avx", target_feature = "avx"))]
+pub mod avx {
+    pub use crate::float::single::*;
+    pub use crate::float::double::*;
+}
+
+/// arm/aarch64 NEON (64-bit to 128-bit) implementation
+#[cfg(all(not(any(feature = "force_naive", feature = "portable_simd")), any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"))]
+pub mod neon {
+    pub use crate::float::single::*;
+    pub use crate::float::double::*;
+}
+
+/// WASM32 SIMD128 proposal (128-bit) implementation
+#[cfg(all(not(any(feature = "force_naive", feature = "portable_simd")), target_arch = "wasm32", target_feature = "simd128"))]
+pub mod wasm {
+    pub use crate::float::single::*;
+    pub use crate::float::double::*;
+}
+
+/// Portable implementation with ```core::simd```
+#[cfg(all(not(feature = "force_naive"), feature = "portable_simd"))]
+pub mod portable {
+    pub use crate::float::single::*;
+    pub use crate::float::double::*;
+}
+
+// The float vectors of the current implementation, to convert naive vectors from and into, unless naive is the current one
+cfg_if::cfg_if! {
+    if #[cfg(all(not(feature = "force_naive"), any(
+        feature = "portable_simd",
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+        all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    )))] {
+        mod current {
+            pub use crate::float::single::*;
+            pub use crate::float::double::*;
+        }
+
+        impl_convert!(
+            naive <=> current:
+            f32x2, f32x3, f32x4, f32x6, f32x8, f32x10, f32x12, f32x14, f32x16, f32x24, f32x32,
+            f64x2, f64x3, f64x4, f64x6, f64x8, f64x10, f64x12, f64x14, f64x16, f64x24, f64x32
+        );
+    }
+}
+
+// With AVX enabled, SSE vectors up to 128 bits are shared by both implementations, and only the wider ones differ
+#[cfg(all(not(any(feature = "force_naive", feature = "portable_simd")), any(target_arch = "x86", target_arch = "x86_64"), feature = "use_avx", target_feature = "avx"))]
+impl_convert!(
+    naive <=> sse:
+    f32x6, f32x8, f32x10, f32x12, f32x14, f32x16, f32x24, f32x32,
+    f64x3, f64x4, f64x6, f64x8, f64x10, f64x12, f64x14, f64x16, f64x24, f64x32
+);
+
+#[cfg(all(not(any(feature = "force_naive", feature = "portable_simd")), any(target_arch = "x86", target_arch = "x86_64"), feature = "use_avx", target_feature = "avx"))]
+impl_convert!(
+    sse <=> avx:
+    f32x6, f32x8, f32x10, f32x12, f32x14, f32x16, f32x24, f32x32,
+    f64x3, f64x4, f64x6, f64x8, f64x10, f64x12, f64x14, f64x16, f64x24, f64x32
+);
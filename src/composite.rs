@@ -22,162 +22,514 @@ macro_rules! impl_scal_arith {
     };
 }
 
-macro_rules! impl_hoz_fns {
-    (2, $ty:ident, $($fun:ident as $name:ident, $docs:expr),+) => {
+macro_rules! impl_rem {
+    ($($target:ident, $ty:ident),+) => {
         $(
-            #[doc=$docs]
-            #[inline(always)]
-            pub fn $name (self) -> $ty {
-                self.0.$name().$fun(self.1.$name())
+            impl Rem for $target {
+                type Output = Self;
+
+                /// Computed in-register on the absolute values as `|self| - (|self| / |rhs|).trunc() * |rhs|`, adding `|rhs|` back where the quotient was rounded up.
+                /// Where the quotient doesn't fit in the mantissa, `|self|` is first reduced by power-of-two multiples of `|rhs|`.
+                /// Like the scalar `%`, the result has the sign of `self`, and is `self` for infinite `rhs`.
+                /// Without fused multiply-add, it may differ from the scalar `%` by a rounding error
+                #[cfg(not(feature = "deterministic"))]
+                #[inline(always)]
+                fn rem (self, rhs: Self) -> Self::Output {
+                    let (x, y) = (self.abs(), rhs.abs());
+                    let zero = Self::from(0 as $ty);
+                    let mut r = Self::rem_step(x, y);
+
+                    // only finite lanes with a nonzero `rhs` go through the reduction, the rest are `NaN` anyway
+                    let limit = Self::from((1u64 << $ty::MANTISSA_DIGITS) as $ty);
+                    let big = limit.cmp_le(x / y)
+                        .bit_and(x.cmp_lt(Self::from($ty::INFINITY)))
+                        .bit_and(zero.cmp_lt(y));
+
+                    if big.any_lane() {
+                        let reduced = Self::rem_big(Self::select(big, x, zero), Self::select(big, y, Self::from(1 as $ty)));
+                        r = Self::select(big, reduced, r);
+                    }
+
+                    // lanes smaller than `rhs`, including every finite lane of an infinite `rhs`, are their own remainder
+                    let r = Self::select(x.cmp_lt(y), x, r);
+                    r.bit_or(self.bit_and(Self::from(-(0 as $ty))))
+                }
+
+                /// Computed with the scalar `%` on every lane, so that the result is the same on every target
+                #[cfg(feature = "deterministic")]
+                #[inline(always)]
+                fn rem (self, rhs: Self) -> Self::Output {
+                    let (mut x, y) = (self.into_array(), rhs.into_array());
+                    for i in 0..x.len() {
+                        x[i] %= y[i];
+                    }
+
+                    Self::from(x)
+                }
             }
-        )*
-    };
 
-    (3, f32, $($fun:ident, $docs:expr),+) => {
-        $(
-            #[doc=$docs]
-            #[inline(always)]
-            pub fn $fun (self) -> f32 {
-                let array = [self.0.$fun(), self.1.$fun(), self.2.$fun(), 0.];
-                f32x4::from(array).$fun()
+            #[cfg(not(feature = "deterministic"))]
+            impl $target {
+                // Reduces the nonnegative lanes of `x` by `y` once, which is exact whenever the quotient fits in the mantissa.
+                // The quotient may be rounded up to the next integer, in which case `y` is added back
+                #[inline(always)]
+                fn rem_step (x: Self, y: Self) -> Self {
+                    let r = (x / y).trunc().mul_add(-y, x);
+                    Self::select(r.cmp_lt(Self::from(0 as $ty)), r + y, r)
+                }
+
+                // Reduces the nonnegative lanes of `x` by the biggest multiple `y * 2^(k * (MANTISSA_DIGITS - 2))` that doesn't exceed them,
+                // then by every smaller one down to `y` itself, so that every quotient fits in the mantissa
+                #[cold]
+                fn rem_big (mut x: Self, y: Self) -> Self {
+                    let scale = Self::from((1u64 << ($ty::MANTISSA_DIGITS - 2)) as $ty);
+                    let mut m = y;
+
+                    loop {
+                        let grow = (m * scale).cmp_le(x);
+                        if !grow.any_lane() {
+                            break
+                        }
+
+                        m = Self::select(grow, m * scale, m);
+                    }
+
+                    loop {
+                        x = Self::rem_step(x, m);
+                        let shrink = y.cmp_lt(m);
+                        if !shrink.any_lane() {
+                            return x
+                        }
+
+                        m = Self::select(shrink, m / scale, m);
+                    }
+                }
             }
+
+            impl_scal_arith!($target, $ty, Rem, rem);
         )*
     };
+}
+
+macro_rules! impl_euclid_fns {
+    ($ty:ident) => {
+        /// Returns a vector with the Euclidean remainder of each lane. Computes the least nonnegative remainder of `self (mod rhs)`
+        #[inline(always)]
+        pub fn rem_euclid (self, rhs: Self) -> Self {
+            let r = self % rhs;
+            Self::select(r.cmp_lt(Self::from(0 as $ty)), r + rhs.abs(), r)
+        }
+
+        /// Returns a vector with the Euclidean division quotient of each lane. Computes `self.div_euclid(rhs)` for every lane
+        #[inline(always)]
+        pub fn div_euclid (self, rhs: Self) -> Self {
+            let zero = Self::from(0 as $ty);
+            let q = (self / rhs).trunc();
+            let step = Self::select(zero.cmp_lt(rhs), Self::from(-1 as $ty), Self::from(1 as $ty));
+            Self::select((self % rhs).cmp_lt(zero), q + step, q)
+        }
+    };
+}
+
+// The two-lane vector of each lane type, in which the deterministic reductions combine the pairs of lanes of wider vectors
+macro_rules! pair_of {
+    (f32) => { f32x2 };
+    (f64) => { f64x2 };
+}
+
+// Combines the pairs of lanes `(2k, 2k + 1)` returned by `pairs` as two-lane vectors, following the tree of `reduce_tree`.
+// Each implementation splits its registers into those pairs, filling the missing lane of an odd length with `pad`
+macro_rules! impl_reduce_pairs {
+    ($ty:ident) => {
+        #[cfg(feature = "deterministic")]
+        #[inline(always)]
+        pub(crate) fn reduce_pairs<F: Fn(pair_of!($ty), pair_of!($ty)) -> pair_of!($ty)> (self, pad: $ty, f: F) -> pair_of!($ty) {
+            crate::reduce_tree(self.pairs(pad), f)
+        }
+    };
+}
 
-    (4, f32, $($fun:ident, $docs:expr),+) => {
+// Pairs every lane `i` of the composites made of equal parts with the lane `i ^ j`, for the sorting networks.
+// Partners within a part are permuted by the part itself, and the rest by swapping whole parts
+macro_rules! impl_xor_lanes {
+    ($([$x:ident => $lx:literal; $n:literal] as $target:ident),+) => {
         $(
-            #[doc=$docs]
-            #[inline(always)]
-            pub fn $fun (self) -> f32 {
-                let array = [self.0.$fun(), self.1.$fun(), self.2.$fun(), self.3.$fun()];
-                f32x4::from(array).$fun()
+            impl $target {
+                #[inline(always)]
+                pub(crate) fn xor_lanes (self, j: usize) -> Self {
+                    let parts : [$x; $n] = unsafe { core::mem::transmute(self) };
+                    let parts : [$x; $n] = if j < $lx {
+                        parts.map(|x| x.xor_lanes(j))
+                    } else {
+                        core::array::from_fn(|p| parts[p ^ (j / $lx)])
+                    };
+
+                    unsafe { core::mem::transmute(parts) }
+                }
             }
         )*
     };
+}
+
+// Functions built on the lane masks of every vector, whose lanes have all of their bits set where the condition holds, and none elsewhere.
+// Every implementation provides `cmp_lt`, `cmp_eq`, `bit_and`, `bit_or` and `bit_andnot` (`!self & rhs`) on top of which these are built
+macro_rules! impl_mask_fns {
+    ($ty:ident) => {
+        // Picks the lanes of `a` where the mask is set, and the lanes of `b` elsewhere
+        #[inline(always)]
+        pub(crate) fn select (mask: Self, a: Self, b: Self) -> Self {
+            mask.bit_and(a).bit_or(mask.bit_andnot(b))
+        }
+
+        #[inline(always)]
+        pub(crate) fn cmp_le (self, rhs: Self) -> Self {
+            self.cmp_lt(rhs).bit_or(self.cmp_eq(rhs))
+        }
+
+        // Set lanes are `NaN`, so a mask only compares equal to zero if none of them is set
+        #[inline(always)]
+        pub(crate) fn any_lane (self) -> bool {
+            self != Self::from(0 as $ty)
+        }
+    };
+}
+
+macro_rules! impl_interp_fns {
+    ($ty:ident) => {
+        /// Returns a vector with every lane restricted to the `[min, max]` interval.
+        /// Both bounds can be either vectors or scalars
+        #[inline(always)]
+        pub fn clamp<T: Into<Self>> (self, min: T, max: T) -> Self {
+            self.vmax(min.into()).vmin(max.into())
+        }
+
+        /// Returns a vector with every lane restricted to the `[0, 1]` interval
+        #[inline(always)]
+        pub fn saturate (self) -> Self {
+            self.clamp(0 as $ty, 1 as $ty)
+        }
+
+        /// Linearly interpolates between `self` and `rhs`, computing `(rhs - self) * t + self` with ```mul_add```
+        #[inline(always)]
+        pub fn lerp<T: Into<Self>> (self, rhs: Self, t: T) -> Self {
+            (rhs - self).mul_add(t.into(), self)
+        }
+
+        /// Performs a smooth Hermite interpolation between 0 and 1 for the lanes that are between `edge0` and `edge1`
+        #[inline(always)]
+        pub fn smoothstep<T: Into<Self>> (self, edge0: T, edge1: T) -> Self {
+            let edge0 = edge0.into();
+            let t = ((self - edge0) / (edge1.into() - edge0)).saturate();
+            t * t * ((3 as $ty) - (2 as $ty) * t)
+        }
+    };
+}
+
+#[cfg_attr(feature = "deterministic", allow(unused_macros))]
+macro_rules! impl_hoz_fns {
+    (@one [$first:tt $(, $rest:tt)*], $ty:ident, $fun:ident, $name:ident, $docs:expr) => {
+        #[doc=$docs]
+        #[inline(always)]
+        pub fn $name (self) -> $ty {
+            self.$first.$name()$(.$fun(self.$rest.$name()))*
+        }
+    };
 
-    (3, f64, $($fun:ident, $docs:expr),+) => {
+    ($idx:tt, $ty:ident, $($fun:ident as $name:ident, $docs:expr),+) => {
         $(
-            #[doc=$docs]
-            #[inline(always)]
-            pub fn $fun (self) -> f64 {
-                let array = [self.0.$fun(), self.1.$fun(), self.2.$fun(), 0.];
-                f64x4::from(array).$fun()
-            }
+            impl_hoz_fns!(@one $idx, $ty, $fun, $name, $docs);
         )*
     };
+}
 
-    (4, f64, $($fun:ident, $docs:expr),+) => {
+macro_rules! impl_self_fns {
+    (@one [$($i:tt),+], $fun:ident, $docs:expr) => {
+        #[doc=concat!("Returns a vector with the ", $docs, " of the original vector")]
+        #[inline(always)]
+        pub fn $fun (self) -> Self {
+            Self (
+                $(self.$i.$fun()),+
+            )
+        }
+    };
+
+    ($idx:tt, $ty:ident, $($fun:ident $(with $tag:ident)?: $docs:expr),+) => {
         $(
-            #[doc=$docs]
-            #[inline(always)]
-            pub fn $fun (self) -> f64 {
-                let array = [self.0.$fun(), self.1.$fun(), self.2.$fun(), self.3.$fun()];
-                f64x4::from(array).$fun()
-            }
+            impl_self_fns!(@one $idx, $fun, $docs);
         )*
     };
 }
 
-macro_rules! impl_self_fns {
-    (2, $ty:ident, $($fun:ident $(with $tag:ident)?: $docs:expr),+) => {
+macro_rules! impl_other_fns {
+    (@mask [$($i:tt),+], $($fun:ident),+) => {
         $(
-            #[doc=concat!("Returns a vector with the ", $docs, " of the original vector")]
             #[inline(always)]
-            pub fn $fun (self) -> Self {
+            pub(crate) fn $fun (self, rhs: Self) -> Self {
                 Self (
-                    self.0.$fun(),
-                    self.1.$fun()
+                    $(self.$i.$fun(rhs.$i)),+
                 )
             }
+        )+
+    };
+
+    (@one [$($i:tt),+], $fun:ident, $docs:expr) => {
+        #[doc=concat!("Returns a vector with the ", $docs, " of each lane")]
+        #[inline(always)]
+        pub fn $fun (self, rhs: Self) -> Self {
+            Self (
+                $(self.$i.$fun(rhs.$i)),+
+            )
+        }
+    };
+
+    ($idx:tt, $($fun:ident, $docs:expr),+) => {
+        $(
+            impl_other_fns!(@one $idx, $fun, $docs);
         )*
     };
+}
 
-    (3, $ty:ident, $($fun:ident $(with $tag:ident)?: $docs:expr),+) => {
+macro_rules! impl_composite {
+    (@arith $idx:tt, $target:ident, $ty:ident, $($trait:ident, $fun:ident),+) => {
         $(
-            #[doc=concat!("Returns a vector with the ", $docs, " of the original vector")]
+            impl_composite!(@arith_one $idx, $target, $ty, $trait, $fun);
+        )*
+    };
+
+    (@arith_one [$($i:tt),+], $target:ident, $ty:ident, $trait:ident, $fun:ident) => {
+        impl $trait for $target {
+            type Output = Self;
+
             #[inline(always)]
-            pub fn $fun (self) -> Self {
+            fn $fun (self, rhs: Self) -> Self::Output {
                 Self (
-                    self.0.$fun(),
-                    self.1.$fun(),
-                    self.2.$fun()
+                    $(self.$i.$fun(rhs.$i)),+
                 )
             }
-        )*
+        }
+
+        impl_scal_arith!($target, $ty, $trait, $fun);
     };
 
-    (4, $ty:ident, $($fun:ident $(with $tag:ident)?: $docs:expr),+) => {
-        $(
-            #[doc=concat!("Returns a vector with the ", $docs, " of the original vector")]
+    // Pairs every part with its tuple index and its lane offset
+    (@index [] [$($idx:tt)*] [$($acc:tt)*] [$($off:tt)*] $name:ident: $ty:ident) => {
+        impl_composite!(@impl [$($acc)*] (0 $($off)*) $name: $ty);
+    };
+
+    (@index [$x:ident => $lx:literal $(, $xs:ident => $lxs:literal)*] [$i:tt $($idx:tt)*] [$($acc:tt)*] [$($off:tt)*] $name:ident: $ty:ident) => {
+        impl_composite!(
+            @index [$($xs => $lxs),*] [$($idx)*]
+            [$($acc)* ($i, $x, (0 $($off)*))]
+            [$($off)* + $lx]
+            $name: $ty
+        );
+    };
+
+    (@impl [$(($i:tt, $x:ident, $off:expr))+] $len:tt $name:ident: $ty:ident) => {
+        #[allow(non_camel_case_types)]
+        #[repr(C)]
+        #[derive(Clone, Copy, Assign, Neg, PartialEq)]
+        #[assign_targets(Add, Sub, Mul, Div, Rem)]
+        #[assign_rhs(Self, $ty)]
+        pub struct $name($(pub(crate) $x),+);
+
+        impl_composite!(
+            @arith [$($i),+], $name, $ty,
+            Add, add,
+            Sub, sub,
+            Mul, mul,
+            Div, div,
+            Rem, rem
+        );
+
+        impl $name {
+            /// Loads values from the pointer into the SIMD vector
             #[inline(always)]
-            pub fn $fun (self) -> Self {
+            pub unsafe fn load (ptr: *const $ty) -> Self {
                 Self (
-                    self.0.$fun(),
-                    self.1.$fun(),
-                    self.2.$fun(),
-                    self.3.$fun()
+                    $(<$x>::load(ptr.add($off))),+
                 )
             }
-        )*
-    }
-}
 
-macro_rules! impl_other_fns {
-    (2, $($fun:ident, $docs:expr),+) => {
-        $(
-            #[doc=concat!("Returns a vector with the ", $docs, " of each lane")]
+            /// Loads values from the pointer into the SIMD vector.
+            /// The pointer must be aligned to the alignment of the vector, which is only checked on debug builds
             #[inline(always)]
-            pub fn $fun (self, rhs: Self) -> Self {
+            pub unsafe fn load_aligned (ptr: *const $ty) -> Self {
+                debug_assert_eq!(ptr as usize % core::mem::align_of::<Self>(), 0, "pointer is not aligned");
+
+                // parts whose offset breaks their own alignment are loaded unaligned
                 Self (
-                    self.0.$fun(rhs.0),
-                    self.1.$fun(rhs.1)
+                    $(
+                        if ($off * core::mem::size_of::<$ty>()) % core::mem::align_of::<$x>() == 0 {
+                            <$x>::load_aligned(ptr.add($off))
+                        } else {
+                            <$x>::load(ptr.add($off))
+                        }
+                    ),+
                 )
             }
-        )*
-    };
 
-    (3, $($fun:ident, $docs:expr),+) => {
-        $(
-            #[doc=concat!("Returns a vector with the ", $docs, " of each lane")]
+            /// Stores the values of the vector into the pointer.
+            /// The pointer must be aligned to the alignment of the vector, which is only checked on debug builds
             #[inline(always)]
-            pub fn $fun (self, rhs: Self) -> Self {
+            pub unsafe fn store_aligned (self, ptr: *mut $ty) {
+                debug_assert_eq!(ptr as usize % core::mem::align_of::<Self>(), 0, "pointer is not aligned");
+
+                $(
+                    if ($off * core::mem::size_of::<$ty>()) % core::mem::align_of::<$x>() == 0 {
+                        self.$i.store_aligned(ptr.add($off))
+                    } else {
+                        let array = self.$i.into_array();
+                        core::ptr::copy_nonoverlapping(array.as_ptr(), ptr.add($off), array.len())
+                    }
+                )+
+            }
+
+            impl_self_fns!(
+                [$($i),+], $ty,
+                abs: "absolute values",
+                sqrt: "square roots",
+                trunc: "integer parts",
+                floor: "floors"
+            );
+
+            #[cfg(not(feature = "deterministic"))]
+            impl_hoz_fns!(
+                [$($i),+], $ty,
+                min as min, "Gets the smallest/minimum value of the vector",
+                max as max, "Gets the biggest/maximum value of the vector",
+                add as sum, "Sums up all the values inside the vector",
+                mul as prod, "Multiplies all the values inside the vector"
+            );
+
+            #[cfg(not(feature = "deterministic"))]
+            impl_other_fns!(
+                [$($i),+],
+                vmin, "smallest/minimum value",
+                vmax, "biggest/maximum value"
+            );
+
+            impl_other_fns!(
+                [$($i),+],
+                step, "step function (`0` if smaller than `rhs`, `1` otherwise)",
+                rem_euclid, "Euclidean remainder",
+                div_euclid, "Euclidean division quotient"
+            );
+
+            impl_interp_fns!($ty);
+
+            impl_other_fns!(@mask [$($i),+], cmp_lt, cmp_eq, bit_and, bit_or, bit_andnot);
+            impl_mask_fns!($ty);
+
+            // Every part has an even length, so their pairs follow each other
+            #[cfg(feature = "deterministic")]
+            #[inline(always)]
+            pub(crate) fn pairs (self, pad: $ty) -> [pair_of!($ty); $len / 2] {
+                let mut pairs = [<pair_of!($ty)>::from(pad); $len / 2];
+                let mut k = 0;
+
+                $(
+                    for pair in self.$i.pairs(pad) {
+                        pairs[k] = pair;
+                        k += 1;
+                    }
+                )+
+
+                pairs
+            }
+
+            impl_reduce_pairs!($ty);
+
+            impl_scan!(
+                @carry [$(($i, $x))+],
+                prefix_sum => add, "sum",
+                prefix_prod => mul, "product",
+                prefix_max => vmax, "maximum",
+                prefix_min => vmin, "minimum"
+            );
+
+            impl_scan!(@exclusive [$(($i, $x))+]);
+
+            /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
+            /// # Compatibility
+            /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
+            /// For the rest of targets, a regular multiplication and addition are performed
+            #[cfg(not(feature = "deterministic"))]
+            #[inline(always)]
+            pub fn mul_add (self, rhs: Self, add: Self) -> Self {
                 Self (
-                    self.0.$fun(rhs.0),
-                    self.1.$fun(rhs.1),
-                    self.2.$fun(rhs.2)
+                    $(self.$i.mul_add(rhs.$i, add.$i)),+
                 )
             }
-        )*
-    };
 
-    (4, $($fun:ident, $docs:expr),+) => {
-        $(
-            #[doc=concat!("Returns a vector with the ", $docs, " of each lane")]
+            /// Interleaves elements of both vectors into one
             #[inline(always)]
-            pub fn $fun (self, rhs: Self) -> Self {
+            pub fn zip (self, rhs: Self) -> Self {
+                let self_ptr = addr_of!(self) as *const $ty;
+                let rhs_ptr = addr_of!(rhs) as *const $ty;
+
+                // Each part interleaves the lanes starting at half of its own offset
+                unsafe {
+                    Self (
+                        $(<$x>::load(self_ptr.add($off / 2)).zip(<$x>::load(rhs_ptr.add($off / 2)))),+
+                    )
+                }
+            }
+        }
+
+        impl From<$ty> for $name {
+            #[inline(always)]
+            fn from(x: $ty) -> Self {
                 Self (
-                    self.0.$fun(rhs.0),
-                    self.1.$fun(rhs.1),
-                    self.2.$fun(rhs.2),
-                    self.3.$fun(rhs.3)
+                    $(Into::<$x>::into(x)),+
                 )
             }
+        }
+    };
+
+    ($(($($x:ident => $lx:literal),+) as $name:ident: $ty:ident),*) => {
+        $(
+            impl_composite!(
+                @index [$($x => $lx),+]
+                [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31]
+                [] []
+                $name: $ty
+            );
         )*
     };
 }
 
-macro_rules! impl_composite {
-    (@arith2 $target:ident, $ty:ident, $($trait:ident, $fun:ident),+) => {
+// Aligned access for vectors without an aligned instruction of their own, which go through their unaligned one
+macro_rules! impl_aligned_fallback {
+    ($ty:ident, $len:literal) => {
+        /// Loads values from the pointer into the SIMD vector.
+        /// The pointer must be aligned to the alignment of the vector, which is only checked on debug builds
+        #[inline(always)]
+        pub unsafe fn load_aligned (ptr: *const $ty) -> Self {
+            debug_assert_eq!(ptr as usize % core::mem::align_of::<Self>(), 0, "pointer is not aligned");
+            Self::load(ptr)
+        }
+
+        /// Stores the values of the vector into the pointer.
+        /// The pointer must be aligned to the alignment of the vector, which is only checked on debug builds
+        #[inline(always)]
+        pub unsafe fn store_aligned (self, ptr: *mut $ty) {
+            debug_assert_eq!(ptr as usize % core::mem::align_of::<Self>(), 0, "pointer is not aligned");
+            let array = self.into_array();
+            core::ptr::copy_nonoverlapping(array.as_ptr(), ptr, $len)
+        }
+    };
+}
+
+macro_rules! impl_padded {
+    (@arith $target:ident, $ty:ident, $($trait:ident, $fun:ident),+) => {
         $(
             impl $trait for $target {
                 type Output = Self;
     
                 #[inline(always)]
                 fn $fun (self, rhs: Self) -> Self::Output {
-                    Self(
-                        self.0.$fun(rhs.0),
-                        self.1.$fun(rhs.1),
-                    )
+                    Self(self.0.$fun(rhs.0))
                 }
             }
 
@@ -185,341 +537,362 @@ macro_rules! impl_composite {
         )*
     };
 
-    (@arith3 $target:ident, $ty:ident, $($trait:ident, $fun:ident),+) => {
+    (@self $($fun:ident: $docs:expr),+) => {
         $(
-            impl $trait for $target {
-                type Output = Self;
-    
-                #[inline(always)]
-                fn $fun (self, rhs: Self) -> Self::Output {
-                    Self(
-                        self.0.$fun(rhs.0),
-                        self.1.$fun(rhs.1),
-                        self.2.$fun(rhs.2),
-                    )
-                }
+            #[doc=concat!("Returns a vector with the ", $docs, " of the original vector")]
+            #[inline(always)]
+            pub fn $fun (self) -> Self {
+                Self(self.0.$fun())
             }
+        )*
+    };
 
-            impl_scal_arith!($target, $ty, $trait, $fun);
+    (@other $($fun:ident: $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a vector with the ", $docs, " of each lane")]
+            #[inline(always)]
+            pub fn $fun (self, rhs: Self) -> Self {
+                Self(self.0.$fun(rhs.0))
+            }
         )*
     };
 
-    (@arith4 $target:ident, $ty:ident, $($trait:ident, $fun:ident),+) => {
+    (@mask $($fun:ident),+) => {
         $(
-            impl $trait for $target {
-                type Output = Self;
-    
-                #[inline(always)]
-                fn $fun (self, rhs: Self) -> Self::Output {
-                    Self(
-                        self.0.$fun(rhs.0),
-                        self.1.$fun(rhs.1),
-                        self.2.$fun(rhs.2),
-                        self.3.$fun(rhs.3),
-                    )
-                }
+            #[inline(always)]
+            pub(crate) fn $fun (self, rhs: Self) -> Self {
+                Self(self.0.$fun(rhs.0))
             }
+        )*
+    };
 
-            impl_scal_arith!($target, $ty, $trait, $fun);
+    (@hoz $ty:ident, $($fun:ident with $pad:expr, $docs:expr),+) => {
+        $(
+            #[doc=$docs]
+            #[inline(always)]
+            pub fn $fun (self) -> $ty {
+                let mut padded = self.0;
+                padded[3] = $pad;
+                padded.$fun()
+            }
         )*
     };
 
-    ($(($x:ident => $lx:literal, $y:ident => $ly:literal) as $name:ident: $ty:ident),*) => {
+    ($($og:ident as $name:ident: $ty:ident),+) => {
         $(
+            /// Three-lane vector, stored inside a four-lane vector whose last lane is ignored
             #[allow(non_camel_case_types)]
-            #[repr(C)]
-            #[derive(Clone, Copy, Assign, Neg, PartialEq)]
-            #[assign_targets(Add, Sub, Mul, Div)]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Assign, Neg)]
+            #[assign_targets(Add, Sub, Mul, Div, Rem)]
             #[assign_rhs(Self, $ty)]
-            pub struct $name(pub(crate) $x, pub(crate) $y);
-    
-            impl_composite!(
-                @arith2 $name, $ty,
+            pub struct $name(pub(crate) $og);
+
+            impl_padded!(
+                @arith $name, $ty,
                 Add, add,
                 Sub, sub,
                 Mul, mul,
-                Div, div
+                Div, div,
+                Rem, rem
             );
 
             impl $name {
                 /// Loads values from the pointer into the SIMD vector
                 #[inline(always)]
                 pub unsafe fn load (ptr: *const $ty) -> Self {
-                    Self (
-                        <$x>::load(ptr),
-                        <$y>::load(ptr.add($lx))
-                    )
+                    Self(<$og>::new([*ptr, *ptr.add(1), *ptr.add(2), 0 as $ty]))
                 }
 
-                impl_self_fns!(
-                    2, $ty,
+                impl_aligned_fallback!($ty, 3);
+
+                impl_padded!(
+                    @self
                     abs: "absolute values",
-                    sqrt: "square roots"
+                    sqrt: "square roots",
+                    trunc: "integer parts",
+                    floor: "floors"
+                );
+
+                #[cfg(not(feature = "deterministic"))]
+                impl_padded!(
+                    @hoz $ty,
+                    min with $ty::INFINITY, "Gets the smallest/minimum value of the vector",
+                    max with $ty::NEG_INFINITY, "Gets the biggest/maximum value of the vector",
+                    sum with 0 as $ty, "Sums up all the values inside the vector",
+                    prod with 1 as $ty, "Multiplies all the values inside the vector"
                 );
 
-                impl_hoz_fns!(
-                    2, $ty,
-                    min as min, "Gets the smallest/minimum value of the vector",
-                    max as max, "Gets the biggest/maximum value of the vector",
-                    add as sum, "Sums up all the values inside the vector",
-                    mul as prod, "Multiplies all the values inside the vector"
+                #[cfg(not(feature = "deterministic"))]
+                impl_padded!(
+                    @other
+                    vmin: "smallest/minimum value",
+                    vmax: "biggest/maximum value"
                 );
 
-                impl_other_fns!(
-                    2,
-                    vmin, "smallest/minimum value",
-                    vmax, "biggest/maximum value"
+                impl_padded!(
+                    @other
+                    step: "step function (`0` if smaller than `rhs`, `1` otherwise)",
+                    rem_euclid: "Euclidean remainder",
+                    div_euclid: "Euclidean division quotient"
                 );
 
+                impl_interp_fns!($ty);
+
+                impl_padded!(@mask cmp_lt, cmp_eq, bit_and, bit_or, bit_andnot);
+                impl_mask_fns!($ty);
+
+                // The padding lane is replaced by `pad` before splitting the inner vector
+                #[cfg(feature = "deterministic")]
+                #[inline(always)]
+                pub(crate) fn pairs (self, pad: $ty) -> [pair_of!($ty); 2] {
+                    let mask = <$og>::new([0 as $ty, 0 as $ty, 0 as $ty, $ty::from_bits(!0)]);
+                    <$og>::select(mask, <$og>::from(pad), self.0).pairs(pad)
+                }
+
+                impl_reduce_pairs!($ty);
+                impl_scan!(@padded);
+
                 /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
                 /// # Compatibility
                 /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
                 /// For the rest of targets, a regular multiplication and addition are performed
+                #[cfg(not(feature = "deterministic"))]
                 #[inline(always)]
                 pub fn mul_add (self, rhs: Self, add: Self) -> Self {
-                    Self (
-                        self.0.mul_add(rhs.0, add.0),
-                        self.1.mul_add(rhs.1, add.1)
-                    )
+                    Self(self.0.mul_add(rhs.0, add.0))
                 }
 
                 /// Interleaves elements of both vectors into one
                 #[inline(always)]
                 pub fn zip (self, rhs: Self) -> Self {
-                    const D1 : usize = $lx/2;
-
-                    let first = self.0.zip(rhs.0);
-                    let last;
-                    unsafe {
-                        let alpha = *((addr_of!(self) as *const $ty).add(D1) as *const $y);
-                        let beta = *((addr_of!(rhs) as *const $ty).add(D1) as *const $y);
-                        last = alpha.zip(beta);
-                    }
+                    Self(self.0.zip(rhs.0))
+                }
+            }
 
-                    Self(first, last)
+            impl PartialEq for $name {
+                #[inline(always)]
+                fn eq (&self, rhs: &Self) -> bool {
+                    let (mut alpha, mut beta) = (self.0, rhs.0);
+                    alpha[3] = 0 as $ty;
+                    beta[3] = 0 as $ty;
+                    alpha == beta
                 }
             }
-    
+
             impl From<$ty> for $name {
                 #[inline(always)]
                 fn from(x: $ty) -> Self {
-                    Self(Into::<$x>::into(x), Into::<$y>::into(x))
+                    Self(<$og>::new([x, x, x, 0 as $ty]))
                 }
             }
         )*
     };
+}
 
-    ($(($x:ident => $lx:literal, $y:ident => $ly:literal, $z:ident => $lz:literal) as $name:ident: $ty:ident),*) => {
+
+macro_rules! impl_int_composite {
+    (@arith $target:ident, $ty:ident, $($trait:ident, $fun:ident),+) => {
         $(
-            #[allow(non_camel_case_types)]
-            #[repr(C)]
-            #[derive(Clone, Copy, Assign, Neg, PartialEq)]
-            #[assign_targets(Add, Sub, Mul, Div)]
-            #[assign_rhs(Self, $ty)]
-            pub struct $name(pub(crate) $x, pub(crate) $y, pub(crate) $z);
-    
-            impl_composite!(
-                @arith3 $name, $ty,
-                Add, add,
-                Sub, sub,
-                Mul, mul,
-                Div, div
-            );
+            impl $trait for $target {
+                type Output = Self;
 
-            impl $name {
-                /// Loads values from the pointer into the SIMD vector
                 #[inline(always)]
-                pub unsafe fn load (ptr: *const $ty) -> Self {
-                    Self (
-                        <$x>::load(ptr),
-                        <$y>::load(ptr.add($lx)),
-                        <$z>::load(ptr.add($lx + $ly))
-                    )
+                fn $fun (self, rhs: Self) -> Self::Output {
+                    Self(self.0.$fun(rhs.0), self.1.$fun(rhs.1))
                 }
+            }
 
-                impl_self_fns!(
-                    3, $ty,
-                    abs: "absolute values",
-                    sqrt: "square roots"
+            impl_scal_arith!($target, $ty, $trait, $fun);
+        )*
+    };
+
+    (@other $($fun:ident: $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a vector with the ", $docs, " of each lane")]
+            #[inline(always)]
+            pub fn $fun (self, rhs: Self) -> Self {
+                Self(self.0.$fun(rhs.0), self.1.$fun(rhs.1))
+            }
+        )*
+    };
+
+    (@small $($target:ident => $wide:ident),+) => {
+        $(
+            impl $target {
+                impl_int_composite!(
+                    @other
+                    saturating_add: "saturating addition",
+                    saturating_sub: "saturating subtraction",
+                    mulhi: "high half of the widened product",
+                    avg: "rounded up average"
                 );
 
-                #[doc="Gets the smallest/minimum value of the vector"]
+                /// Widens the lanes into two vectors, with the lower and upper halves of the original vector respectively
                 #[inline(always)]
-                pub fn min (self) -> $ty {
-                    let array = [self.0.min(), self.1.min(), self.2.min(), $ty::MAX];
-                    <concat_idents!($ty, x4)>::from(array).min()
+                pub fn widen (self) -> ($wide, $wide) {
+                    let (a, b) = self.0.widen();
+                    let (c, d) = self.1.widen();
+                    ($wide(a, b), $wide(c, d))
                 }
+            }
+        )*
+    };
 
-                #[doc="Gets the biggest/maximum value of the vector"]
+    (@narrow $($target:ident => $narrow:ident),+) => {
+        $(
+            impl $target {
+                /// Narrows the lanes of both vectors into one, saturating the values that don't fit
                 #[inline(always)]
-                pub fn max (self) -> $ty {
-                    let array = [self.0.max(), self.1.max(), self.2.max(), $ty::MIN];
-                    <concat_idents!($ty, x4)>::from(array).max()
+                pub fn narrow (self, rhs: Self) -> $narrow {
+                    $narrow(self.0.narrow(self.1), rhs.0.narrow(rhs.1))
                 }
+            }
+        )*
+    };
 
-                #[doc="Sums up all the values inside the vector"]
+    (@float $($target:ident => $fun:ident -> $float:ident: $fty:ident, $docs:expr),+) => {
+        $(
+            impl $target {
+                #[doc=concat!("Converts every lane into a ", $docs, " float")]
                 #[inline(always)]
-                pub fn sum (self) -> $ty {
-                    let array = [self.0.sum(), self.1.sum(), self.2.sum(), 0.];
-                    <concat_idents!($ty, x4)>::from(array).sum()
+                pub fn $fun (self) -> $float {
+                    const HALF : usize = core::mem::size_of::<$float>() / core::mem::size_of::<$fty>() / 2;
+                    let mut array = [0 as $fty; 2 * HALF];
+                    array[..HALF].copy_from_slice(&self.0.$fun().into_array());
+                    array[HALF..].copy_from_slice(&self.1.$fun().into_array());
+                    $float::from(array)
                 }
+            }
+        )*
+    };
 
-                #[doc="Multiplies all the values inside the vector"]
-                #[inline(always)]
-                pub fn prod (self) -> $ty {
-                    let array = [self.0.prod(), self.1.prod(), self.2.prod(), 1.];
-                    <concat_idents!($ty, x4)>::from(array).prod()
-                }
+    (@cmp $($fun:ident: $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a mask with every bit set on the lanes where `self` is ", $docs, " `rhs`, and cleared otherwise")]
+            #[inline(always)]
+            pub fn $fun (self, rhs: Self) -> Self {
+                Self(self.0.$fun(rhs.0), self.1.$fun(rhs.1))
+            }
+        )*
+    };
 
-                impl_other_fns!(
-                    3,
-                    vmin, "smallest/minimum value",
-                    vmax, "biggest/maximum value"
+    (@wide $($target:ident: $ty:ident => $float:ident($part:ident, $fpart:ident)),+) => {
+        $(
+            impl_int_composite!(@arith $target, $ty, Mul, mul);
+            impl_int_composite!(@shift $target, Shl, shl, Shr, shr);
+
+            impl $target {
+                impl_int_composite!(
+                    @cmp
+                    veq: "equal to",
+                    vne: "not equal to",
+                    vlt: "less than",
+                    vle: "less than or equal to",
+                    vgt: "greater than",
+                    vge: "greater than or equal to"
                 );
 
-                /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
-                /// # Compatibility
-                /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
-                /// For the rest of targets, a regular multiplication and addition are performed
-                #[inline(always)]
-                pub fn mul_add (self, rhs: Self, add: Self) -> Self {
-                    Self (
-                        self.0.mul_add(rhs.0, add.0),
-                        self.1.mul_add(rhs.1, add.1),
-                        self.2.mul_add(rhs.2, add.2)
-                    )
-                }
+                impl_scan!(@carry [(0, $part) (1, $part)], prefix_prod => mul, "wrapping product");
 
-                /// Interleaves elements of both vectors into one
+                /// Converts every lane of a double-precision float vector into an integer, truncating towards zero and saturating the values that don't fit (`NaN` becomes `0`)
                 #[inline(always)]
-                pub fn zip (self, rhs: Self) -> Self {
-                    const D1 : usize = $lx/2;
-                    const D2 : usize = D1 + $ly/2;
+                pub fn from_f64 (x: $float) -> Self {
+                    const HALF : usize = core::mem::size_of::<$fpart>() / core::mem::size_of::<f64>();
+                    let array = x.into_array();
 
-                    let first = self.0.zip(rhs.0);
-                    let second;
-                    let last;
                     unsafe {
-                        let alpha = *((addr_of!(self) as *const $ty).add(D1) as *const $y);
-                        let beta = *((addr_of!(rhs) as *const $ty).add(D1) as *const $y);
-                        second = alpha.zip(beta);
-
-                        let alpha = *((addr_of!(self) as *const $ty).add(D2) as *const $z);
-                        let beta = *((addr_of!(rhs) as *const $ty).add(D2) as *const $z);
-                        last = alpha.zip(beta);
+                        Self(
+                            <$part>::from_f64(<$fpart>::load(array.as_ptr())),
+                            <$part>::from_f64(<$fpart>::load(array.as_ptr().add(HALF)))
+                        )
                     }
+                }
+            }
 
-                    Self(first, second, last)
+            impl_int_composite!(@float $target => to_f64 -> $float: f64, "double-precision");
+        )*
+    };
+
+    (@shift $target:ident, $($trait:ident, $fun:ident),+) => {
+        $(
+            impl core::ops::$trait<u32> for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $fun (self, rhs: u32) -> Self::Output {
+                    Self(self.0.$fun(rhs), self.1.$fun(rhs))
                 }
             }
 
-            impl From<$ty> for $name {
+            impl core::ops::$trait for $target {
+                type Output = Self;
+
                 #[inline(always)]
-                fn from(x: $ty) -> Self {
-                    Self(x.into(), x.into(), x.into())
+                fn $fun (self, rhs: Self) -> Self::Output {
+                    Self(self.0.$fun(rhs.0), self.1.$fun(rhs.1))
                 }
             }
         )*
     };
 
-    ($(($x:ident => $lx:literal, $y:ident => $ly:literal, $z:ident => $lz:literal, $w:ident => $lw:literal) as $name:ident: $ty:ident),*) => {
+    ($($half:ident as $name:ident: $ty:ident),+) => {
         $(
             #[allow(non_camel_case_types)]
             #[repr(C)]
-            #[derive(Clone, Copy, Assign, Neg, PartialEq)]
-            #[assign_targets(Add, Sub, Mul, Div)]
+            #[derive(Clone, Copy, Assign)]
+            #[assign_targets(Add, Sub, BitAnd, BitOr, BitXor)]
             #[assign_rhs(Self, $ty)]
-            pub struct $name(pub(crate) $x, pub(crate) $y, pub(crate) $z, pub(crate) $w);
-    
-            impl_composite!(
-                @arith4 $name, $ty,
+            pub struct $name(pub(crate) $half, pub(crate) $half);
+
+            impl_int_composite!(
+                @arith $name, $ty,
                 Add, add,
                 Sub, sub,
-                Mul, mul,
-                Div, div
+                BitAnd, bitand,
+                BitOr, bitor,
+                BitXor, bitxor
             );
 
+            impl core::ops::Not for $name {
+                type Output = Self;
+
+                #[inline(always)]
+                fn not (self) -> Self::Output {
+                    Self(!self.0, !self.1)
+                }
+            }
+
             impl $name {
                 /// Loads values from the pointer into the SIMD vector
                 #[inline(always)]
                 pub unsafe fn load (ptr: *const $ty) -> Self {
-                    Self (
-                        <$x>::load(ptr),
-                        <$y>::load(ptr.add($lx)),
-                        <$z>::load(ptr.add($lx + $ly)),
-                        <$w>::load(ptr.add($lx + $ly + $lz)),
-                    )
+                    const HALF : usize = core::mem::size_of::<$half>() / core::mem::size_of::<$ty>();
+                    Self(<$half>::load(ptr), <$half>::load(ptr.add(HALF)))
                 }
 
-                impl_self_fns!(
-                    4, $ty,
-                    abs: "absolute values",
-                    sqrt: "square roots"
-                );
-
-                impl_hoz_fns!(
-                    4, $ty,
-                    min, "Gets the smallest/minimum value of the vector",
-                    max, "Gets the biggest/maximum value of the vector",
-                    sum, "Sums up all the values inside the vector",
-                    prod, "Multiplies all the values inside the vector"
+                impl_int_composite!(
+                    @other
+                    vmin: "smallest/minimum value",
+                    vmax: "biggest/maximum value"
                 );
 
-                impl_other_fns!(
-                    4,
-                    vmin, "smallest/minimum value",
-                    vmax, "biggest/maximum value"
+                impl_scan!(
+                    @carry [(0, $half) (1, $half)],
+                    prefix_sum => add, "wrapping sum",
+                    prefix_max => vmax, "maximum",
+                    prefix_min => vmin, "minimum"
                 );
 
-                /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
-                /// # Compatibility
-                /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
-                /// For the rest of targets, a regular multiplication and addition are performed
-                #[inline(always)]
-                pub fn mul_add (self, rhs: Self, add: Self) -> Self {
-                    Self (
-                        self.0.mul_add(rhs.0, add.0),
-                        self.1.mul_add(rhs.1, add.1),
-                        self.2.mul_add(rhs.2, add.2),
-                        self.3.mul_add(rhs.3, add.3)
-                    )
-                }
-
-                /// Interleaves elements of both vectors into one
-                #[inline(always)]
-                pub fn zip (self, rhs: Self) -> Self {
-                    const D1 : usize = $lx/2;
-                    const D2 : usize = D1 + $ly/2;
-                    const D3 : usize = D2 + $lz/2;
-
-                    let first = self.0.zip(rhs.0);
-                    let second;
-                    let third;
-                    let last;
-                    unsafe {
-                        let alpha = *((addr_of!(self) as *const $ty).add(D1) as *const $y);
-                        let beta = *((addr_of!(rhs) as *const $ty).add(D1) as *const $y);
-                        second = alpha.zip(beta);
-
-                        let alpha = *((addr_of!(self) as *const $ty).add(D2) as *const $z);
-                        let beta = *((addr_of!(rhs) as *const $ty).add(D2) as *const $z);
-                        third = alpha.zip(beta);
-
-                        let alpha = *((addr_of!(self) as *const $ty).add(D3) as *const $w);
-                        let beta = *((addr_of!(rhs) as *const $ty).add(D3) as *const $w);
-                        last = alpha.zip(beta);
-                    }
-
-                    Self(first, second, third, last)
-                }
+                impl_scan!(@exclusive [(0, $half) (1, $half)]);
             }
-    
+
             impl From<$ty> for $name {
                 #[inline(always)]
-                fn from(x: $ty) -> Self {
-                    Self(x.into(), x.into(), x.into(), x.into())
+                fn from (x: $ty) -> Self {
+                    Self(<$half>::from(x), <$half>::from(x))
                 }
             }
         )*
     };
-}
\ No newline at end of file
+}
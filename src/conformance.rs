@@ -0,0 +1,365 @@
+use alloc::vec::Vec;
+use core::fmt::{Display, Formatter};
+use crate::naive;
+
+/// Lane type of the vectors that can be checked
+pub trait Lane: Copy + Into<f64> + 'static {
+    /// Difference between `1` and the next representable value
+    const EPSILON: f64;
+
+    /// Values that usually break vectorized code: NaN, ±0, ±Inf, subnormals and the extremes of the type
+    const EDGES: &'static [Self];
+
+    /// Reinterprets random bits as a value
+    fn from_bits (bits: u64) -> Self;
+
+    /// Converts a value in the `[-2, 2]` interval
+    fn from_unit (x: f64) -> Self;
+}
+
+impl Lane for f32 {
+    const EPSILON: f64 = f32::EPSILON as f64;
+    const EDGES: &'static [Self] = &[
+        f32::NAN, 0., -0., f32::INFINITY, f32::NEG_INFINITY,
+        f32::MIN_POSITIVE, -f32::MIN_POSITIVE, 1e-40, -1e-40, 1e-45,
+        1., -1., f32::MAX, f32::MIN
+    ];
+
+    #[inline(always)]
+    fn from_bits (bits: u64) -> Self {
+        f32::from_bits((bits >> 32) as u32)
+    }
+
+    #[inline(always)]
+    fn from_unit (x: f64) -> Self {
+        x as f32
+    }
+}
+
+impl Lane for f64 {
+    const EPSILON: f64 = f64::EPSILON;
+    const EDGES: &'static [Self] = &[
+        f64::NAN, 0., -0., f64::INFINITY, f64::NEG_INFINITY,
+        f64::MIN_POSITIVE, -f64::MIN_POSITIVE, 1e-310, -1e-310, 5e-324,
+        1., -1., f64::MAX, f64::MIN
+    ];
+
+    #[inline(always)]
+    fn from_bits (bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+
+    #[inline(always)]
+    fn from_unit (x: f64) -> Self {
+        x
+    }
+}
+
+/// Values the random inputs of a check are taken from, after every combination of edge cases
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Inputs {
+    /// Random bit patterns, including NaN, infinities and subnormals
+    Any,
+
+    /// Random values with magnitudes in the `[0.5, 2)` interval, for operations whose result depends on rounding order
+    Unit
+}
+
+/// Difference allowed between the expected and the actual results of the random inputs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tolerance {
+    /// Results must be equal, or both NaN. `0` and `-0` are considered equal
+    Exact,
+
+    /// Results may differ by up to the specified number of epsilons of the lane type
+    Epsilons(f64)
+}
+
+/// Intended difference between the expected and the actual results of the edge cases, which are otherwise compared exactly
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Allow {
+    /// No difference. Results must be equal, or both NaN
+    Nothing,
+
+    /// Any result on the lanes (or vectors, for reductions) with a NaN input, for operations that may return either operand, like `min`
+    NanInputs,
+
+    /// Results may differ by the tolerance, for reductions whose order is unspecified, like `sum`
+    Reordering,
+
+    /// Any result on the lanes at or after one with a NaN input, for scans that carry every lane into the ones after it, like `prefix_max`
+    NanPrefix
+}
+
+/// Mismatch between an operation of a vector and its reference
+#[derive(Debug, Clone, PartialEq)]
+pub struct Failure {
+    /// Name of the operation
+    pub op: &'static str,
+
+    /// Name of the vector type
+    pub vector: &'static str,
+
+    /// Lane with the mismatch, or `None` for reductions
+    pub lane: Option<usize>,
+
+    /// Inputs of the failing lane (every lane of the vector for reductions)
+    pub inputs: Vec<f64>,
+
+    /// Result of the reference
+    pub expected: f64,
+
+    /// Result of the vector
+    pub actual: f64
+}
+
+impl Display for Failure {
+    fn fmt (&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self.lane {
+            Some(lane) => write!(f, "{}::{} failed on lane {}", self.vector, self.op, lane)?,
+            None => write!(f, "{}::{} failed", self.vector, self.op)?
+        }
+
+        write!(f, " with inputs {:?}: expected {:?}, found {:?}", self.inputs, self.expected, self.actual)
+    }
+}
+
+impl std::error::Error for Failure {}
+
+// Xorshift generator, seeded with a constant so that checks are reproducible
+struct Rng (u64);
+
+impl Rng {
+    #[inline(always)]
+    fn next (&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn lane<T: Lane> (&mut self, inputs: Inputs) -> T {
+        match inputs {
+            Inputs::Any => T::from_bits(self.next()),
+            Inputs::Unit => {
+                let bits = self.next();
+                let x = 0.5 + 1.5 * (bits >> 11) as f64 / (1u64 << 53) as f64;
+                T::from_unit(if bits & 1 == 0 { x } else { -x })
+            }
+        }
+    }
+}
+
+#[inline]
+fn matches (expected: f64, actual: f64, tolerance: Tolerance, epsilon: f64) -> bool {
+    if expected.is_nan() || actual.is_nan() {
+        return expected.is_nan() && actual.is_nan()
+    }
+
+    match tolerance {
+        Tolerance::Exact => expected == actual,
+        Tolerance::Epsilons(n) => expected == actual || (expected - actual).abs() <= n * epsilon * expected.abs().max(1.)
+    }
+}
+
+// Runs the check over every combination of edge cases, and then `iterations` random inputs.
+// The check is told whether its inputs are edge cases
+fn run<T: Lane, const N: usize, const A: usize> (
+    inputs: Inputs,
+    iterations: usize,
+    mut check: impl FnMut(&[[T;N];A], bool) -> Result<(), (Option<usize>, f64, f64)>,
+    fail: impl Fn(&[[T;N];A], Option<usize>, f64, f64) -> Failure
+) -> Result<(), Failure> {
+    let edges = T::EDGES;
+    let len = edges.len();
+    let mut run_one = |args: [[T;N];A], edge: bool| check(&args, edge).map_err(|(lane, expected, actual)| fail(&args, lane, expected, actual));
+
+    // every pair of edge cases meets on the first lane, and the rest are rotations of them
+    for a in 0..len {
+        for b in 0..len {
+            run_one(core::array::from_fn(|k| {
+                let first = if k % 2 == 0 { a } else { b };
+                core::array::from_fn(|j| edges[(first + j + k / 2) % len])
+            }), true)?;
+        }
+    }
+
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    for _ in 0..iterations {
+        run_one(core::array::from_fn(|_| core::array::from_fn(|_| rng.lane(inputs))), false)?;
+    }
+
+    Ok(())
+}
+
+// Tolerance of the comparison, which is exact for edge cases unless their order is allowed to change
+#[inline(always)]
+fn tolerance_of (edge: bool, tolerance: Tolerance, allow: Allow) -> Tolerance {
+    match edge && allow != Allow::Reordering {
+        true => Tolerance::Exact,
+        false => tolerance
+    }
+}
+
+#[inline]
+fn compare<T: Lane, const N: usize> (expected: [T;N], actual: [T;N], tolerance: Tolerance, skip: impl Fn(usize) -> bool) -> Result<(), (Option<usize>, f64, f64)> {
+    for i in 0..N {
+        let (expected, actual) = (expected[i].into(), actual[i].into());
+        if !skip(i) && !matches(expected, actual, tolerance, T::EPSILON) {
+            return Err((Some(i), expected, actual))
+        }
+    }
+
+    Ok(())
+}
+
+fn failure<T: Lane, V, const N: usize, const A: usize> (op: &'static str, args: &[[T;N];A], lane: Option<usize>, expected: f64, actual: f64) -> Failure {
+    let inputs = match lane {
+        Some(i) => args.iter().map(|x| x[i].into()).collect(),
+        None => args.iter().flat_map(|x| x.iter().map(|x| (*x).into())).collect()
+    };
+
+    Failure { op, vector: core::any::type_name::<V>(), lane, inputs, expected, actual }
+}
+
+/// Checks a lane-wise operation with `A` vector operands against its reference implementation
+pub fn check<T: Lane, V, R, const N: usize, const A: usize> (
+    op: &'static str,
+    inputs: Inputs,
+    tolerance: Tolerance,
+    allow: Allow,
+    iterations: usize,
+    actual: impl Fn([V;A]) -> V,
+    expected: impl Fn([R;A]) -> R
+) -> Result<(), Failure> where V: From<[T;N]> + Into<[T;N]>, R: From<[T;N]> + Into<[T;N]> {
+    run::<T, N, A>(
+        inputs, iterations,
+        |args, edge| compare(
+            expected(args.map(R::from)).into(), actual(args.map(V::from)).into(),
+            tolerance_of(edge, tolerance, allow),
+            |i| match allow {
+                Allow::NanInputs => args.iter().any(|x| Into::<f64>::into(x[i]).is_nan()),
+                Allow::NanPrefix => args.iter().any(|x| x[..=i].iter().any(|x| Into::<f64>::into(*x).is_nan())),
+                _ => false
+            }
+        ),
+        |args, lane, expected, actual| failure::<T, V, N, A>(op, args, lane, expected, actual)
+    )
+}
+
+/// Checks a reduction of a vector into one value against its reference implementation
+pub fn check_reduce<T: Lane, V, R, const N: usize> (
+    op: &'static str,
+    inputs: Inputs,
+    tolerance: Tolerance,
+    allow: Allow,
+    iterations: usize,
+    actual: impl Fn(V) -> T,
+    expected: impl Fn(R) -> T
+) -> Result<(), Failure> where V: From<[T;N]>, R: From<[T;N]> {
+    run::<T, N, 1>(
+        inputs, iterations,
+        |[x], edge| {
+            let (expected, actual) = (expected(R::from(*x)).into(), actual(V::from(*x)).into());
+            let skip = allow == Allow::NanInputs && x.iter().any(|x| Into::<f64>::into(*x).is_nan());
+
+            match skip || matches(expected, actual, tolerance_of(edge, tolerance, allow), T::EPSILON * N as f64) {
+                true => Ok(()),
+                false => Err((None, expected, actual))
+            }
+        },
+        |args, lane, expected, actual| failure::<T, V, N, 1>(op, args, lane, expected, actual)
+    )
+}
+
+macro_rules! impl_conformance {
+    ($($module:ident: $ty:ident => [$($target:ident: $len:literal),+], sorted [$($sorted:ident: $slen:literal),+]),+) => {
+        /// Checks every operation of every vector type against the naive implementation,
+        /// on every combination of edge cases and `iterations` random inputs.
+        /// The checked operations are the arithmetic operators, `vmin`, `vmax`, `step`, `zip`, `neg`, `abs`, `sqrt`, `trunc`, `floor`,
+        /// `mul_add`, `clamp`, `lerp`, `rem_euclid`, `div_euclid`, the prefix scans, `sum`, `prod`, `min`, `max`, `sum_kahan`, `sum_pairwise`
+        /// and, on the vectors that can be sorted, `sort_lanes` and `sort_lanes_desc`
+        pub fn check_all (iterations: usize) -> Result<(), Failure> {
+            $(
+                $(
+                    impl_conformance!(@vector $ty, crate::float::$module::$target, naive::$target, $len, iterations);
+                )+
+
+                $(
+                    impl_conformance!(
+                        @lanes $ty, crate::float::$module::$sorted, naive::$sorted, $slen, iterations, 1,
+                        "sort_lanes", Any, Exact, Nothing => |[x]| x.sort_lanes(),
+                        "sort_lanes_desc", Any, Exact, Nothing => |[x]| x.sort_lanes_desc()
+                    );
+                )+
+            )+
+
+            Ok(())
+        }
+    };
+
+    (@vector $ty:ident, $target:ty, $naive:ty, $len:literal, $iter:ident) => {
+        impl_conformance!(
+            @lanes $ty, $target, $naive, $len, $iter, 2,
+            "add", Any, Exact, Nothing => |[x, y]| x + y,
+            "sub", Any, Exact, Nothing => |[x, y]| x - y,
+            "mul", Any, Exact, Nothing => |[x, y]| x * y,
+            "div", Any, Exact, Nothing => |[x, y]| x / y,
+            "rem", Unit, Epsilons(4.), Nothing => |[x, y]| x % y,
+            "rem_euclid", Unit, Epsilons(4.), Nothing => |[x, y]| x.rem_euclid(y),
+            "div_euclid", Unit, Epsilons(4.), Nothing => |[x, y]| x.div_euclid(y),
+            "vmin", Any, Exact, NanInputs => |[x, y]| x.vmin(y),
+            "vmax", Any, Exact, NanInputs => |[x, y]| x.vmax(y),
+            "step", Any, Exact, Nothing => |[x, y]| x.step(y),
+            "zip", Any, Exact, Nothing => |[x, y]| x.zip(y)
+        );
+
+        impl_conformance!(
+            @lanes $ty, $target, $naive, $len, $iter, 1,
+            "neg", Any, Exact, Nothing => |[x]| -x,
+            "abs", Any, Exact, Nothing => |[x]| x.abs(),
+            "sqrt", Any, Exact, Nothing => |[x]| x.sqrt(),
+            "trunc", Any, Exact, Nothing => |[x]| x.trunc(),
+            "floor", Any, Exact, Nothing => |[x]| x.floor(),
+            "prefix_sum", Unit, Epsilons(64.), Reordering => |[x]| x.prefix_sum(),
+            "prefix_sum_exclusive", Unit, Epsilons(64.), Reordering => |[x]| x.prefix_sum_exclusive(),
+            "prefix_prod", Unit, Epsilons(64.), Reordering => |[x]| x.prefix_prod(),
+            "prefix_max", Any, Exact, NanPrefix => |[x]| x.prefix_max(),
+            "prefix_min", Any, Exact, NanPrefix => |[x]| x.prefix_min()
+        );
+
+        impl_conformance!(
+            @lanes $ty, $target, $naive, $len, $iter, 3,
+            "mul_add", Unit, Epsilons(8.), Nothing => |[x, y, z]| x.mul_add(y, z),
+            "lerp", Unit, Epsilons(8.), Nothing => |[x, y, z]| x.lerp(y, z),
+            "clamp", Any, Exact, NanInputs => |[x, y, z]| x.clamp(y.vmin(z), y.vmax(z))
+        );
+
+        impl_conformance!(
+            @reduce $ty, $target, $naive, $len, $iter,
+            "sum", Unit, Epsilons(64.), Reordering => |x| x.sum(),
+            "prod", Unit, Epsilons(2.), Reordering => |x| x.prod(),
+            "min", Any, Exact, NanInputs => |x| x.min(),
+            "max", Any, Exact, NanInputs => |x| x.max(),
+            "sum_kahan", Unit, Exact, Nothing => |x| x.sum_kahan(),
+            "sum_pairwise", Unit, Exact, Nothing => |x| x.sum_pairwise()
+        );
+    };
+
+    (@lanes $ty:ident, $target:ty, $naive:ty, $len:literal, $iter:ident, $arity:literal, $($op:literal, $inputs:ident, $tol:ident $(($eps:expr))?, $allow:ident => $f:expr),+) => {
+        $(
+            check::<$ty, $target, $naive, $len, $arity>($op, Inputs::$inputs, Tolerance::$tol $(($eps))?, Allow::$allow, $iter, $f, $f)?;
+        )+
+    };
+
+    (@reduce $ty:ident, $target:ty, $naive:ty, $len:literal, $iter:ident, $($op:literal, $inputs:ident, $tol:ident $(($eps:expr))?, $allow:ident => $f:expr),+) => {
+        $(
+            check_reduce::<$ty, $target, $naive, $len>($op, Inputs::$inputs, Tolerance::$tol $(($eps))?, Allow::$allow, $iter, $f, $f)?;
+        )+
+    };
+}
+
+impl_conformance!(
+    single: f32 => [f32x2: 2, f32x3: 3, f32x4: 4, f32x6: 6, f32x8: 8, f32x10: 10, f32x12: 12, f32x14: 14, f32x16: 16, f32x24: 24, f32x32: 32], sorted [f32x4: 4, f32x8: 8, f32x16: 16],
+    double: f64 => [f64x2: 2, f64x3: 3, f64x4: 4, f64x6: 6, f64x8: 8, f64x10: 10, f64x12: 12, f64x14: 14, f64x16: 16, f64x24: 24, f64x32: 32], sorted [f64x4: 4, f64x8: 8, f64x16: 16]
+);
@@ -0,0 +1,141 @@
+/// Scalar approximate equality, following the same semantics as the `approx` crate
+trait LaneEq: Copy {
+    fn abs_diff_eq (self, other: Self, epsilon: Self) -> bool;
+    fn relative_eq (self, other: Self, epsilon: Self, max_relative: Self) -> bool;
+    fn ulps_eq (self, other: Self, epsilon: Self, max_ulps: u32) -> bool;
+}
+
+macro_rules! impl_lane_eq {
+    ($($ty:ident as $int:ident),+) => {
+        $(
+            impl LaneEq for $ty {
+                #[inline(always)]
+                fn abs_diff_eq (self, other: Self, epsilon: Self) -> bool {
+                    (if self > other { self - other } else { other - self }) <= epsilon
+                }
+
+                #[inline]
+                fn relative_eq (self, other: Self, epsilon: Self, max_relative: Self) -> bool {
+                    if self == other { return true }
+                    if self.is_infinite() || other.is_infinite() { return false }
+
+                    let abs_diff = if self > other { self - other } else { other - self };
+                    if abs_diff <= epsilon { return true }
+
+                    let abs_self = if self < 0. { -self } else { self };
+                    let abs_other = if other < 0. { -other } else { other };
+                    abs_diff <= abs_self.max(abs_other) * max_relative
+                }
+
+                #[inline]
+                fn ulps_eq (self, other: Self, epsilon: Self, max_ulps: u32) -> bool {
+                    if LaneEq::abs_diff_eq(self, other, epsilon) { return true }
+                    if self.is_nan() || other.is_nan() { return false }
+                    if self.is_sign_negative() != other.is_sign_negative() { return false }
+                    self.to_bits().abs_diff(other.to_bits()) <= max_ulps as $int
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_approx {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            impl $target {
+                /// Checks if every lane's absolute difference is within `epsilon`
+                #[inline]
+                pub fn abs_diff_eq (&self, other: &Self, epsilon: $ty) -> bool {
+                    let (alpha, beta) = (self.into_array(), other.into_array());
+                    alpha.iter().zip(beta.iter()).all(|(x, y)| LaneEq::abs_diff_eq(*x, *y, epsilon))
+                }
+
+                /// Checks if every lane is within `epsilon` in absolute terms, or within `max_relative` of the biggest magnitude in relative terms
+                #[inline]
+                pub fn relative_eq (&self, other: &Self, epsilon: $ty, max_relative: $ty) -> bool {
+                    let (alpha, beta) = (self.into_array(), other.into_array());
+                    alpha.iter().zip(beta.iter()).all(|(x, y)| LaneEq::relative_eq(*x, *y, epsilon, max_relative))
+                }
+
+                /// Checks if every lane is within `epsilon` in absolute terms, or at most `max_ulps` representable values apart
+                #[inline]
+                pub fn ulps_eq (&self, other: &Self, epsilon: $ty, max_ulps: u32) -> bool {
+                    let (alpha, beta) = (self.into_array(), other.into_array());
+                    alpha.iter().zip(beta.iter()).all(|(x, y)| LaneEq::ulps_eq(*x, *y, epsilon, max_ulps))
+                }
+            }
+
+            #[cfg(feature = "approx")]
+            impl approx::AbsDiffEq for $target {
+                type Epsilon = $ty;
+
+                #[inline(always)]
+                fn default_epsilon() -> $ty {
+                    $ty::EPSILON
+                }
+
+                #[inline(always)]
+                fn abs_diff_eq(&self, other: &Self, epsilon: $ty) -> bool {
+                    $target::abs_diff_eq(self, other, epsilon)
+                }
+            }
+
+            #[cfg(feature = "approx")]
+            impl approx::RelativeEq for $target {
+                #[inline(always)]
+                fn default_max_relative() -> $ty {
+                    $ty::EPSILON
+                }
+
+                #[inline(always)]
+                fn relative_eq(&self, other: &Self, epsilon: $ty, max_relative: $ty) -> bool {
+                    $target::relative_eq(self, other, epsilon, max_relative)
+                }
+            }
+
+            #[cfg(feature = "approx")]
+            impl approx::UlpsEq for $target {
+                #[inline(always)]
+                fn default_max_ulps() -> u32 {
+                    4
+                }
+
+                #[inline(always)]
+                fn ulps_eq(&self, other: &Self, epsilon: $ty, max_ulps: u32) -> bool {
+                    $target::ulps_eq(self, other, epsilon, max_ulps)
+                }
+            }
+        )*
+    };
+}
+
+impl_lane_eq!(
+    f32 as u32,
+    f64 as u64
+);
+
+impl_approx!(
+    [f32;2] as f32x2,
+    [f32;3] as f32x3,
+    [f32;4] as f32x4,
+    [f32;6] as f32x6,
+    [f32;8] as f32x8,
+    [f32;10] as f32x10,
+    [f32;12] as f32x12,
+    [f32;14] as f32x14,
+    [f32;16] as f32x16,
+    [f32;24] as f32x24,
+    [f32;32] as f32x32,
+
+    [f64;2] as f64x2,
+    [f64;3] as f64x3,
+    [f64;4] as f64x4,
+    [f64;6] as f64x6,
+    [f64;8] as f64x8,
+    [f64;10] as f64x10,
+    [f64;12] as f64x12,
+    [f64;14] as f64x14,
+    [f64;16] as f64x16,
+    [f64;24] as f64x24,
+    [f64;32] as f64x32
+);
\ No newline at end of file
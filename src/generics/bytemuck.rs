@@ -0,0 +1,167 @@
+macro_rules! impl_zeroable {
+    ($($target:ident),+) => {
+        $(
+            unsafe impl bytemuck::Zeroable for $target {}
+        )*
+    };
+}
+
+macro_rules! impl_pod {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            const _ : () = assert!(core::mem::size_of::<$target>() == $len * core::mem::size_of::<$ty>());
+            unsafe impl bytemuck::Pod for $target {}
+
+            impl $target {
+                /// Reinterprets a slice of scalars as a slice of vectors, alongside the scalars that didn't fit into a full vector.
+                /// If the slice isn't aligned to the vector's alignment, no vectors are returned and every scalar is left in the remainder
+                /// (use [`try_as_vectors`](Self::try_as_vectors) to detect it)
+                #[inline(always)]
+                pub fn as_vectors (slice: &[$ty]) -> (&[Self], &[$ty]) {
+                    match Self::try_as_vectors(slice) {
+                        Ok(split) => split,
+                        Err(_) => (&[], slice)
+                    }
+                }
+
+                /// Reinterprets a mutable slice of scalars as a mutable slice of vectors, alongside the scalars that didn't fit into a full vector.
+                /// If the slice isn't aligned to the vector's alignment, no vectors are returned and every scalar is left in the remainder
+                /// (use [`try_as_vectors_mut`](Self::try_as_vectors_mut) to detect it)
+                #[inline(always)]
+                pub fn as_vectors_mut (slice: &mut [$ty]) -> (&mut [Self], &mut [$ty]) {
+                    if slice.as_ptr() as usize % core::mem::align_of::<Self>() != 0 {
+                        return (&mut [], slice)
+                    }
+
+                    Self::try_as_vectors_mut(slice).unwrap()
+                }
+
+                /// Reinterprets a slice of scalars as a slice of vectors, alongside the scalars that didn't fit into a full vector.
+                /// Returns an error if the slice isn't aligned to the vector's alignment
+                #[inline]
+                pub fn try_as_vectors (slice: &[$ty]) -> Result<(&[Self], &[$ty]), bytemuck::PodCastError> {
+                    let (vectors, rem) = slice.split_at(slice.len() - slice.len() % $len);
+                    Ok((bytemuck::try_cast_slice(vectors)?, rem))
+                }
+
+                /// Reinterprets a mutable slice of scalars as a mutable slice of vectors, alongside the scalars that didn't fit into a full vector.
+                /// Returns an error if the slice isn't aligned to the vector's alignment
+                #[inline]
+                pub fn try_as_vectors_mut (slice: &mut [$ty]) -> Result<(&mut [Self], &mut [$ty]), bytemuck::PodCastError> {
+                    let len = slice.len() - slice.len() % $len;
+                    let (vectors, rem) = slice.split_at_mut(len);
+                    Ok((bytemuck::try_cast_slice_mut(vectors)?, rem))
+                }
+
+                /// Reinterprets a slice of vectors as a slice of scalars
+                #[inline(always)]
+                pub fn as_scalars (slice: &[Self]) -> &[$ty] {
+                    bytemuck::cast_slice(slice)
+                }
+
+                /// Reinterprets a mutable slice of vectors as a mutable slice of scalars
+                #[inline(always)]
+                pub fn as_scalars_mut (slice: &mut [Self]) -> &mut [$ty] {
+                    bytemuck::cast_slice_mut(slice)
+                }
+            }
+        )*
+    };
+}
+
+impl_zeroable!(
+    f32x2, f32x3, f32x4, f32x6, f32x8, f32x10, f32x12, f32x14, f32x16, f32x24, f32x32,
+    f64x2, f64x3, f64x4, f64x6, f64x8, f64x10, f64x12, f64x14, f64x16, f64x24, f64x32
+);
+
+// Only the vectors without padding lanes (nor padding between their parts) are plain old data
+cfg_if::cfg_if! {
+    if #[cfg(any(feature = "force_naive", feature = "portable_simd", not(any(
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+        all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    ))))] {
+        impl_pod!(
+            [f32;2] as f32x2,
+            [f32;3] as f32x3,
+            [f32;4] as f32x4,
+            [f32;6] as f32x6,
+            [f32;8] as f32x8,
+            [f32;10] as f32x10,
+            [f32;12] as f32x12,
+            [f32;14] as f32x14,
+            [f32;16] as f32x16,
+            [f32;24] as f32x24,
+            [f32;32] as f32x32,
+
+            [f64;2] as f64x2,
+            [f64;3] as f64x3,
+            [f64;4] as f64x4,
+            [f64;6] as f64x6,
+            [f64;8] as f64x8,
+            [f64;10] as f64x10,
+            [f64;12] as f64x12,
+            [f64;14] as f64x14,
+            [f64;16] as f64x16,
+            [f64;24] as f64x24,
+            [f64;32] as f64x32
+        );
+    } else if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "use_avx", target_feature = "avx"))] {
+        impl_pod!(
+            [f32;4] as f32x4,
+            [f32;8] as f32x8,
+            [f32;16] as f32x16,
+            [f32;24] as f32x24,
+            [f32;32] as f32x32,
+
+            [f64;2] as f64x2,
+            [f64;4] as f64x4,
+            [f64;8] as f64x8,
+            [f64;12] as f64x12,
+            [f64;16] as f64x16,
+            [f64;24] as f64x24,
+            [f64;32] as f64x32
+        );
+    } else if #[cfg(all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"))] {
+        impl_pod!(
+            [f32;2] as f32x2,
+            [f32;4] as f32x4,
+            [f32;8] as f32x8,
+            [f32;12] as f32x12,
+            [f32;16] as f32x16,
+            [f32;24] as f32x24,
+            [f32;32] as f32x32,
+
+            [f64;2] as f64x2,
+            [f64;4] as f64x4,
+            [f64;6] as f64x6,
+            [f64;8] as f64x8,
+            [f64;10] as f64x10,
+            [f64;12] as f64x12,
+            [f64;14] as f64x14,
+            [f64;16] as f64x16,
+            [f64;24] as f64x24,
+            [f64;32] as f64x32
+        );
+    } else {
+        impl_pod!(
+            [f32;4] as f32x4,
+            [f32;8] as f32x8,
+            [f32;12] as f32x12,
+            [f32;16] as f32x16,
+            [f32;24] as f32x24,
+            [f32;32] as f32x32,
+
+            [f64;2] as f64x2,
+            [f64;4] as f64x4,
+            [f64;6] as f64x6,
+            [f64;8] as f64x8,
+            [f64;10] as f64x10,
+            [f64;12] as f64x12,
+            [f64;14] as f64x14,
+            [f64;16] as f64x16,
+            [f64;24] as f64x24,
+            [f64;32] as f64x32
+        );
+    }
+}
\ No newline at end of file
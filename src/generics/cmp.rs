@@ -0,0 +1,102 @@
+/// Wrapper that compares and hashes vectors by the bit representation of their lanes,
+/// allowing them to be used as keys in hash maps or ordered collections.
+/// Under this representation, `NaN` is equal to itself if its bits match, and `0.0` and `-0.0` are different
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BitEq<T> (pub T);
+
+macro_rules! impl_cmp {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            impl Default for $target {
+                #[inline(always)]
+                fn default() -> Self {
+                    Self::from(0 as $ty)
+                }
+            }
+
+            impl PartialOrd for $target {
+                /// Compares the vectors lexicographically, lane by lane
+                #[inline]
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    self.into_array().partial_cmp(&other.into_array())
+                }
+            }
+
+            impl $target {
+                /// Compares the vectors lexicographically, using the total ordering of each lane (see ```total_cmp``` for floats)
+                #[inline]
+                pub fn total_cmp (&self, other: &Self) -> core::cmp::Ordering {
+                    let (alpha, beta) = (self.into_array(), other.into_array());
+                    alpha.iter().zip(beta.iter())
+                        .map(|(x, y)| x.total_cmp(y))
+                        .find(|ord| ord.is_ne())
+                        .unwrap_or(core::cmp::Ordering::Equal)
+                }
+
+                /// Returns the total ordering of each lane (see ```total_cmp``` for floats)
+                #[inline]
+                pub fn total_cmp_lanes (&self, other: &Self) -> [core::cmp::Ordering;$len] {
+                    let (alpha, beta) = (self.into_array(), other.into_array());
+                    core::array::from_fn(|i| alpha[i].total_cmp(&beta[i]))
+                }
+            }
+
+            impl PartialEq for BitEq<$target> {
+                #[inline]
+                fn eq(&self, other: &Self) -> bool {
+                    self.0.into_array().map($ty::to_bits) == other.0.into_array().map($ty::to_bits)
+                }
+            }
+
+            impl Eq for BitEq<$target> {}
+
+            impl PartialOrd for BitEq<$target> {
+                #[inline(always)]
+                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for BitEq<$target> {
+                #[inline(always)]
+                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                    self.0.total_cmp(&other.0)
+                }
+            }
+
+            impl core::hash::Hash for BitEq<$target> {
+                #[inline]
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    self.0.into_array().map($ty::to_bits).hash(state)
+                }
+            }
+        )*
+    };
+}
+
+impl_cmp!(
+    [f32;2] as f32x2,
+    [f32;3] as f32x3,
+    [f32;4] as f32x4,
+    [f32;6] as f32x6,
+    [f32;8] as f32x8,
+    [f32;10] as f32x10,
+    [f32;12] as f32x12,
+    [f32;14] as f32x14,
+    [f32;16] as f32x16,
+    [f32;24] as f32x24,
+    [f32;32] as f32x32,
+
+    [f64;2] as f64x2,
+    [f64;3] as f64x3,
+    [f64;4] as f64x4,
+    [f64;6] as f64x6,
+    [f64;8] as f64x8,
+    [f64;10] as f64x10,
+    [f64;12] as f64x12,
+    [f64;14] as f64x14,
+    [f64;16] as f64x16,
+    [f64;24] as f64x24,
+    [f64;32] as f64x32
+);
\ No newline at end of file
@@ -0,0 +1,150 @@
+macro_rules! impl_compensated {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            impl $target {
+                /// Sums up all the values inside the vector with Kahan-Babuška's compensated summation,
+                /// carrying the rounding error of every addition into a separate compensation term.
+                /// Infinities and `NaN` propagate like in a regular sum
+                #[inline]
+                pub fn sum_kahan (self) -> $ty {
+                    let mut sum = 0 as $ty;
+                    let mut comp = 0 as $ty;
+
+                    for x in self.into_array() {
+                        let t = sum + x;
+                        let b = t - sum;
+                        comp += (sum - (t - b)) + (x - b);
+                        sum = t;
+                    }
+
+                    // the compensation of an infinite sum is `NaN`, and there's no error left to compensate anyway
+                    if !sum.is_finite() {
+                        return sum
+                    }
+
+                    sum + comp
+                }
+
+                /// Sums up all the values inside the vector in pairs of lanes, then in pairs of those sums, and so on.
+                /// The rounding error grows with the logarithm of the number of lanes, instead of linearly
+                #[inline]
+                pub fn sum_pairwise (self) -> $ty {
+                    reduce_tree(self.into_array(), |x, y| x + y)
+                }
+
+                // Adds `x` to every lane of `sum`, keeping the exact rounding error of the addition in `comp`
+                #[inline(always)]
+                fn kahan_step (sum: &mut Self, comp: &mut Self, x: Self) {
+                    let t = *sum + x;
+                    let b = t - *sum;
+                    *comp += (*sum - (t - b)) + (x - b);
+                    *sum = t;
+                }
+
+                // Combines the lanes of the accumulator and its compensation, alongside the values that didn't fill a vector
+                #[inline(always)]
+                fn kahan_finish (sum: Self, comp: Self, rest: impl Iterator<Item = $ty>) -> $ty {
+                    let (sum, comp) = (sum.into_array(), comp.into_array());
+                    let mut total = 0 as $ty;
+                    let mut total_comp = 0 as $ty;
+
+                    for x in sum.into_iter().chain(rest) {
+                        let t = total + x;
+                        let b = t - total;
+                        total_comp += (total - (t - b)) + (x - b);
+                        total = t;
+                    }
+
+                    if !total.is_finite() {
+                        return total
+                    }
+
+                    total + (total_comp + comp.into_iter().sum::<$ty>())
+                }
+
+                /// Sums up all the values of the slice, keeping a vector with the rounding error of every lane alongside the accumulator.
+                /// The error barely grows with the length of the slice, unlike with a regular sum
+                #[inline]
+                pub fn compensated_sum (slice: &[$ty]) -> $ty {
+                    let mut sum = Self::from(0 as $ty);
+                    let mut comp = Self::from(0 as $ty);
+                    let mut chunks = slice.chunks_exact($len);
+
+                    for chunk in &mut chunks {
+                        Self::kahan_step(&mut sum, &mut comp, unsafe { Self::load(chunk.as_ptr()) });
+                    }
+
+                    Self::kahan_finish(sum, comp, chunks.remainder().iter().copied())
+                }
+
+                /// Returns the dot product of both slices, keeping a vector with the rounding error of every lane alongside the accumulator.
+                /// Every product is rounded before being accumulated
+                #[inline]
+                pub fn compensated_dot (lhs: &[$ty], rhs: &[$ty]) -> $ty {
+                    assert_eq!(lhs.len(), rhs.len(), "slices must have the same length");
+
+                    let mut sum = Self::from(0 as $ty);
+                    let mut comp = Self::from(0 as $ty);
+                    let mut lhs_chunks = lhs.chunks_exact($len);
+                    let mut rhs_chunks = rhs.chunks_exact($len);
+
+                    for (alpha, beta) in (&mut lhs_chunks).zip(&mut rhs_chunks) {
+                        let (alpha, beta) = unsafe { (Self::load(alpha.as_ptr()), Self::load(beta.as_ptr())) };
+                        Self::kahan_step(&mut sum, &mut comp, alpha * beta);
+                    }
+
+                    let rest = lhs_chunks.remainder().iter().zip(rhs_chunks.remainder()).map(|(x, y)| x * y);
+                    Self::kahan_finish(sum, comp, rest)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_compensated_all {
+    () => {
+        impl_compensated!(
+            [f32;2] as f32x2,
+            [f32;3] as f32x3,
+            [f32;4] as f32x4,
+            [f32;6] as f32x6,
+            [f32;8] as f32x8,
+            [f32;10] as f32x10,
+            [f32;12] as f32x12,
+            [f32;14] as f32x14,
+            [f32;16] as f32x16,
+            [f32;24] as f32x24,
+            [f32;32] as f32x32,
+
+            [f64;2] as f64x2,
+            [f64;3] as f64x3,
+            [f64;4] as f64x4,
+            [f64;6] as f64x6,
+            [f64;8] as f64x8,
+            [f64;10] as f64x10,
+            [f64;12] as f64x12,
+            [f64;14] as f64x14,
+            [f64;16] as f64x16,
+            [f64;24] as f64x24,
+            [f64;32] as f64x32
+        );
+    };
+}
+
+impl_compensated_all!();
+
+// Naive vectors sum up the same way, so that they can be compared with the current implementation
+cfg_if::cfg_if! {
+    if #[cfg(all(not(feature = "force_naive"), any(
+        feature = "portable_simd",
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+        all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    )))] {
+        mod compensated_naive {
+            use crate::naive::*;
+            use super::reduce_tree;
+            impl_compensated_all!();
+        }
+    }
+}
@@ -0,0 +1,160 @@
+macro_rules! impl_deterministic {
+    (@min $ty:ident) => {
+        |x: $ty, y: $ty| {
+            if x.is_nan() || y.is_nan() { $ty::NAN }
+            else if x < y || (x == y && x.is_sign_negative()) { x }
+            else { y }
+        }
+    };
+
+    (@max $ty:ident) => {
+        |x: $ty, y: $ty| {
+            if x.is_nan() || y.is_nan() { $ty::NAN }
+            else if x > y || (x == y && x.is_sign_positive()) { x }
+            else { y }
+        }
+    };
+
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            impl $target {
+                /// Gets the smallest/minimum value of the vector.
+                /// The result is `NaN` if any lane is `NaN`, and `-0` is smaller than `0`
+                #[inline(always)]
+                pub fn min (self) -> $ty {
+                    let pair = self.reduce_pairs($ty::INFINITY, |x, y| x.vmin(y)).into_array();
+                    (impl_deterministic!(@min $ty))(pair[0], pair[1])
+                }
+
+                /// Gets the biggest/maximum value of the vector.
+                /// The result is `NaN` if any lane is `NaN`, and `0` is bigger than `-0`
+                #[inline(always)]
+                pub fn max (self) -> $ty {
+                    let pair = self.reduce_pairs($ty::NEG_INFINITY, |x, y| x.vmax(y)).into_array();
+                    (impl_deterministic!(@max $ty))(pair[0], pair[1])
+                }
+
+                /// Sums up all the values inside the vector.
+                /// The pairs of lanes `(2k, 2k + 1)` are added up as vectors, in pairs of them, and the two lanes of the result last
+                #[inline(always)]
+                pub fn sum (self) -> $ty {
+                    let pair = self.reduce_pairs(-(0 as $ty), |x, y| x + y).into_array();
+                    pair[0] + pair[1]
+                }
+
+                /// Multiplies all the values inside the vector.
+                /// The pairs of lanes `(2k, 2k + 1)` are multiplied as vectors, in pairs of them, and the two lanes of the result last
+                #[inline(always)]
+                pub fn prod (self) -> $ty {
+                    let pair = self.reduce_pairs(1 as $ty, |x, y| x * y).into_array();
+                    pair[0] * pair[1]
+                }
+
+                /// Returns a vector with the smallest/minimum value of each lane.
+                /// The lane is `NaN` if any of them is `NaN`, and `-0` is smaller than `0`
+                #[inline(always)]
+                pub fn vmin (self, rhs: Self) -> Self {
+                    // equal lanes only differ in the sign of zero, which the `or` of their bits keeps if any of them has it
+                    let min = Self::select(self.cmp_lt(rhs), self, rhs);
+                    let min = Self::select(self.cmp_eq(rhs), self.bit_or(rhs), min);
+                    Self::select(self.cmp_eq(self).bit_and(rhs.cmp_eq(rhs)), min, Self::from($ty::NAN))
+                }
+
+                /// Returns a vector with the biggest/maximum value of each lane.
+                /// The lane is `NaN` if any of them is `NaN`, and `0` is bigger than `-0`
+                #[inline(always)]
+                pub fn vmax (self, rhs: Self) -> Self {
+                    // equal lanes only differ in the sign of zero, which the `and` of their bits drops if any of them lacks it
+                    let max = Self::select(rhs.cmp_lt(self), self, rhs);
+                    let max = Self::select(self.cmp_eq(rhs), self.bit_and(rhs), max);
+                    Self::select(self.cmp_eq(self).bit_and(rhs.cmp_eq(rhs)), max, Self::from($ty::NAN))
+                }
+
+                /// Multiply-add. Computes `(self * a) + b` with two rounding errors,
+                /// so that the result doesn't depend on the target supporting fused multiply-add
+                #[inline(always)]
+                pub fn mul_add (self, rhs: Self, add: Self) -> Self {
+                    (self * rhs) + add
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_deterministic_all {
+    () => {
+        impl_deterministic!(
+            [f32;2] as f32x2,
+            [f32;3] as f32x3,
+            [f32;4] as f32x4,
+            [f32;6] as f32x6,
+            [f32;8] as f32x8,
+            [f32;10] as f32x10,
+            [f32;12] as f32x12,
+            [f32;14] as f32x14,
+            [f32;16] as f32x16,
+            [f32;24] as f32x24,
+            [f32;32] as f32x32,
+
+            [f64;2] as f64x2,
+            [f64;3] as f64x3,
+            [f64;4] as f64x4,
+            [f64;6] as f64x6,
+            [f64;8] as f64x8,
+            [f64;10] as f64x10,
+            [f64;12] as f64x12,
+            [f64;14] as f64x14,
+            [f64;16] as f64x16,
+            [f64;24] as f64x24,
+            [f64;32] as f64x32
+        );
+    };
+}
+
+impl_deterministic_all!();
+
+// Naive vectors follow the same rules, so that they can be compared with the current implementation
+cfg_if::cfg_if! {
+    if #[cfg(all(not(feature = "force_naive"), any(
+        feature = "portable_simd",
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+        all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    )))] {
+        mod deterministic_naive {
+            use crate::naive::*;
+            impl_deterministic_all!();
+        }
+    }
+}
+
+// With AVX enabled, the SSE vectors wider than 128 bits are only reachable through `backend::sse`, but follow the same rules
+#[cfg(all(not(any(feature = "force_naive", feature = "portable_simd")), any(target_arch = "x86", target_arch = "x86_64"), feature = "use_avx", target_feature = "avx"))]
+mod deterministic_sse {
+    use crate::x86::sse::{
+        f32x6, f32x8, f32x10, f32x12, f32x14, f32x16, f32x24, f32x32,
+        f64x3, f64x4, f64x6, f64x8, f64x10, f64x12, f64x14, f64x16, f64x24, f64x32
+    };
+
+    impl_deterministic!(
+        [f32;6] as f32x6,
+        [f32;8] as f32x8,
+        [f32;10] as f32x10,
+        [f32;12] as f32x12,
+        [f32;14] as f32x14,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+
+        [f64;3] as f64x3,
+        [f64;4] as f64x4,
+        [f64;6] as f64x6,
+        [f64;8] as f64x8,
+        [f64;10] as f64x10,
+        [f64;12] as f64x12,
+        [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+}
@@ -1,6 +1,4 @@
 use core::fmt::Debug;
-use crate::float::single::*;
-use crate::float::double::*;
 use core::ptr::addr_of;
 use core::ops::*;
 
@@ -124,6 +122,7 @@ macro_rules! impl_index {
 
 impl_generic!(
     f32x2, f32, 2,
+    f32x3, f32, 3,
     f32x4, f32, 4,
     f32x6, f32, 6,
     f32x8, f32, 8,
@@ -131,15 +130,20 @@ impl_generic!(
     f32x12, f32, 12,
     f32x14, f32, 14,
     f32x16, f32, 16,
+    f32x24, f32, 24,
+    f32x32, f32, 32,
 
     f64x2, f64, 2,
+    f64x3, f64, 3,
     f64x4, f64, 4,
     f64x6, f64, 6,
     f64x8, f64, 8,
     f64x10, f64, 10,
     f64x12, f64, 12,
     f64x14, f64, 14,
-    f64x16, f64, 16
+    f64x16, f64, 16,
+    f64x24, f64, 24,
+    f64x32, f64, 32
 );
 
 impl_clone!(
@@ -150,6 +154,7 @@ impl_clone!(
 
 impl_index!(
     f32x2, f32, 2,
+    f32x3, f32, 3,
     f32x4, f32, 4,
     f32x6, f32, 6,
     f32x8, f32, 8,
@@ -157,13 +162,73 @@ impl_index!(
     f32x12, f32, 12,
     f32x14, f32, 14,
     f32x16, f32, 16,
+    f32x24, f32, 24,
+    f32x32, f32, 32,
 
     f64x2, f64, 2,
+    f64x3, f64, 3,
     f64x4, f64, 4,
     f64x6, f64, 6,
     f64x8, f64, 8,
     f64x10, f64, 10,
     f64x12, f64, 12,
     f64x14, f64, 14,
-    f64x16, f64, 16
-);
\ No newline at end of file
+    f64x16, f64, 16,
+    f64x24, f64, 24,
+    f64x32, f64, 32
+);
+// With AVX enabled, the SSE vectors wider than 128 bits aren't the exported ones, but are still reachable through `backend::sse`
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "use_avx", target_feature = "avx"))]
+mod generic_sse {
+    use core::fmt::Debug;
+    use core::ptr::addr_of;
+    use core::ops::*;
+    use crate::x86::sse::{
+        f32x6, f32x8, f32x10, f32x12, f32x14, f32x16, f32x24, f32x32,
+        f64x3, f64x4, f64x6, f64x8, f64x10, f64x12, f64x14, f64x16, f64x24, f64x32
+    };
+
+    impl_generic!(
+        f32x6, f32, 6,
+        f32x8, f32, 8,
+        f32x10, f32, 10,
+        f32x12, f32, 12,
+        f32x14, f32, 14,
+        f32x16, f32, 16,
+        f32x24, f32, 24,
+        f32x32, f32, 32,
+
+        f64x3, f64, 3,
+        f64x4, f64, 4,
+        f64x6, f64, 6,
+        f64x8, f64, 8,
+        f64x10, f64, 10,
+        f64x12, f64, 12,
+        f64x14, f64, 14,
+        f64x16, f64, 16,
+        f64x24, f64, 24,
+        f64x32, f64, 32
+    );
+
+    impl_index!(
+        f32x6, f32, 6,
+        f32x8, f32, 8,
+        f32x10, f32, 10,
+        f32x12, f32, 12,
+        f32x14, f32, 14,
+        f32x16, f32, 16,
+        f32x24, f32, 24,
+        f32x32, f32, 32,
+
+        f64x3, f64, 3,
+        f64x4, f64, 4,
+        f64x6, f64, 6,
+        f64x8, f64, 8,
+        f64x10, f64, 10,
+        f64x12, f64, 12,
+        f64x14, f64, 14,
+        f64x16, f64, 16,
+        f64x24, f64, 24,
+        f64x32, f64, 32
+    );
+}
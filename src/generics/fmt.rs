@@ -0,0 +1,120 @@
+/// Error returned when parsing a vector from a string fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseVectorError {
+    /// The string isn't enclosed between parentheses
+    Delimiters,
+
+    /// The string doesn't contain the expected number of lanes
+    LaneCount { expected: usize, found: usize },
+
+    /// The value of a lane couldn't be parsed as a float
+    Float { lane: usize, error: core::num::ParseFloatError }
+}
+
+impl core::fmt::Display for ParseVectorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Delimiters => f.write_str("vector must be enclosed between parentheses"),
+            Self::LaneCount { expected, found } => write!(f, "expected {expected} lanes, found {found}"),
+            Self::Float { lane, error } => write!(f, "invalid float at lane {lane}: {error}")
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl std::error::Error for ParseVectorError {
+    #[inline]
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Float { error, .. } => Some(error),
+            _ => None
+        }
+    }
+}
+
+macro_rules! impl_fmt {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            impl_fmt!(
+                @trait $target,
+                Display, LowerExp, UpperExp
+            );
+
+            impl core::str::FromStr for $target {
+                type Err = ParseVectorError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let inner = s.trim()
+                        .strip_prefix('(')
+                        .and_then(|x| x.strip_suffix(')'))
+                        .ok_or(ParseVectorError::Delimiters)?;
+
+                    if inner.trim().is_empty() {
+                        return Err(ParseVectorError::LaneCount { expected: $len, found: 0 })
+                    }
+
+                    let mut array = [0 as $ty; $len];
+                    let mut found = 0;
+
+                    for lane in inner.split(',') {
+                        if found < $len {
+                            array[found] = lane.trim().parse::<$ty>()
+                                .map_err(|error| ParseVectorError::Float { lane: found, error })?;
+                        }
+
+                        found += 1;
+                    }
+
+                    match found {
+                        $len => Ok(Self::from(array)),
+                        _ => Err(ParseVectorError::LaneCount { expected: $len, found })
+                    }
+                }
+            }
+        )*
+    };
+
+    (@trait $target:ident, $($trait:ident),+) => {
+        $(
+            impl core::fmt::$trait for $target {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    let array = self.into_array();
+                    f.write_str("(")?;
+
+                    for (i, lane) in array.iter().enumerate() {
+                        if i > 0 { f.write_str(", ")?; }
+                        core::fmt::$trait::fmt(lane, f)?;
+                    }
+
+                    f.write_str(")")
+                }
+            }
+        )*
+    };
+}
+
+impl_fmt!(
+    [f32;2] as f32x2,
+    [f32;3] as f32x3,
+    [f32;4] as f32x4,
+    [f32;6] as f32x6,
+    [f32;8] as f32x8,
+    [f32;10] as f32x10,
+    [f32;12] as f32x12,
+    [f32;14] as f32x14,
+    [f32;16] as f32x16,
+    [f32;24] as f32x24,
+    [f32;32] as f32x32,
+
+    [f64;2] as f64x2,
+    [f64;3] as f64x3,
+    [f64;4] as f64x4,
+    [f64;6] as f64x6,
+    [f64;8] as f64x8,
+    [f64;10] as f64x10,
+    [f64;12] as f64x12,
+    [f64;14] as f64x14,
+    [f64;16] as f64x16,
+    [f64;24] as f64x24,
+    [f64;32] as f64x32
+);
\ No newline at end of file
@@ -0,0 +1,107 @@
+macro_rules! impl_glam {
+    // Through arrays, for vectors of different sizes
+    ($($target:ident <=> $glam:ident),+) => {
+        $(
+            impl From<glam::$glam> for $target {
+                #[inline(always)]
+                fn from (x: glam::$glam) -> Self {
+                    Self::from(x.to_array())
+                }
+            }
+
+            impl From<$target> for glam::$glam {
+                #[inline(always)]
+                fn from (x: $target) -> Self {
+                    Self::from_array(x.into_array())
+                }
+            }
+        )*
+    };
+
+    // Vectors of the same size hold the same lanes in the same order, so no copy is needed
+    (@transmute $($target:ident <=> $glam:ident),+) => {
+        $(
+            impl From<glam::$glam> for $target {
+                #[inline(always)]
+                fn from (x: glam::$glam) -> Self {
+                    unsafe { core::mem::transmute(x) }
+                }
+            }
+
+            impl From<$target> for glam::$glam {
+                #[inline(always)]
+                fn from (x: $target) -> Self {
+                    unsafe { core::mem::transmute(x) }
+                }
+            }
+        )*
+    };
+
+    // glam's SIMD vectors wrap the same register as ours, with three-lane vectors padded to four
+    (@register) => {
+        impl From<glam::Vec4> for f32x4 {
+            #[inline(always)]
+            fn from (x: glam::Vec4) -> Self {
+                Self(x.into())
+            }
+        }
+
+        impl From<f32x4> for glam::Vec4 {
+            #[inline(always)]
+            fn from (x: f32x4) -> Self {
+                Self::from(x.0)
+            }
+        }
+
+        impl From<glam::Vec3A> for f32x3 {
+            #[inline(always)]
+            fn from (x: glam::Vec3A) -> Self {
+                Self(f32x4(x.into()))
+            }
+        }
+
+        impl From<f32x3> for glam::Vec3A {
+            #[inline(always)]
+            fn from (x: f32x3) -> Self {
+                Self::from(x.0.0)
+            }
+        }
+    };
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(feature = "force_naive", not(any(
+        feature = "portable_simd",
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+        all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    ))))] {
+        impl_glam!(@transmute f32x2 <=> Vec2, f32x4 <=> Vec4, f64x3 <=> DVec3);
+        impl_glam!(f32x3 <=> Vec3A);
+    } else if #[cfg(feature = "portable_simd")] {
+        impl_glam!(@transmute f32x2 <=> Vec2, f32x4 <=> Vec4);
+        impl_glam!(f32x3 <=> Vec3A, f64x3 <=> DVec3);
+    } else if #[cfg(any(
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse2"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    ))] {
+        cfg_if::cfg_if! {
+            if #[cfg(feature = "glam_scalar_math")] {
+                impl_glam!(@transmute f32x3 <=> Vec3A, f32x4 <=> Vec4);
+            } else {
+                impl_glam!(@register);
+            }
+        }
+
+        impl_glam!(f32x2 <=> Vec2, f64x3 <=> DVec3);
+    } else if #[cfg(all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"))] {
+        impl_glam!(@transmute f32x2 <=> Vec2, f32x3 <=> Vec3A, f32x4 <=> Vec4);
+        impl_glam!(f64x3 <=> DVec3);
+    } else {
+        // SSE without SSE2, where glam uses its scalar vectors
+        impl_glam!(@transmute f32x3 <=> Vec3A, f32x4 <=> Vec4);
+        impl_glam!(f32x2 <=> Vec2, f64x3 <=> DVec3);
+    }
+}
+
+impl_glam!(@transmute f64x2 <=> DVec2, f64x4 <=> DVec4);
@@ -0,0 +1,230 @@
+macro_rules! impl_int_generic {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            impl $target {
+                #[inline(always)]
+                pub fn new (a: [$ty;$len]) -> Self {
+                    Self::from(a)
+                }
+
+                /// Creates a new vector with all lanes filled with the provided value
+                #[inline(always)]
+                pub fn filled_with (a: $ty) -> Self {
+                    Self::from(a)
+                }
+
+                /// Returns an array with the vector's values
+                #[inline(always)]
+                pub fn into_array (self) -> [$ty;$len] {
+                    self.into()
+                }
+
+                /// Returns a reference to the value in the specified lane without checking if it's within range
+                #[inline(always)]
+                pub unsafe fn get_unchecked (&self, idx: usize) -> &$ty {
+                    &*(self as *const Self as *const $ty).add(idx)
+                }
+
+                /// Returns a mutable reference to the value in the specified lane without checking if it's within range
+                #[inline(always)]
+                pub unsafe fn get_mut_unchecked (&mut self, idx: usize) -> &mut $ty {
+                    &mut *(self as *mut Self as *mut $ty).add(idx)
+                }
+
+                /// Returns a vector with the wrapping addition of each lane
+                #[inline(always)]
+                pub fn wrapping_add (self, rhs: Self) -> Self {
+                    self + rhs
+                }
+
+                /// Returns a vector with the wrapping subtraction of each lane
+                #[inline(always)]
+                pub fn wrapping_sub (self, rhs: Self) -> Self {
+                    self - rhs
+                }
+            }
+
+            impl core::fmt::Debug for $target {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_list().entries(self.into_array()).finish()
+                }
+            }
+
+            impl Default for $target {
+                #[inline(always)]
+                fn default() -> Self {
+                    Self::from(0 as $ty)
+                }
+            }
+
+            impl PartialEq for $target {
+                #[inline(always)]
+                fn eq (&self, other: &Self) -> bool {
+                    self.into_array() == other.into_array()
+                }
+            }
+
+            impl Eq for $target {}
+
+            impl core::hash::Hash for $target {
+                #[inline]
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    self.into_array().hash(state)
+                }
+            }
+
+            impl core::ops::Index<usize> for $target {
+                type Output = $ty;
+
+                #[inline(always)]
+                fn index (&self, idx: usize) -> &Self::Output {
+                    assert!(idx < $len, "Index out of bounds");
+                    unsafe { self.get_unchecked(idx) }
+                }
+            }
+
+            impl core::ops::IndexMut<usize> for $target {
+                #[inline(always)]
+                fn index_mut (&mut self, idx: usize) -> &mut Self::Output {
+                    assert!(idx < $len, "Index out of bounds");
+                    unsafe { self.get_mut_unchecked(idx) }
+                }
+            }
+
+            impl From<[$ty;$len]> for $target {
+                #[inline(always)]
+                fn from (x: [$ty;$len]) -> Self {
+                    unsafe { Self::load(core::ptr::addr_of!(x).cast()) }
+                }
+            }
+
+            impl Into<[$ty;$len]> for $target {
+                #[inline(always)]
+                fn into (self) -> [$ty;$len] {
+                    unsafe { *(core::ptr::addr_of!(self) as *const [$ty;$len]) }
+                }
+            }
+        )*
+    };
+}
+
+// Multiplication and shifts are only available on 64-bit lanes, and shifts take `u32` amounts
+macro_rules! impl_int_assign {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            impl core::ops::MulAssign for $target {
+                #[inline(always)]
+                fn mul_assign (&mut self, rhs: Self) {
+                    *self = *self * rhs
+                }
+            }
+
+            impl core::ops::MulAssign<$ty> for $target {
+                #[inline(always)]
+                fn mul_assign (&mut self, rhs: $ty) {
+                    *self = *self * rhs
+                }
+            }
+
+            impl_int_assign!(@shift $target, Self, u32);
+        )*
+    };
+
+    (@shift $target:ident, $($rhs:ty),+) => {
+        $(
+            impl core::ops::ShlAssign<$rhs> for $target {
+                #[inline(always)]
+                fn shl_assign (&mut self, rhs: $rhs) {
+                    *self = *self << rhs
+                }
+            }
+
+            impl core::ops::ShrAssign<$rhs> for $target {
+                #[inline(always)]
+                fn shr_assign (&mut self, rhs: $rhs) {
+                    *self = *self >> rhs
+                }
+            }
+        )*
+    };
+}
+
+// Conversions to floats of the narrower lanes go through their widened 32-bit lanes
+macro_rules! impl_int_to_float {
+    ($($target:ident => [$float:ident; 2]),+) => {
+        $(
+            impl $target {
+                /// Converts every lane into a single-precision float
+                #[inline(always)]
+                pub fn to_f32 (self) -> [$float;2] {
+                    let (lo, hi) = self.widen();
+                    [lo.to_f32(), hi.to_f32()]
+                }
+            }
+        )*
+    };
+
+    ($($target:ident => [$float:ident; 4]),+) => {
+        $(
+            impl $target {
+                /// Converts every lane into a single-precision float
+                #[inline(always)]
+                pub fn to_f32 (self) -> [$float;4] {
+                    let (lo, hi) = self.widen();
+                    let ([a, b], [c, d]) = (lo.to_f32(), hi.to_f32());
+                    [a, b, c, d]
+                }
+            }
+        )*
+    };
+}
+
+impl_int_generic!(
+    [i8;16] as i8x16,
+    [u8;16] as u8x16,
+    [i16;8] as i16x8,
+    [u16;8] as u16x8,
+    [i32;4] as i32x4,
+    [u32;4] as u32x4,
+
+    [i8;32] as i8x32,
+    [u8;32] as u8x32,
+    [i16;16] as i16x16,
+    [u16;16] as u16x16,
+    [i32;8] as i32x8,
+    [u32;8] as u32x8,
+
+    [i64;2] as i64x2,
+    [u64;2] as u64x2,
+    [i64;4] as i64x4,
+    [u64;4] as u64x4,
+    [i64;8] as i64x8,
+    [u64;8] as u64x8,
+    [i64;16] as i64x16,
+    [u64;16] as u64x16
+);
+
+impl_int_assign!(
+    [i64;2] as i64x2,
+    [u64;2] as u64x2,
+    [i64;4] as i64x4,
+    [u64;4] as u64x4,
+    [i64;8] as i64x8,
+    [u64;8] as u64x8,
+    [i64;16] as i64x16,
+    [u64;16] as u64x16
+);
+
+impl_int_to_float!(
+    i16x8 => [f32x4; 2],
+    u16x8 => [f32x4; 2],
+    i16x16 => [f32x8; 2],
+    u16x16 => [f32x8; 2]
+);
+
+impl_int_to_float!(
+    i8x16 => [f32x4; 4],
+    u8x16 => [f32x4; 4],
+    i8x32 => [f32x8; 4],
+    u8x32 => [f32x8; 4]
+);
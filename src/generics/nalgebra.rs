@@ -0,0 +1,58 @@
+macro_rules! impl_nalgebra {
+    // Through arrays, for vectors of different sizes
+    ($($target:ident <=> $vec:ident<$ty:ident>),+) => {
+        $(
+            impl From<nalgebra::$vec<$ty>> for $target {
+                #[inline(always)]
+                fn from (x: nalgebra::$vec<$ty>) -> Self {
+                    Self::from(x.data.0[0])
+                }
+            }
+
+            impl From<$target> for nalgebra::$vec<$ty> {
+                #[inline(always)]
+                fn from (x: $target) -> Self {
+                    Self::from(x.into_array())
+                }
+            }
+        )*
+    };
+
+    // nalgebra's vectors are `repr(C)` arrays, so vectors of the same size hold the same lanes in the same order
+    (@transmute $($target:ident <=> $vec:ident<$ty:ident>),+) => {
+        $(
+            impl From<nalgebra::$vec<$ty>> for $target {
+                #[inline(always)]
+                fn from (x: nalgebra::$vec<$ty>) -> Self {
+                    unsafe { core::mem::transmute(x) }
+                }
+            }
+
+            impl From<$target> for nalgebra::$vec<$ty> {
+                #[inline(always)]
+                fn from (x: $target) -> Self {
+                    unsafe { core::mem::transmute(x) }
+                }
+            }
+        )*
+    };
+}
+
+cfg_if::cfg_if! {
+    if #[cfg(any(feature = "force_naive", not(any(
+        feature = "portable_simd",
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+        all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    ))))] {
+        impl_nalgebra!(@transmute f32x2 <=> Vector2<f32>, f32x3 <=> Vector3<f32>, f64x3 <=> Vector3<f64>);
+    } else if #[cfg(any(feature = "portable_simd", all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon")))] {
+        impl_nalgebra!(@transmute f32x2 <=> Vector2<f32>);
+        impl_nalgebra!(f32x3 <=> Vector3<f32>, f64x3 <=> Vector3<f64>);
+    } else {
+        // Two and three-lane vectors are padded
+        impl_nalgebra!(f32x2 <=> Vector2<f32>, f32x3 <=> Vector3<f32>, f64x3 <=> Vector3<f64>);
+    }
+}
+
+impl_nalgebra!(@transmute f32x4 <=> Vector4<f32>, f64x2 <=> Vector2<f64>, f64x4 <=> Vector4<f64>);
@@ -0,0 +1,125 @@
+macro_rules! impl_num {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            impl num_traits::Zero for $target {
+                #[inline(always)]
+                fn zero() -> Self {
+                    Self::from(0 as $ty)
+                }
+
+                /// Returns `true` if every lane is equal to zero
+                #[inline(always)]
+                fn is_zero(&self) -> bool {
+                    *self == Self::zero()
+                }
+            }
+
+            impl num_traits::One for $target {
+                #[inline(always)]
+                fn one() -> Self {
+                    Self::from(1 as $ty)
+                }
+            }
+
+            impl num_traits::Num for $target {
+                type FromStrRadixErr = <$ty as num_traits::Num>::FromStrRadixErr;
+
+                /// Parses a scalar in the specified radix, filling every lane with it
+                #[inline]
+                fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                    <$ty as num_traits::Num>::from_str_radix(str, radix).map(Self::from)
+                }
+            }
+
+            impl num_traits::Signed for $target {
+                #[cfg(feature = "use_std")]
+                #[inline(always)]
+                fn abs(&self) -> Self {
+                    $target::abs(*self)
+                }
+
+                #[cfg(not(feature = "use_std"))]
+                #[inline]
+                fn abs(&self) -> Self {
+                    Self::from(self.into_array().map(num_traits::float::FloatCore::abs))
+                }
+
+                #[inline(always)]
+                fn abs_sub(&self, other: &Self) -> Self {
+                    (*self - *other).vmax(num_traits::Zero::zero())
+                }
+
+                #[inline]
+                fn signum(&self) -> Self {
+                    Self::from(self.into_array().map(num_traits::float::FloatCore::signum))
+                }
+
+                /// Returns `true` if every lane is positive
+                #[inline]
+                fn is_positive(&self) -> bool {
+                    self.into_array().iter().all(|x| num_traits::Signed::is_positive(x))
+                }
+
+                /// Returns `true` if every lane is negative
+                #[inline]
+                fn is_negative(&self) -> bool {
+                    self.into_array().iter().all(|x| num_traits::Signed::is_negative(x))
+                }
+            }
+
+            impl num_traits::Bounded for $target {
+                #[inline(always)]
+                fn min_value() -> Self {
+                    Self::from($ty::MIN)
+                }
+
+                #[inline(always)]
+                fn max_value() -> Self {
+                    Self::from($ty::MAX)
+                }
+            }
+
+            impl num_traits::MulAdd for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul_add(self, a: Self, b: Self) -> Self::Output {
+                    $target::mul_add(self, a, b)
+                }
+            }
+
+            impl num_traits::MulAddAssign for $target {
+                #[inline(always)]
+                fn mul_add_assign(&mut self, a: Self, b: Self) {
+                    *self = $target::mul_add(*self, a, b)
+                }
+            }
+        )*
+    };
+}
+
+impl_num!(
+    [f32;2] as f32x2,
+    [f32;3] as f32x3,
+    [f32;4] as f32x4,
+    [f32;6] as f32x6,
+    [f32;8] as f32x8,
+    [f32;10] as f32x10,
+    [f32;12] as f32x12,
+    [f32;14] as f32x14,
+    [f32;16] as f32x16,
+    [f32;24] as f32x24,
+    [f32;32] as f32x32,
+
+    [f64;2] as f64x2,
+    [f64;3] as f64x3,
+    [f64;4] as f64x4,
+    [f64;6] as f64x6,
+    [f64;8] as f64x8,
+    [f64;10] as f64x10,
+    [f64;12] as f64x12,
+    [f64;14] as f64x14,
+    [f64;16] as f64x16,
+    [f64;24] as f64x24,
+    [f64;32] as f64x32
+);
\ No newline at end of file
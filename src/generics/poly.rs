@@ -0,0 +1,56 @@
+macro_rules! impl_poly {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            impl $target {
+                /// Evaluates the polynomial `coeffs[0] + coeffs[1] * x + coeffs[2] * x^2 + ...` on every lane, using Horner's scheme.
+                /// Every step is a ```mul_add```, so it's fused whenever the target supports it
+                #[inline]
+                pub fn poly_eval (self, coeffs: &[$ty]) -> Self {
+                    match coeffs.split_last() {
+                        Some((last, rest)) => rest.iter().rev().fold(Self::from(*last), |acc, c| acc.mul_add(self, Self::from(*c))),
+                        None => Self::from(0 as $ty)
+                    }
+                }
+
+                /// Evaluates the polynomial `coeffs[0] + coeffs[1] * x + coeffs[2] * x^2 + ...` on every lane, using Estrin's scheme.
+                /// Independent terms are evaluated in parallel, which shortens the dependency chain of higher degree polynomials.
+                /// Every step is a ```mul_add```, so it's fused whenever the target supports it
+                #[inline]
+                pub fn poly<const N: usize> (self, coeffs: [$ty;N]) -> Self {
+                    if N == 0 {
+                        return Self::from(0 as $ty)
+                    }
+
+                    // pairs of terms are combined as `a + b * x`, then as `a + b * x^2`, `a + b * x^4`...
+                    reduce_levels(coeffs.map(Self::from), self, |power, a, b| b.mul_add(*power, a), |power| power * power)
+                }
+            }
+        )*
+    };
+}
+
+impl_poly!(
+    [f32;2] as f32x2,
+    [f32;3] as f32x3,
+    [f32;4] as f32x4,
+    [f32;6] as f32x6,
+    [f32;8] as f32x8,
+    [f32;10] as f32x10,
+    [f32;12] as f32x12,
+    [f32;14] as f32x14,
+    [f32;16] as f32x16,
+    [f32;24] as f32x24,
+    [f32;32] as f32x32,
+
+    [f64;2] as f64x2,
+    [f64;3] as f64x3,
+    [f64;4] as f64x4,
+    [f64;6] as f64x6,
+    [f64;8] as f64x8,
+    [f64;10] as f64x10,
+    [f64;12] as f64x12,
+    [f64;14] as f64x14,
+    [f64;16] as f64x16,
+    [f64;24] as f64x24,
+    [f64;32] as f64x32
+);
@@ -0,0 +1,182 @@
+// Prefix scans.
+// Vectors held in registers combine every lane with the one `shift` lanes below it, for doubling shifts (Hillis-Steele),
+// moving their lanes up with `shift_up`, which fills the lanes left at the bottom from its `fill` vector.
+// Composites scan each of their parts, and carry the last lane of every part into the next one
+macro_rules! impl_scan {
+    (@fns $($fun:ident => $identity:expr, $op:expr, $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a vector where every lane is the ", $docs, " of itself and all the lanes before it")]
+            #[inline]
+            pub fn $fun (self) -> Self {
+                self.scan($identity, $op)
+            }
+        )*
+    };
+
+    (@lanes $ty:ident, $len:literal) => {
+        #[inline(always)]
+        fn scan (self, identity: $ty, op: impl Fn(Self, Self) -> Self) -> Self {
+            let fill = Self::from(identity);
+            let mut result = self;
+            let mut shift = 1;
+
+            while shift < $len {
+                result = op(result, result.shift_up(shift, fill));
+                shift *= 2;
+            }
+
+            result
+        }
+
+        /// Returns a vector where every lane is the sum of all the lanes before it, starting at zero
+        #[inline]
+        pub fn prefix_sum_exclusive (self) -> Self {
+            self.prefix_sum().shift_up(1, Self::from(0 as $ty))
+        }
+    };
+
+    // Register-level float vectors
+    (@float $ty:ident, $len:literal) => {
+        impl_scan!(@lanes $ty, $len);
+        impl_scan!(
+            @fns
+            prefix_sum => 0 as $ty, core::ops::Add::add, "sum",
+            prefix_prod => 1 as $ty, core::ops::Mul::mul, "product",
+            prefix_max => $ty::NEG_INFINITY, Self::vmax, "maximum",
+            prefix_min => $ty::INFINITY, Self::vmin, "minimum"
+        );
+    };
+
+    // Register-level integer vectors. Only the 64-bit ones multiply lane-wise, so only they add `prefix_prod` with `@prod`
+    (@int $ty:ident, $len:literal) => {
+        impl_scan!(@lanes $ty, $len);
+        impl_scan!(
+            @fns
+            prefix_sum => 0, core::ops::Add::add, "wrapping sum",
+            prefix_max => $ty::MIN, Self::vmax, "maximum",
+            prefix_min => $ty::MAX, Self::vmin, "minimum"
+        );
+    };
+
+    (@prod) => {
+        impl_scan!(@fns prefix_prod => 1, core::ops::Mul::mul, "wrapping product");
+    };
+
+    // Composites, given their parts as `(index, type)`
+    (@carry $parts:tt, $($fun:ident => $op:ident, $docs:expr),+) => {
+        $(
+            impl_scan!(@carry_one $parts, $fun, $op, $docs);
+        )*
+    };
+
+    (@carry_one [$(($i:tt, $x:ident))+], $fun:ident, $op:ident, $docs:expr) => {
+        #[doc=concat!("Returns a vector where every lane is the ", $docs, " of itself and all the lanes before it")]
+        #[inline]
+        pub fn $fun (self) -> Self {
+            let mut result = Self($(self.$i.$fun()),+);
+            impl_scan!(@carry_parts result, result, $op, [$(($i, $x))+]);
+            result
+        }
+    };
+
+    (@exclusive [$(($i:tt, $x:ident))+]) => {
+        /// Returns a vector where every lane is the sum of all the lanes before it, starting at zero
+        #[inline]
+        pub fn prefix_sum_exclusive (self) -> Self {
+            let inclusive = self.prefix_sum();
+            let mut result = Self($(self.$i.prefix_sum_exclusive()),+);
+            impl_scan!(@carry_parts result, inclusive, add, [$(($i, $x))+]);
+            result
+        }
+    };
+
+    // Three-lane vectors padded to four, whose padding lane comes last and never reaches the others
+    (@padded) => {
+        impl_scan!(
+            @padded_fns
+            prefix_sum: "sum",
+            prefix_prod: "product",
+            prefix_max: "maximum",
+            prefix_min: "minimum"
+        );
+
+        /// Returns a vector where every lane is the sum of all the lanes before it, starting at zero
+        #[inline]
+        pub fn prefix_sum_exclusive (self) -> Self {
+            Self(self.0.prefix_sum_exclusive())
+        }
+    };
+
+    (@padded_fns $($fun:ident: $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a vector where every lane is the ", $docs, " of itself and all the lanes before it")]
+            #[inline]
+            pub fn $fun (self) -> Self {
+                Self(self.0.$fun())
+            }
+        )*
+    };
+
+    // Combines every part of `$dst` with the last lane of the previous part of `$src`, in order
+    (@carry_parts $dst:ident, $src:ident, $op:ident, [($p:tt, $px:ident) ($i:tt, $x:ident) $($rest:tt)*]) => {
+        let last = $src.$p.into_array();
+        $dst.$i = $dst.$i.$op(<$x>::from(last[last.len() - 1]));
+        impl_scan!(@carry_parts $dst, $src, $op, [($i, $x) $($rest)*]);
+    };
+
+    (@carry_parts $dst:ident, $src:ident, $op:ident, [($p:tt, $px:ident)]) => {};
+
+    (@cumsum $([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            impl $target {
+                /// Replaces every value of the slice with the sum of itself and all the values before it, returning the total sum.
+                /// The running total is carried from one chunk of lanes to the next
+                #[inline]
+                pub fn cumsum (slice: &mut [$ty]) -> $ty {
+                    let mut total = 0 as $ty;
+                    let mut chunks = slice.chunks_exact_mut($len);
+
+                    for chunk in &mut chunks {
+                        let sums = (unsafe { Self::load(chunk.as_ptr()) }.prefix_sum() + total).into_array();
+                        chunk.copy_from_slice(&sums);
+                        total = sums[$len - 1];
+                    }
+
+                    for x in chunks.into_remainder() {
+                        total += *x;
+                        *x = total;
+                    }
+
+                    total
+                }
+            }
+        )*
+    };
+}
+
+impl_scan!(
+    @cumsum
+    [f32;2] as f32x2,
+    [f32;3] as f32x3,
+    [f32;4] as f32x4,
+    [f32;6] as f32x6,
+    [f32;8] as f32x8,
+    [f32;10] as f32x10,
+    [f32;12] as f32x12,
+    [f32;14] as f32x14,
+    [f32;16] as f32x16,
+    [f32;24] as f32x24,
+    [f32;32] as f32x32,
+
+    [f64;2] as f64x2,
+    [f64;3] as f64x3,
+    [f64;4] as f64x4,
+    [f64;6] as f64x6,
+    [f64;8] as f64x8,
+    [f64;10] as f64x10,
+    [f64;12] as f64x12,
+    [f64;14] as f64x14,
+    [f64;16] as f64x16,
+    [f64;24] as f64x24,
+    [f64;32] as f64x32
+);
@@ -1,8 +1,6 @@
 use serde::Deserialize;
 use serde::Serialize;
 use serde::ser::SerializeSeq;
-use crate::float::single::*;
-use crate::float::double::*;
 
 macro_rules! impl_ser {
     ($([$ty:ident;$len:literal] as $target:ident),+) => {
@@ -46,6 +44,7 @@ macro_rules! impl_serde {
 
 impl_serde!(
     [f32;2] as f32x2,
+    [f32;3] as f32x3,
     [f32;4] as f32x4,
     [f32;6] as f32x6,
     [f32;8] as f32x8,
@@ -53,13 +52,18 @@ impl_serde!(
     [f32;12] as f32x12,
     [f32;14] as f32x14,
     [f32;16] as f32x16,
+    [f32;24] as f32x24,
+    [f32;32] as f32x32,
 
     [f64;2] as f64x2,
+    [f64;3] as f64x3,
     [f64;4] as f64x4,
     [f64;6] as f64x6,
     [f64;8] as f64x8,
     [f64;10] as f64x10,
     [f64;12] as f64x12,
     [f64;14] as f64x14,
-    [f64;16] as f64x16
+    [f64;16] as f64x16,
+    [f64;24] as f64x24,
+    [f64;32] as f64x32
 );
\ No newline at end of file
@@ -0,0 +1,152 @@
+/// Runs a bitonic sorting network over the `N` lanes of `v`. Every stage pairs each lane `i` with the lane `i ^ j` given by `partner`,
+/// and `merge` keeps the minimum of each pair on the lanes where `lo` is set, and the maximum on the rest
+#[inline(always)]
+fn bitonic_network<V: Copy, const N: usize> (mut v: V, desc: bool, partner: impl Fn(V, usize) -> V, merge: impl Fn(V, V, [bool;N]) -> V) -> V {
+    let mut k = 2;
+    while k <= N {
+        let mut j = k / 2;
+        while j > 0 {
+            // the lower lane of each pair keeps the minimum on ascending blocks, and the maximum on descending ones
+            let lo = core::array::from_fn(|i| ((i & k == 0) == (i & j == 0)) != desc);
+            v = merge(v, partner(v, j), lo);
+            j /= 2;
+        }
+
+        k *= 2;
+    }
+
+    v
+}
+
+macro_rules! impl_sort {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            impl $target {
+                // Sets every lane of the mask where `lanes` is set
+                #[inline(always)]
+                fn lane_mask (lanes: [bool;$len]) -> Self {
+                    Self::from(lanes.map(|set| if set { $ty::from_bits(!0) } else { 0 as $ty }))
+                }
+
+                // Sets the lanes that are smaller than those of `rhs` on the total ordering of floats, built from float comparisons alone.
+                // Magnitudes compare like their bits, except for `NaN`, which is bigger than every number and compares its payload as a subnormal
+                #[inline(always)]
+                fn total_lt (self, rhs: Self) -> Self {
+                    let (zero, ones) = (Self::from(0 as $ty), Self::from($ty::from_bits(!0)));
+                    let sign = |x: Self| Self::from(1 as $ty).bit_or(x.bit_and(Self::from(-(0 as $ty)))).cmp_lt(zero);
+                    let payload = |x: Self| x.bit_and(Self::from($ty::from_bits($ty::MIN_POSITIVE.to_bits() - 1)));
+
+                    let (nan_a, nan_b) = (self.cmp_eq(self).bit_andnot(ones), rhs.cmp_eq(rhs).bit_andnot(ones));
+                    let mag_lt = |a: Self, b: Self, nan_a: Self, nan_b: Self| {
+                        let numbers = Self::select(nan_b, ones, a.abs().cmp_lt(b.abs()));
+                        Self::select(nan_a, nan_b.bit_and(payload(a).cmp_lt(payload(b))), numbers)
+                    };
+
+                    let (sign_a, sign_b) = (sign(self), sign(rhs));
+                    let same_sign = Self::select(sign_a, sign_b, sign_b.bit_andnot(ones));
+                    let lt = Self::select(sign_a, mag_lt(rhs, self, nan_b, nan_a), mag_lt(self, rhs, nan_a, nan_b));
+                    Self::select(same_sign, lt, sign_a)
+                }
+
+                #[inline(always)]
+                fn sort_network (self, desc: bool) -> Self {
+                    bitonic_network(self, desc, Self::xor_lanes, |alpha, beta, lo| {
+                        Self::select(Self::lane_mask(lo), alpha.vmin(beta), alpha.vmax(beta))
+                    })
+                }
+
+                #[inline(always)]
+                fn sort_network_total (self, desc: bool) -> Self {
+                    bitonic_network(self, desc, Self::xor_lanes, |alpha, beta, lo| {
+                        let swap = beta.total_lt(alpha);
+                        let (min, max) = (Self::select(swap, beta, alpha), Self::select(swap, alpha, beta));
+                        Self::select(Self::lane_mask(lo), min, max)
+                    })
+                }
+
+                // `NaN` lanes would be dropped by `vmin`/`vmax`, so those vectors go through the total ordering instead
+                #[inline(always)]
+                fn has_nan (self) -> bool {
+                    self.cmp_eq(self).bit_andnot(Self::from($ty::from_bits(!0))).any_lane()
+                }
+
+                /// Returns a vector with the lanes sorted in ascending order.
+                /// If any lane is `NaN`, the lanes are sorted like ```sort_lanes_total``` does instead
+                #[inline]
+                pub fn sort_lanes (self) -> Self {
+                    if self.has_nan() { self.sort_network_total(false) } else { self.sort_network(false) }
+                }
+
+                /// Returns a vector with the lanes sorted in descending order.
+                /// If any lane is `NaN`, the lanes are sorted like ```sort_lanes_total_desc``` does instead
+                #[inline]
+                pub fn sort_lanes_desc (self) -> Self {
+                    if self.has_nan() { self.sort_network_total(true) } else { self.sort_network(true) }
+                }
+
+                /// Returns a vector with the lanes sorted in ascending order, following the total ordering of floats (see ```total_cmp```).
+                /// Negative `NaN`s are placed first, and positive ones last
+                #[inline]
+                pub fn sort_lanes_total (self) -> Self {
+                    self.sort_network_total(false)
+                }
+
+                /// Returns a vector with the lanes sorted in descending order, following the total ordering of floats (see ```total_cmp```).
+                /// Positive `NaN`s are placed first, and negative ones last
+                #[inline]
+                pub fn sort_lanes_total_desc (self) -> Self {
+                    self.sort_network_total(true)
+                }
+
+                /// Returns the median of the lanes, which is the mean of the two middle values
+                #[inline]
+                pub fn median (self) -> $ty {
+                    let sorted = self.sort_lanes().into_array();
+                    (sorted[$len / 2 - 1] + sorted[$len / 2]) / 2.
+                }
+
+                /// Returns the `K` smallest lanes, in ascending order
+                /// # Panics
+                #[doc=concat!("Panics if `K` is bigger than ", $len)]
+                #[inline]
+                pub fn partial_sort<const K: usize> (self) -> [$ty;K] {
+                    assert!(K <= $len, "K must be less than or equal to the number of lanes");
+                    let sorted = self.sort_lanes().into_array();
+                    core::array::from_fn(|i| sorted[i])
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_sort_all {
+    () => {
+        impl_sort!(
+            [f32;4] as f32x4,
+            [f32;8] as f32x8,
+            [f32;16] as f32x16,
+
+            [f64;4] as f64x4,
+            [f64;8] as f64x8,
+            [f64;16] as f64x16
+        );
+    };
+}
+
+impl_sort_all!();
+
+// Naive vectors are sorted the same way, so that they can be compared with the current implementation
+cfg_if::cfg_if! {
+    if #[cfg(all(not(feature = "force_naive"), any(
+        feature = "portable_simd",
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+        all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    )))] {
+        mod sort_naive {
+            use crate::naive::*;
+            use super::bitonic_network;
+            impl_sort_all!();
+        }
+    }
+}
@@ -0,0 +1,26 @@
+// Reduces the values in pairs, level by level, always following the same tree: `((x0 ∘ x1) ∘ (x2 ∘ x3)) ∘ ...`.
+// The state is passed to every combination of a level, and advanced with `next` before the following one
+#[inline(always)]
+fn reduce_levels<T: Copy, S, F: Fn(&S, T, T) -> T, G: Fn(S) -> S, const N: usize> (mut values: [T;N], mut state: S, f: F, next: G) -> T {
+    let mut len = N;
+    while len > 1 {
+        for i in 0..len / 2 {
+            values[i] = f(&state, values[2 * i], values[2 * i + 1]);
+        }
+
+        if len % 2 == 1 {
+            values[len / 2] = values[len - 1];
+        }
+
+        len = (len + 1) / 2;
+        state = next(state);
+    }
+
+    values[0]
+}
+
+// Reduces the values in pairs, always following the same tree: `((x0 ∘ x1) ∘ (x2 ∘ x3)) ∘ ...`
+#[inline(always)]
+fn reduce_tree<T: Copy, F: Fn(T, T) -> T, const N: usize> (values: [T;N], f: F) -> T {
+    reduce_levels(values, (), |_, x, y| f(x, y), |_| ())
+}
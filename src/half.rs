@@ -0,0 +1,289 @@
+use core::fmt::Debug;
+use crate::float::single::*;
+
+cfg_if::cfg_if! {
+    if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "f16c"))] {
+        use core::mem::transmute;
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::*;
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::*;
+    }
+}
+
+macro_rules! impl_half {
+    ($($name:ident => $target:ident, $len:literal, $to:ident, $from:ident, $docs:expr),+) => {
+        $(
+            #[doc=concat!("Vector of ", $len, " ", $docs, " values, stored by their bits")]
+            #[allow(non_camel_case_types)]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+            pub struct $name([u16;$len]);
+
+            impl $name {
+                /// Creates a new vector from the bits of each lane
+                #[inline(always)]
+                pub const fn from_bits (bits: [u16;$len]) -> Self {
+                    Self(bits)
+                }
+
+                /// Returns the bits of each lane
+                #[inline(always)]
+                pub const fn to_bits (self) -> [u16;$len] {
+                    self.0
+                }
+
+                #[doc=concat!("Converts every lane into a single-precision float (```", stringify!($target), "```)")]
+                #[inline]
+                pub fn to_f32 (self) -> $target {
+                    $name::$to(self)
+                }
+
+                #[doc=concat!("Converts every lane of a ```", stringify!($target), "``` into ", $docs, ", rounding to the nearest value (ties to even)")]
+                #[inline]
+                pub fn from_f32 (x: $target) -> Self {
+                    $name::$from(x)
+                }
+            }
+
+            impl Debug for $name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_list().entries(self.to_f32().into_array()).finish()
+                }
+            }
+
+            impl From<$target> for $name {
+                #[inline(always)]
+                fn from (x: $target) -> Self {
+                    Self::from_f32(x)
+                }
+            }
+
+            impl From<$name> for $target {
+                #[inline(always)]
+                fn from (x: $name) -> Self {
+                    x.to_f32()
+                }
+            }
+        )*
+    };
+
+    (@software $($name:ident => $target:ident, $len:literal, $to:ident, $from:ident, $lane_to:ident, $lane_from:ident),+) => {
+        $(
+            impl $name {
+                #[inline(always)]
+                fn $to (self) -> $target {
+                    let mut array = [0f32; $len];
+                    for i in 0..$len {
+                        array[i] = $lane_to(self.0[i]);
+                    }
+
+                    $target::from(array)
+                }
+
+                #[inline(always)]
+                fn $from (x: $target) -> Self {
+                    let x = x.into_array();
+                    let mut array = [0u16; $len];
+                    for i in 0..$len {
+                        array[i] = $lane_from(x[i]);
+                    }
+
+                    Self(array)
+                }
+            }
+        )*
+    };
+}
+
+impl_half!(
+    f16x4 => f32x4, 4, to_f32_f16, from_f32_f16, "half-precision (IEEE 754 binary16)",
+    f16x8 => f32x8, 8, to_f32_f16, from_f32_f16, "half-precision (IEEE 754 binary16)",
+    bf16x4 => f32x4, 4, to_f32_bf16, from_f32_bf16, "bfloat16",
+    bf16x8 => f32x8, 8, to_f32_bf16, from_f32_bf16, "bfloat16"
+);
+
+impl_half!(
+    @software
+    bf16x4 => f32x4, 4, to_f32_bf16, from_f32_bf16, bf16_to_f32_lane, f32_to_bf16_lane,
+    bf16x8 => f32x8, 8, to_f32_bf16, from_f32_bf16, bf16_to_f32_lane, f32_to_bf16_lane
+);
+
+cfg_if::cfg_if! {
+    if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "f16c"))] {
+        impl f16x4 {
+            #[inline(always)]
+            fn to_f32_f16 (self) -> f32x4 {
+                unsafe {
+                    let bits = _mm_loadl_epi64(self.0.as_ptr().cast());
+                    f32x4::from(transmute::<__m128, [f32;4]>(_mm_cvtph_ps(bits)))
+                }
+            }
+
+            #[inline(always)]
+            fn from_f32_f16 (x: f32x4) -> Self {
+                unsafe {
+                    let x = transmute::<[f32;4], __m128>(x.into_array());
+                    let bits = transmute::<__m128i, [u16;8]>(_mm_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(x));
+                    Self([bits[0], bits[1], bits[2], bits[3]])
+                }
+            }
+        }
+
+        impl f16x8 {
+            #[inline(always)]
+            fn to_f32_f16 (self) -> f32x8 {
+                unsafe {
+                    let bits = _mm_loadu_si128(self.0.as_ptr().cast());
+                    f32x8::from(transmute::<__m256, [f32;8]>(_mm256_cvtph_ps(bits)))
+                }
+            }
+
+            #[inline(always)]
+            fn from_f32_f16 (x: f32x8) -> Self {
+                unsafe {
+                    let x = transmute::<[f32;8], __m256>(x.into_array());
+                    Self(transmute::<__m128i, [u16;8]>(_mm256_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(x)))
+                }
+            }
+        }
+    } else {
+        impl_half!(
+            @software
+            f16x4 => f32x4, 4, to_f32_f16, from_f32_f16, f16_to_f32_lane, f32_to_f16_lane,
+            f16x8 => f32x8, 8, to_f32_f16, from_f32_f16, f16_to_f32_lane, f32_to_f16_lane
+        );
+    }
+}
+
+macro_rules! impl_slice {
+    ($($to:ident, $from:ident => $name:ident, $lane_to:ident, $lane_from:ident, $docs:expr),+) => {
+        $(
+            #[doc=concat!("Converts a slice of ", $docs, " bits into single-precision floats")]
+            /// # Panics
+            /// Panics if both slices don't have the same length
+            pub fn $to (src: &[u16], dst: &mut [f32]) {
+                assert_eq!(src.len(), dst.len(), "source and destination must have the same length");
+
+                let mut src = src.chunks_exact(8);
+                let mut dst = dst.chunks_exact_mut(8);
+
+                for (src, dst) in (&mut src).zip(&mut dst) {
+                    let bits : [u16;8] = src.try_into().unwrap();
+                    dst.copy_from_slice(&$name::from_bits(bits).to_f32().into_array());
+                }
+
+                for (src, dst) in src.remainder().iter().zip(dst.into_remainder()) {
+                    *dst = $lane_to(*src);
+                }
+            }
+
+            #[doc=concat!("Converts a slice of single-precision floats into ", $docs, " bits, rounding to the nearest value (ties to even)")]
+            /// # Panics
+            /// Panics if both slices don't have the same length
+            pub fn $from (src: &[f32], dst: &mut [u16]) {
+                assert_eq!(src.len(), dst.len(), "source and destination must have the same length");
+
+                let mut src = src.chunks_exact(8);
+                let mut dst = dst.chunks_exact_mut(8);
+
+                for (src, dst) in (&mut src).zip(&mut dst) {
+                    let x : [f32;8] = src.try_into().unwrap();
+                    dst.copy_from_slice(&$name::from_f32(f32x8::from(x)).to_bits());
+                }
+
+                for (src, dst) in src.remainder().iter().zip(dst.into_remainder()) {
+                    *dst = $lane_from(*src);
+                }
+            }
+        )*
+    };
+}
+
+impl_slice!(
+    f16_to_f32, f32_to_f16 => f16x8, f16_to_f32_lane, f32_to_f16_lane, "half-precision",
+    bf16_to_f32, f32_to_bf16 => bf16x8, bf16_to_f32_lane, f32_to_bf16_lane, "bfloat16"
+);
+
+// Software conversions, used wherever there's no hardware support
+#[inline]
+fn f16_to_f32_lane (x: u16) -> f32 {
+    let sign = ((x & 0x8000) as u32) << 16;
+    let exp = ((x >> 10) & 0x1f) as u32;
+    let man = (x & 0x3ff) as u32;
+
+    let bits = match (exp, man) {
+        (0, 0) => sign,
+        (0, _) => {
+            // subnormal, normalized into a single-precision float
+            let shift = man.leading_zeros() - 21;
+            sign | ((113 - shift) << 23) | (((man << shift) & 0x3ff) << 13)
+        },
+        (0x1f, _) => sign | 0x7f800000 | (man << 13),
+        _ => sign | ((exp + 112) << 23) | (man << 13)
+    };
+
+    f32::from_bits(bits)
+}
+
+#[inline]
+fn f32_to_f16_lane (x: f32) -> u16 {
+    let bits = x.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exp = ((bits >> 23) & 0xff) as i32;
+    let man = bits & 0x7fffff;
+
+    // infinity or NaN (NaNs are kept quiet)
+    if exp == 0xff {
+        return sign | 0x7c00 | if man == 0 { 0 } else { 0x200 | (man >> 13) as u16 }
+    }
+
+    let exp = exp - 127 + 15;
+    if exp >= 0x1f {
+        return sign | 0x7c00
+    }
+
+    // subnormal or zero
+    if exp <= 0 {
+        if exp < -10 {
+            return sign
+        }
+
+        let man = man | 0x800000;
+        let shift = (14 - exp) as u32;
+        let half = 1 << (shift - 1);
+        let rem = man & ((1 << shift) - 1);
+
+        let mut result = man >> shift;
+        if rem > half || (rem == half && (result & 1) == 1) {
+            result += 1;
+        }
+
+        return sign | result as u16
+    }
+
+    // a carry out of the mantissa correctly rounds up into the exponent
+    let mut result = ((exp as u32) << 10) | (man >> 13);
+    let rem = man & 0x1fff;
+    if rem > 0x1000 || (rem == 0x1000 && (result & 1) == 1) {
+        result += 1;
+    }
+
+    sign | result as u16
+}
+
+#[inline(always)]
+fn bf16_to_f32_lane (x: u16) -> f32 {
+    f32::from_bits((x as u32) << 16)
+}
+
+#[inline]
+fn f32_to_bf16_lane (x: f32) -> u16 {
+    let bits = x.to_bits();
+    if x.is_nan() {
+        return ((bits >> 16) | 0x40) as u16
+    }
+
+    let round = 0x7fff + ((bits >> 16) & 1);
+    (bits.wrapping_add(round) >> 16) as u16
+}
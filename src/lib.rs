@@ -1,8 +1,12 @@
 #![feature(concat_idents, exclusive_range_pattern)]
 #![cfg_attr(target_feature = "sse", feature(stdarch, stdsimd))]
 #![cfg_attr(target_arch = "wasm32", feature(simd_wasm64))]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
 #![cfg_attr(not(feature = "use_std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 macro_rules! flat_mod {
     ($($i:ident),+) => {
         $(
@@ -17,6 +21,8 @@ macro_rules! import {
         cfg_if::cfg_if! {
             if #[cfg(feature = "force_naive")] {
                 $(pub use crate::naive::$i;)*
+            } else if #[cfg(feature = "portable_simd")] {
+                $(pub use crate::portable::$i;)*
             } else if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))] {
                 $(pub use crate::x86::$i;)*
             } else if #[cfg(all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"))] {
@@ -32,39 +38,110 @@ macro_rules! import {
 
 include!("composite.rs");
 
+// The generic implementations are included into the crate root, and share its imports
+use crate::float::single::*;
+use crate::float::double::*;
+use crate::int::*;
+
+include!("generics/fmt.rs");
+include!("generics/cmp.rs");
+include!("generics/approx.rs");
+include!("generics/int.rs");
+include!("generics/sort.rs");
+include!("generics/scan.rs");
+include!("generics/tree.rs");
+include!("generics/poly.rs");
+include!("generics/compensated.rs");
+
+#[cfg(feature = "deterministic")]
+include!("generics/deterministic.rs");
+
 #[cfg(feature = "random")]
 include!("generics/random.rs");
 
 #[cfg(feature = "serialize")]
 include!("generics/serialize.rs");
 
+#[cfg(feature = "bytemuck")]
+include!("generics/bytemuck.rs");
+
+#[cfg(feature = "num-traits")]
+include!("generics/num.rs");
+
+#[cfg(feature = "glam")]
+include!("generics/glam.rs");
+
+#[cfg(feature = "nalgebra")]
+include!("generics/nalgebra.rs");
+
+// Always compiled, as the reference the other backends are checked against
+mod naive;
+
+#[cfg(not(feature = "force_naive"))]
 cfg_if::cfg_if! {
-    if #[cfg(feature = "force_naive")] {
-        mod naive;
+    if #[cfg(feature = "portable_simd")] {
+        mod portable;
     } else if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))] {
-        mod x86;
         include!("generics/float.rs");
+        mod x86;
     } else if #[cfg(all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"))] {
-        mod arm;
         include!("generics/float.rs");
+        mod arm;
     } else if #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))] {
-        mod wasm;
         include!("generics/float.rs");
-    } else {
-        mod naive;
+        mod wasm;
     }
 }
 
+/// Explicit implementations, usable side by side
+pub mod backend;
+
+/// Checks of every operation against the naive implementation
+#[cfg(feature = "conformance")]
+pub mod conformance;
+
+mod half;
+
+/// Aligned buffers that can be viewed as vectors
+#[cfg(feature = "alloc")]
+pub mod aligned;
+
+/// SIMD operations over ndarray arrays
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+
+/// Parallel slice operations
+#[cfg(feature = "rayon")]
+pub mod par;
+
+/// Structure-of-arrays containers
+#[cfg(feature = "alloc")]
+pub mod soa;
+
+/// Integer vectors
+pub mod int {
+    import!(
+        i8x16, u8x16, i16x8, u16x8, i32x4, u32x4,
+        i8x32, u8x32, i16x16, u16x16, i32x8, u32x8,
+        i64x2, u64x2, i64x4, u64x4, i64x8, u64x8, i64x16, u64x16
+    );
+}
+
 /// Floating-point vectors
 pub mod float {
     /// Single-precision floating point vectors
     pub mod single {
-        import!(f32x2, f32x4, f32x6, f32x8, f32x10, f32x12, f32x14, f32x16);
+        import!(f32x2, f32x3, f32x4, f32x6, f32x8, f32x10, f32x12, f32x14, f32x16, f32x24, f32x32);
     }
 
     /// Double-precision floating point vectors
     pub mod double {
-        import!(f64x2, f64x4, f64x6, f64x8, f64x10, f64x12, f64x14, f64x16);
+        import!(f64x2, f64x3, f64x4, f64x6, f64x8, f64x10, f64x12, f64x14, f64x16, f64x24, f64x32);
+    }
+
+    /// Half-precision and bfloat16 storage vectors
+    pub mod half {
+        pub use crate::half::*;
     }
 }
 
@@ -82,6 +159,10 @@ pub enum LlmlImpl {
     /// WASM32 SIMD128 proposal (128-bit) implementation
     WASM,
 
+    /// Portable implementation with ```core::simd```, for any target LLVM supports.
+    /// It leaves the choice of registers to LLVM, so it doesn't report any register width
+    PORTABLE,
+
     /// Naive implementation with arrays. Useful as a backup if no other method is available
     NAIVE
 }
@@ -89,19 +170,22 @@ pub enum LlmlImpl {
 impl LlmlImpl {
     pub const CURRENT : Self = current_impl();
 
+    /// Whether the implementation uses 64-bit registers
     #[inline]
     pub const fn is_64bit (&self) -> bool {
         matches!(self, LlmlImpl::NEON)
     }
 
+    /// Whether the implementation uses 128-bit registers
     #[inline]
     pub const fn is_128bit (&self) -> bool {
         match self {
-            LlmlImpl::NAIVE => false,
-            _ => true
+            LlmlImpl::SSE | LlmlImpl::AVX | LlmlImpl::NEON | LlmlImpl::WASM => true,
+            LlmlImpl::PORTABLE | LlmlImpl::NAIVE => false
         }
     }
 
+    /// Whether the implementation uses 256-bit registers
     #[inline]
     pub const fn is_256bit (&self) -> bool {
         matches!(self, LlmlImpl::AVX)
@@ -113,6 +197,8 @@ pub const fn current_impl () -> LlmlImpl {
     cfg_if::cfg_if! {
         if #[cfg(feature = "force_naive")] {
             LlmlImpl::NAIVE
+        } else if #[cfg(feature = "portable_simd")] {
+            LlmlImpl::PORTABLE
         } else if #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"))] {
             #[cfg(all(feature = "use_avx", target_feature = "avx"))]
             return LlmlImpl::AVX;
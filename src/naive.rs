@@ -1,6 +1,19 @@
 use llml_simd_proc::*;
 use core::ops::*;
-use std::mem::MaybeUninit;
+use core::mem::MaybeUninit;
+
+// Integer vectors are only needed when naive is the current implementation
+cfg_if::cfg_if! {
+    if #[cfg(any(feature = "force_naive", not(any(
+        feature = "portable_simd",
+        all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+        all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"),
+        all(target_arch = "wasm32", target_feature = "simd128")
+    ))))] {
+        mod int;
+        pub use self::int::*;
+    }
+}
 
 #[inline]
 fn array<T, F: Fn(usize) -> T, const N: usize> (f: F) -> [T; N] {
@@ -40,6 +53,7 @@ macro_rules! impl_self_fns {
     };
 }
 
+#[cfg_attr(feature = "deterministic", allow(unused_macros))]
 macro_rules! impl_other_fns {
     ([$ty:ident;$len:literal], $($fun:ident $(as $name:ident)?: $docs:expr),+) => {
         $(
@@ -102,7 +116,7 @@ macro_rules! impl_naive {
             #[allow(non_camel_case_types)]
             #[repr(transparent)]
             #[derive(Debug, Clone, Copy, Assign)]
-            #[assign_targets(Add, Sub, Mul, Div)]
+            #[assign_targets(Add, Sub, Mul, Div, Rem)]
             #[assign_rhs(Self, $ty)]
             pub struct $target([$ty;$len]);
             impl_naive!(
@@ -110,7 +124,8 @@ macro_rules! impl_naive {
                 Add, add,
                 Sub, sub,
                 Mul, mul,
-                Div, div
+                Div, div,
+                Rem, rem
             );
 
             impl Neg for $target {
@@ -145,6 +160,22 @@ macro_rules! impl_naive {
                     Self(*(ptr as *const [$ty;$len]))
                 }
 
+                /// Loads values from the pointer into the SIMD vector.
+                /// The pointer must be aligned to the alignment of the vector, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn load_aligned (ptr: *const $ty) -> Self {
+                    debug_assert_eq!(ptr as usize % core::mem::align_of::<Self>(), 0, "pointer is not aligned");
+                    Self::load(ptr)
+                }
+
+                /// Stores the values of the vector into the pointer.
+                /// The pointer must be aligned to the alignment of the vector, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn store_aligned (self, ptr: *mut $ty) {
+                    debug_assert_eq!(ptr as usize % core::mem::align_of::<Self>(), 0, "pointer is not aligned");
+                    *(ptr as *mut [$ty;$len]) = self.0
+                }
+
                 /// Returns a reference to the value in the specified lane without checking if it’s within range
                 #[inline(always)]
                 pub unsafe fn get_unchecked (&self, idx: usize) -> &$ty {
@@ -174,10 +205,34 @@ macro_rules! impl_naive {
                 impl_self_fns!(
                     [$ty;$len],
                     abs: "absolute values",
-                    sqrt: "square roots"
+                    sqrt: "square roots",
+                    trunc: "integer parts",
+                    floor: "floors"
                 );
 
+                /// Returns a vector with the absolute values of the original vector
+                #[cfg(not(feature = "use_std"))]
+                #[inline(always)]
+                pub fn abs (self) -> Self {
+                    Self::from(-(0 as $ty)).bit_andnot(self)
+                }
+
+                /// Returns a vector with the integer parts of the original vector
+                #[cfg(not(feature = "use_std"))]
+                #[inline(always)]
+                pub fn trunc (self) -> Self {
+                    // lanes that don't fit in the mantissa are already integers, and the rest fit in an integer cast
+                    let limit = (1u64 << ($ty::MANTISSA_DIGITS - 1)) as $ty;
+                    Self(array(|i| {
+                        let x = self[i];
+                        if !(-limit < x && x < limit) { x }
+                        else if x.is_sign_negative() { -((-x as u64) as $ty) }
+                        else { (x as u64) as $ty }
+                    }))
+                }
+
                 /// Gets the smallest/minimum value of the vector
+                #[cfg(not(feature = "deterministic"))]
                 #[inline(always)]
                 pub fn min (self) -> $ty {
                     *self.0.iter()
@@ -186,6 +241,7 @@ macro_rules! impl_naive {
                 }
 
                 /// Gets the biggest/maximum value of the vector
+                #[cfg(not(feature = "deterministic"))]
                 #[inline(always)]
                 pub fn max (self) -> $ty {
                     *self.0.iter()
@@ -194,28 +250,84 @@ macro_rules! impl_naive {
                 }
 
                 /// Sums up all the values inside the vector
+                #[cfg(not(feature = "deterministic"))]
                 #[inline(always)]
                 pub fn sum (self) -> $ty {
                     self.0.iter().sum::<$ty>()
                 }
 
                 /// Multiplies all the values inside the vector
+                #[cfg(not(feature = "deterministic"))]
                 #[inline(always)]
                 pub fn prod (self) -> $ty {
                     self.0.iter().product::<$ty>()
                 }
 
+                #[cfg(not(feature = "deterministic"))]
                 impl_other_fns!(
                     [$ty;$len],
                     min as vmin: "smallest/minimum value",
                     max as vmax: "biggest/maximum value"
                 );
 
+                /// Returns a vector with the step function (`0` if smaller than `rhs`, `1` otherwise) of each lane
+                #[inline(always)]
+                pub fn step (self, rhs: Self) -> Self {
+                    Self(array(|i| if self[i] >= rhs[i] { 1. } else { 0. }))
+                }
+
+                impl_interp_fns!($ty);
+
+                impl_euclid_fns!($ty);
+
+                #[inline(always)]
+                pub(crate) fn cmp_lt (self, rhs: Self) -> Self {
+                    Self(array(|i| if self[i] < rhs[i] { $ty::from_bits(!0) } else { 0 as $ty }))
+                }
+
+                #[inline(always)]
+                pub(crate) fn cmp_eq (self, rhs: Self) -> Self {
+                    Self(array(|i| if self[i] == rhs[i] { $ty::from_bits(!0) } else { 0 as $ty }))
+                }
+
+                #[inline(always)]
+                pub(crate) fn bit_and (self, rhs: Self) -> Self {
+                    Self(array(|i| $ty::from_bits(self[i].to_bits() & rhs[i].to_bits())))
+                }
+
+                #[inline(always)]
+                pub(crate) fn bit_or (self, rhs: Self) -> Self {
+                    Self(array(|i| $ty::from_bits(self[i].to_bits() | rhs[i].to_bits())))
+                }
+
+                #[inline(always)]
+                pub(crate) fn bit_andnot (self, rhs: Self) -> Self {
+                    Self(array(|i| $ty::from_bits(!self[i].to_bits() & rhs[i].to_bits())))
+                }
+
+                impl_mask_fns!($ty);
+
+                #[cfg(feature = "deterministic")]
+                #[inline(always)]
+                pub(crate) fn pairs (self, pad: $ty) -> [pair_of!($ty); ($len + 1) / 2] {
+                    array(|k| <pair_of!($ty)>::new([self[2 * k], if 2 * k + 1 < $len { self[2 * k + 1] } else { pad }]))
+                }
+
+                impl_reduce_pairs!($ty);
+
+                // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+                #[inline(always)]
+                pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+                    Self(array(|i| if i >= lanes { self[i - lanes] } else { fill[i] }))
+                }
+
+                impl_scan!(@float $ty, $len);
+
                 /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
                 /// # Compatibility
                 /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
                 /// For the rest of targets, a regular multiplication and addition are performed
-                #[cfg(feature = "use_std")]
+                #[cfg(all(feature = "use_std", not(feature = "deterministic")))]
                 #[inline(always)]
                 pub fn mul_add (self, rhs: Self, add: Self) -> Self {
                     Self(array(|i| self[i].mul_add(rhs[i], add[i])))
@@ -225,7 +337,7 @@ macro_rules! impl_naive {
                 /// # Compatibility
                 /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
                 /// For the rest of targets, a regular multiplication and addition are performed
-                #[cfg(not(feature = "use_std"))]
+                #[cfg(all(not(feature = "use_std"), not(feature = "deterministic")))]
                 #[inline(always)]
                 pub fn mul_add (self, rhs: Self, add: Self) -> Self {
                     Self(array(|i| (self[i] * rhs[i]) + add[i]))
@@ -246,7 +358,9 @@ macro_rules! impl_naive {
                     while (i < $len) {
                         unsafe {
                             ptr.add(i).write(self_ptr.add(j).read());
-                            ptr.add(i+1).write(rhs_ptr.add(j).read());
+                            if i + 1 < $len {
+                                ptr.add(i+1).write(rhs_ptr.add(j).read());
+                            }
                         }
 
                         j += 1;
@@ -319,6 +433,8 @@ impl_naive!(
     [f32;12] as f32x12,
     [f32;14] as f32x14,
     [f32;16] as f32x16,
+    [f32;24] as f32x24,
+    [f32;32] as f32x32,
 
     [f64;2] as f64x2,
     [f64;3] as f64x3,
@@ -328,5 +444,26 @@ impl_naive!(
     [f64;10] as f64x10,
     [f64;12] as f64x12,
     [f64;14] as f64x14,
-    [f64;16] as f64x16
-);
\ No newline at end of file
+    [f64;16] as f64x16,
+    [f64;24] as f64x24,
+    [f64;32] as f64x32
+);
+
+// Pairs every lane `i` with the lane `i ^ j`, for the sorting networks
+macro_rules! impl_xor_lanes {
+    ($($target:ident),+) => {
+        $(
+            impl $target {
+                #[inline(always)]
+                pub(crate) fn xor_lanes (self, j: usize) -> Self {
+                    Self(array(|i| self[i ^ j]))
+                }
+            }
+        )*
+    };
+}
+
+impl_xor_lanes!(
+    f32x4, f32x8, f32x16,
+    f64x4, f64x8, f64x16
+);
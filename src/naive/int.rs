@@ -0,0 +1,311 @@
+use llml_simd_proc::*;
+use core::ops::*;
+use crate::float::single::{f32x4, f32x8};
+use crate::float::double::{f64x2, f64x4, f64x8, f64x16};
+use super::array;
+
+macro_rules! impl_naive_int {
+    (@arith $target:ident, $ty:ident, $($trait:ident, $fun:ident => $op:ident),+) => {
+        $(
+            impl $trait for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $fun (self, rhs: Self) -> Self::Output {
+                    Self(array(|i| self.0[i].$op(rhs.0[i])))
+                }
+            }
+
+            impl_scal_arith!($target, $ty, $trait, $fun);
+        )*
+    };
+
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            #[allow(non_camel_case_types)]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Assign)]
+            #[assign_targets(Add, Sub, BitAnd, BitOr, BitXor)]
+            #[assign_rhs(Self, $ty)]
+            pub struct $target([$ty;$len]);
+            impl_naive_int!(
+                @arith $target, $ty,
+                Add, add => wrapping_add,
+                Sub, sub => wrapping_sub,
+                BitAnd, bitand => bitand,
+                BitOr, bitor => bitor,
+                BitXor, bitxor => bitxor
+            );
+
+            impl Not for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn not (self) -> Self::Output {
+                    Self(array(|i| !self.0[i]))
+                }
+            }
+
+            impl $target {
+                /// Loads values from the pointer into the SIMD vector
+                #[inline(always)]
+                pub unsafe fn load (ptr: *const $ty) -> Self {
+                    Self(*(ptr as *const [$ty;$len]))
+                }
+
+                /// Returns a vector with the smallest/minimum value of each lane
+                #[inline(always)]
+                pub fn vmin (self, rhs: Self) -> Self {
+                    Self(array(|i| self.0[i].min(rhs.0[i])))
+                }
+
+                /// Returns a vector with the biggest/maximum value of each lane
+                #[inline(always)]
+                pub fn vmax (self, rhs: Self) -> Self {
+                    Self(array(|i| self.0[i].max(rhs.0[i])))
+                }
+
+                // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+                #[inline(always)]
+                pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+                    Self(array(|i| if i >= lanes { self.0[i - lanes] } else { fill.0[i] }))
+                }
+
+                impl_scan!(@int $ty, $len);
+            }
+
+            impl From<$ty> for $target {
+                #[inline(always)]
+                fn from (x: $ty) -> Self {
+                    Self([x;$len])
+                }
+            }
+        )*
+    };
+
+    (@small $($target:ident => $wide:ident: $wty:ident, $half:literal),+) => {
+        $(
+            impl $target {
+                /// Returns a vector with the saturating addition of each lane
+                #[inline(always)]
+                pub fn saturating_add (self, rhs: Self) -> Self {
+                    Self(array(|i| self.0[i].saturating_add(rhs.0[i])))
+                }
+
+                /// Returns a vector with the saturating subtraction of each lane
+                #[inline(always)]
+                pub fn saturating_sub (self, rhs: Self) -> Self {
+                    Self(array(|i| self.0[i].saturating_sub(rhs.0[i])))
+                }
+
+                /// Returns a vector with the high half of the widened product of each lane
+                #[inline(always)]
+                pub fn mulhi (self, rhs: Self) -> Self {
+                    const BITS : u32 = (core::mem::size_of::<$wty>() * 4) as u32;
+                    Self(array(|i| ((self.0[i] as $wty * rhs.0[i] as $wty) >> BITS) as _))
+                }
+
+                /// Returns a vector with the rounded up average of each lane
+                #[inline(always)]
+                pub fn avg (self, rhs: Self) -> Self {
+                    Self(array(|i| ((self.0[i] as $wty + rhs.0[i] as $wty + 1) >> 1) as _))
+                }
+
+                /// Widens the lanes into two vectors, with the lower and upper halves of the original vector respectively
+                #[inline(always)]
+                pub fn widen (self) -> ($wide, $wide) {
+                    (
+                        $wide(array(|i| self.0[i] as $wty)),
+                        $wide(array(|i| self.0[$half + i] as $wty))
+                    )
+                }
+            }
+        )*
+    };
+
+    (@narrow $($target:ident => $narrow:ident: $nty:ident, $half:literal),+) => {
+        $(
+            impl $target {
+                /// Narrows the lanes of both vectors into one, saturating the values that don't fit
+                #[inline(always)]
+                pub fn narrow (self, rhs: Self) -> $narrow {
+                    $narrow(array(|i| {
+                        let x = if i < $half { self.0[i] } else { rhs.0[i - $half] };
+                        x.clamp($nty::MIN as _, $nty::MAX as _) as $nty
+                    }))
+                }
+            }
+        )*
+    };
+
+    (@wide $([$ty:ident;$len:literal] as $target:ident => $float:ident),+) => {
+        $(
+            impl Mul for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul (self, rhs: Self) -> Self::Output {
+                    Self(array(|i| self.0[i].wrapping_mul(rhs.0[i])))
+                }
+            }
+
+            impl_scal_arith!($target, $ty, Mul, mul);
+
+            impl Shl<u32> for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shl (self, rhs: u32) -> Self::Output {
+                    Self(array(|i| self.0[i].wrapping_shl(rhs)))
+                }
+            }
+
+            impl Shr<u32> for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shr (self, rhs: u32) -> Self::Output {
+                    Self(array(|i| self.0[i].wrapping_shr(rhs)))
+                }
+            }
+
+            impl Shl for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shl (self, rhs: Self) -> Self::Output {
+                    Self(array(|i| self.0[i].wrapping_shl(rhs.0[i] as u32)))
+                }
+            }
+
+            impl Shr for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shr (self, rhs: Self) -> Self::Output {
+                    Self(array(|i| self.0[i].wrapping_shr(rhs.0[i] as u32)))
+                }
+            }
+
+            impl $target {
+                impl_scan!(@prod);
+
+                impl_naive_int!(
+                    @cmp $ty,
+                    veq => eq: "equal to",
+                    vne => ne: "not equal to",
+                    vlt => lt: "less than",
+                    vle => le: "less than or equal to",
+                    vgt => gt: "greater than",
+                    vge => ge: "greater than or equal to"
+                );
+
+                /// Converts every lane into a double-precision float
+                #[inline(always)]
+                pub fn to_f64 (self) -> $float {
+                    $float::from(array(|i| self.0[i] as f64))
+                }
+
+                /// Converts every lane of a double-precision float vector into an integer, truncating towards zero and saturating the values that don't fit (`NaN` becomes `0`)
+                #[inline(always)]
+                pub fn from_f64 (x: $float) -> Self {
+                    let x = x.into_array();
+                    Self(array(|i| x[i] as $ty))
+                }
+            }
+        )*
+    };
+
+    (@cmp $ty:ident, $($fun:ident => $op:ident: $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a mask with every bit set on the lanes where `self` is ", $docs, " `rhs`, and cleared otherwise")]
+            #[inline(always)]
+            pub fn $fun (self, rhs: Self) -> Self {
+                Self(array(|i| if self.0[i].$op(&rhs.0[i]) { !0 } else { 0 }))
+            }
+        )*
+    };
+
+    (@float $($target:ident => $float:ident),+) => {
+        $(
+            impl $target {
+                /// Converts every lane into a single-precision float
+                #[inline(always)]
+                pub fn to_f32 (self) -> $float {
+                    $float::from(array(|i| self.0[i] as f32))
+                }
+            }
+        )*
+    };
+}
+
+impl_naive_int!(
+    [i8;16] as i8x16,
+    [u8;16] as u8x16,
+    [i16;8] as i16x8,
+    [u16;8] as u16x8,
+    [i32;4] as i32x4,
+    [u32;4] as u32x4,
+
+    [i8;32] as i8x32,
+    [u8;32] as u8x32,
+    [i16;16] as i16x16,
+    [u16;16] as u16x16,
+    [i32;8] as i32x8,
+    [u32;8] as u32x8,
+
+    [i64;2] as i64x2,
+    [u64;2] as u64x2,
+    [i64;4] as i64x4,
+    [u64;4] as u64x4,
+    [i64;8] as i64x8,
+    [u64;8] as u64x8,
+    [i64;16] as i64x16,
+    [u64;16] as u64x16
+);
+
+impl_naive_int!(
+    @wide
+    [i64;2] as i64x2 => f64x2,
+    [u64;2] as u64x2 => f64x2,
+    [i64;4] as i64x4 => f64x4,
+    [u64;4] as u64x4 => f64x4,
+    [i64;8] as i64x8 => f64x8,
+    [u64;8] as u64x8 => f64x8,
+    [i64;16] as i64x16 => f64x16,
+    [u64;16] as u64x16 => f64x16
+);
+
+impl_naive_int!(
+    @small
+    i8x16 => i16x8: i16, 8,
+    u8x16 => u16x8: u16, 8,
+    i16x8 => i32x4: i32, 4,
+    u16x8 => u32x4: u32, 4,
+
+    i8x32 => i16x16: i16, 16,
+    u8x32 => u16x16: u16, 16,
+    i16x16 => i32x8: i32, 8,
+    u16x16 => u32x8: u32, 8
+);
+
+impl_naive_int!(
+    @narrow
+    i16x8 => i8x16: i8, 8,
+    u16x8 => u8x16: u8, 8,
+    i32x4 => i16x8: i16, 4,
+    u32x4 => u16x8: u16, 4,
+
+    i16x16 => i8x32: i8, 16,
+    u16x16 => u8x32: u8, 16,
+    i32x8 => i16x16: i16, 8,
+    u32x8 => u16x16: u16, 8
+);
+
+impl_naive_int!(
+    @float
+    i32x4 => f32x4,
+    u32x4 => f32x4,
+    i32x8 => f32x8,
+    u32x8 => f32x8
+);
@@ -0,0 +1,191 @@
+use ::ndarray::{ArrayBase, Array1, Array2, Data, DataMut, Ix1, Ix2, Zip};
+use crate::float::single::f32x8;
+
+/// Number of lanes of the vectors the arrays are processed with
+const WIDTH : usize = 8;
+
+/// SIMD operations over one-dimensional arrays.
+/// Contiguous arrays are processed in whole ```f32x8``` vectors, and arrays with any other stride fall back to scalar code
+pub trait SimdArray1 {
+    /// Returns the sum of every value
+    fn simd_sum (&self) -> f32;
+
+    /// Returns the dot product of both arrays
+    fn simd_dot<S: Data<Elem = f32>> (&self, rhs: &ArrayBase<S, Ix1>) -> f32;
+
+    /// Returns a new array with the result of applying `f` to every vector of values
+    fn simd_map<F: Fn(f32x8) -> f32x8> (&self, f: F) -> Array1<f32>;
+}
+
+/// In-place SIMD operations over one-dimensional arrays.
+/// Contiguous arrays are processed in whole ```f32x8``` vectors, and arrays with any other stride fall back to scalar code
+pub trait SimdArrayMut1 {
+    /// Replaces every vector of values with the result of applying `f` to it
+    fn simd_map_inplace<F: Fn(f32x8) -> f32x8> (&mut self, f: F);
+
+    /// Adds `alpha * x` to the array
+    fn simd_axpy<S: Data<Elem = f32>> (&mut self, alpha: f32, x: &ArrayBase<S, Ix1>);
+}
+
+/// SIMD operations over the rows of two-dimensional arrays
+pub trait SimdArray2 {
+    /// Returns the sum of every row
+    fn simd_row_sums (&self) -> Array1<f32>;
+
+    /// Returns the dot product of every row with `rhs`, which is the product of the matrix with the vector
+    fn simd_row_dots<S: Data<Elem = f32>> (&self, rhs: &ArrayBase<S, Ix1>) -> Array1<f32>;
+
+    /// Returns a new array with the result of applying `f` to every vector of values of every row
+    fn simd_map<F: Fn(f32x8) -> f32x8> (&self, f: F) -> Array2<f32>;
+}
+
+/// In-place SIMD operations over the rows of two-dimensional arrays
+pub trait SimdArrayMut2 {
+    /// Replaces every vector of values of every row with the result of applying `f` to it
+    fn simd_map_inplace<F: Fn(f32x8) -> f32x8> (&mut self, f: F);
+
+    /// Adds `alpha * x` to every row
+    fn simd_axpy_rows<S: Data<Elem = f32>> (&mut self, alpha: f32, x: &ArrayBase<S, Ix1>);
+}
+
+impl<S: Data<Elem = f32>> SimdArray1 for ArrayBase<S, Ix1> {
+    #[inline]
+    fn simd_sum (&self) -> f32 {
+        match self.as_slice() {
+            Some(slice) => slice_sum(slice),
+            None => self.iter().sum()
+        }
+    }
+
+    #[inline]
+    fn simd_dot<R: Data<Elem = f32>> (&self, rhs: &ArrayBase<R, Ix1>) -> f32 {
+        assert_eq!(self.len(), rhs.len(), "arrays must have the same length");
+        match (self.as_slice(), rhs.as_slice()) {
+            (Some(lhs), Some(rhs)) => slice_dot(lhs, rhs),
+            _ => Zip::from(self).and(rhs).fold(0., |acc, x, y| acc + x * y)
+        }
+    }
+
+    #[inline]
+    fn simd_map<F: Fn(f32x8) -> f32x8> (&self, f: F) -> Array1<f32> {
+        let mut result = self.to_owned();
+        result.simd_map_inplace(f);
+        result
+    }
+}
+
+impl<S: DataMut<Elem = f32>> SimdArrayMut1 for ArrayBase<S, Ix1> {
+    #[inline]
+    fn simd_map_inplace<F: Fn(f32x8) -> f32x8> (&mut self, f: F) {
+        match self.as_slice_mut() {
+            Some(slice) => slice_apply(slice, &f),
+            None => self.iter_mut().for_each(|x| *x = f(f32x8::from(*x))[0])
+        }
+    }
+
+    #[inline]
+    fn simd_axpy<R: Data<Elem = f32>> (&mut self, alpha: f32, x: &ArrayBase<R, Ix1>) {
+        assert_eq!(self.len(), x.len(), "arrays must have the same length");
+        match (self.as_slice_mut(), x.as_slice()) {
+            (Some(y), Some(x)) => slice_axpy(y, alpha, x),
+            _ => Zip::from(self).and(x).for_each(|y, x| *y += alpha * x)
+        }
+    }
+}
+
+impl<S: Data<Elem = f32>> SimdArray2 for ArrayBase<S, Ix2> {
+    #[inline]
+    fn simd_row_sums (&self) -> Array1<f32> {
+        self.rows().into_iter().map(|row| row.simd_sum()).collect()
+    }
+
+    #[inline]
+    fn simd_row_dots<R: Data<Elem = f32>> (&self, rhs: &ArrayBase<R, Ix1>) -> Array1<f32> {
+        self.rows().into_iter().map(|row| row.simd_dot(rhs)).collect()
+    }
+
+    #[inline]
+    fn simd_map<F: Fn(f32x8) -> f32x8> (&self, f: F) -> Array2<f32> {
+        let mut result = self.to_owned();
+        result.simd_map_inplace(f);
+        result
+    }
+}
+
+impl<S: DataMut<Elem = f32>> SimdArrayMut2 for ArrayBase<S, Ix2> {
+    #[inline]
+    fn simd_map_inplace<F: Fn(f32x8) -> f32x8> (&mut self, f: F) {
+        match self.as_slice_mut() {
+            Some(slice) => slice_apply(slice, &f),
+            None => self.rows_mut().into_iter().for_each(|mut row| row.simd_map_inplace(&f))
+        }
+    }
+
+    #[inline]
+    fn simd_axpy_rows<R: Data<Elem = f32>> (&mut self, alpha: f32, x: &ArrayBase<R, Ix1>) {
+        self.rows_mut().into_iter().for_each(|mut row| row.simd_axpy(alpha, x))
+    }
+}
+
+/// Loads the last, partial vector of a slice, filling the missing lanes with zeros
+#[inline(always)]
+fn pad (rest: &[f32]) -> f32x8 {
+    let mut array = [0.; WIDTH];
+    array[..rest.len()].copy_from_slice(rest);
+    f32x8::from(array)
+}
+
+#[inline]
+fn slice_sum (slice: &[f32]) -> f32 {
+    let mut chunks = slice.chunks_exact(WIDTH);
+    let mut acc = f32x8::default();
+
+    for x in &mut chunks {
+        acc += unsafe { f32x8::load(x.as_ptr()) };
+    }
+
+    (acc + pad(chunks.remainder())).sum()
+}
+
+#[inline]
+fn slice_dot (lhs: &[f32], rhs: &[f32]) -> f32 {
+    let mut lhs = lhs.chunks_exact(WIDTH);
+    let mut rhs = rhs.chunks_exact(WIDTH);
+    let mut acc = f32x8::default();
+
+    for (x, y) in (&mut lhs).zip(&mut rhs) {
+        acc += unsafe { f32x8::load(x.as_ptr()) * f32x8::load(y.as_ptr()) };
+    }
+
+    (acc + pad(lhs.remainder()) * pad(rhs.remainder())).sum()
+}
+
+#[inline]
+fn slice_apply<F: Fn(f32x8) -> f32x8> (slice: &mut [f32], f: &F) {
+    let mut chunks = slice.chunks_exact_mut(WIDTH);
+    for x in &mut chunks {
+        let result = f(unsafe { f32x8::load(x.as_ptr()) });
+        x.copy_from_slice(&result.into_array());
+    }
+
+    let rest = chunks.into_remainder();
+    if !rest.is_empty() {
+        let result = f(pad(rest)).into_array();
+        rest.copy_from_slice(&result[..rest.len()]);
+    }
+}
+
+#[inline]
+fn slice_axpy (y: &mut [f32], alpha: f32, x: &[f32]) {
+    let mut chunks = y.chunks_exact_mut(WIDTH);
+    let mut x = x.chunks_exact(WIDTH);
+
+    for (y, x) in (&mut chunks).zip(&mut x) {
+        let result = unsafe { f32x8::load(y.as_ptr()) + f32x8::load(x.as_ptr()) * alpha };
+        y.copy_from_slice(&result.into_array());
+    }
+
+    for (y, x) in chunks.into_remainder().iter_mut().zip(x.remainder()) {
+        *y += alpha * x;
+    }
+}
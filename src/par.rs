@@ -0,0 +1,237 @@
+use rayon::prelude::*;
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+use crate::float::single::f32x8;
+use crate::float::double::f64x4;
+
+/// Size, in bytes, of the chunks every thread works on at once. Small enough for a chunk of every operand to stay in cache
+pub const CHUNK_BYTES : usize = 32 * 1024;
+
+/// Parallel slice operations, splitting the slice into cache-sized chunks that are processed with SIMD vectors on every thread.
+///
+/// Reductions are combined in whatever order the threads finish, so sums may differ in the last bits between runs.
+/// The ```_deterministic``` variants always combine the chunks in the same order, independently of the number of threads
+pub trait ParSimd {
+    /// Type of every value
+    type Lane: Copy + Send + Sync;
+
+    /// Vector the values are processed with
+    type Vector: Copy;
+
+    /// Writes the result of applying `f` to every vector of values into `dst`
+    fn par_map<F: Fn(Self::Vector) -> Self::Vector + Sync> (&self, dst: &mut [Self::Lane], f: F);
+
+    /// Writes the result of applying `f` to every pair of vectors of both slices into `dst`
+    fn par_zip<F: Fn(Self::Vector, Self::Vector) -> Self::Vector + Sync> (&self, rhs: &[Self::Lane], dst: &mut [Self::Lane], f: F);
+
+    /// Replaces every vector of values with the result of applying `f` to it
+    fn par_apply<F: Fn(Self::Vector) -> Self::Vector + Sync> (&mut self, f: F);
+
+    /// Replaces every vector of values with the result of applying `f` to it and the matching vector of `rhs`
+    fn par_apply_zip<F: Fn(Self::Vector, Self::Vector) -> Self::Vector + Sync> (&mut self, rhs: &[Self::Lane], f: F);
+
+    /// Returns the sum of every value
+    fn par_sum (&self) -> Self::Lane;
+
+    /// Returns the sum of every value, combining the partial sums in a fixed order
+    fn par_sum_deterministic (&self) -> Self::Lane;
+
+    /// Returns the dot product of both slices
+    fn par_dot (&self, rhs: &[Self::Lane]) -> Self::Lane;
+
+    /// Returns the dot product of both slices, combining the partial sums in a fixed order
+    fn par_dot_deterministic (&self, rhs: &[Self::Lane]) -> Self::Lane;
+
+    /// Returns the smallest value, or `None` if the slice is empty
+    fn par_min (&self) -> Option<Self::Lane>;
+
+    /// Returns the biggest value, or `None` if the slice is empty
+    fn par_max (&self) -> Option<Self::Lane>;
+}
+
+macro_rules! impl_par {
+    ($($ty:ident => $vec:ident: $len:literal),+) => {
+        $(
+            impl ParSimd for [$ty] {
+                type Lane = $ty;
+                type Vector = $vec;
+
+                fn par_map<F: Fn($vec) -> $vec + Sync> (&self, dst: &mut [$ty], f: F) {
+                    assert_eq!(self.len(), dst.len(), "slices must have the same length");
+                    let len = chunk_len::<$ty, $len>();
+
+                    // every chunk is copied while it's in cache, and transformed in place
+                    dst.par_chunks_mut(len).zip(self.par_chunks(len)).for_each(|(dst, src)| {
+                        dst.copy_from_slice(src);
+                        apply::<$ty, $vec, $len>(dst, &f)
+                    })
+                }
+
+                fn par_zip<F: Fn($vec, $vec) -> $vec + Sync> (&self, rhs: &[$ty], dst: &mut [$ty], f: F) {
+                    assert_eq!(self.len(), rhs.len(), "slices must have the same length");
+                    assert_eq!(self.len(), dst.len(), "slices must have the same length");
+                    let len = chunk_len::<$ty, $len>();
+
+                    dst.par_chunks_mut(len).zip(self.par_chunks(len)).zip(rhs.par_chunks(len)).for_each(|((dst, src), rhs)| {
+                        dst.copy_from_slice(src);
+                        apply_zip::<$ty, $vec, $len>(dst, rhs, &f)
+                    })
+                }
+
+                #[inline]
+                fn par_apply<F: Fn($vec) -> $vec + Sync> (&mut self, f: F) {
+                    self.par_chunks_mut(chunk_len::<$ty, $len>())
+                        .for_each(|chunk| apply::<$ty, $vec, $len>(chunk, &f))
+                }
+
+                #[inline]
+                fn par_apply_zip<F: Fn($vec, $vec) -> $vec + Sync> (&mut self, rhs: &[$ty], f: F) {
+                    assert_eq!(self.len(), rhs.len(), "slices must have the same length");
+                    let len = chunk_len::<$ty, $len>();
+
+                    self.par_chunks_mut(len).zip(rhs.par_chunks(len))
+                        .for_each(|(chunk, rhs)| apply_zip::<$ty, $vec, $len>(chunk, rhs, &f))
+                }
+
+                #[inline]
+                fn par_sum (&self) -> $ty {
+                    self.par_chunks(chunk_len::<$ty, $len>())
+                        .map(|chunk| reduce::<$ty, $vec, $len>(chunk, 0 as $ty, core::ops::Add::add).sum())
+                        .sum()
+                }
+
+                #[inline]
+                fn par_sum_deterministic (&self) -> $ty {
+                    self.par_chunks(chunk_len::<$ty, $len>())
+                        .map(|chunk| reduce::<$ty, $vec, $len>(chunk, 0 as $ty, core::ops::Add::add).sum())
+                        .collect::<Vec<_>>()
+                        .into_iter()
+                        .sum()
+                }
+
+                #[inline]
+                fn par_dot (&self, rhs: &[$ty]) -> $ty {
+                    assert_eq!(self.len(), rhs.len(), "slices must have the same length");
+                    let len = chunk_len::<$ty, $len>();
+
+                    self.par_chunks(len).zip(rhs.par_chunks(len))
+                        .map(|(x, y)| dot::<$ty, $vec, $len>(x, y).sum())
+                        .sum()
+                }
+
+                #[inline]
+                fn par_dot_deterministic (&self, rhs: &[$ty]) -> $ty {
+                    assert_eq!(self.len(), rhs.len(), "slices must have the same length");
+                    let len = chunk_len::<$ty, $len>();
+
+                    self.par_chunks(len).zip(rhs.par_chunks(len))
+                        .map(|(x, y)| dot::<$ty, $vec, $len>(x, y).sum())
+                        .collect::<Vec<_>>()
+                        .into_iter()
+                        .sum()
+                }
+
+                #[inline]
+                fn par_min (&self) -> Option<$ty> {
+                    self.par_chunks(chunk_len::<$ty, $len>())
+                        .map(|chunk| reduce::<$ty, $vec, $len>(chunk, $ty::INFINITY, <$vec>::vmin).min())
+                        .reduce_with(<$ty>::min)
+                }
+
+                #[inline]
+                fn par_max (&self) -> Option<$ty> {
+                    self.par_chunks(chunk_len::<$ty, $len>())
+                        .map(|chunk| reduce::<$ty, $vec, $len>(chunk, $ty::NEG_INFINITY, <$vec>::vmax).max())
+                        .reduce_with(<$ty>::max)
+                }
+            }
+        )*
+    };
+}
+
+impl_par!(
+    f32 => f32x8: 8,
+    f64 => f64x4: 4
+);
+
+/// Number of values in every chunk, rounded down to a whole number of vectors
+#[inline(always)]
+const fn chunk_len<T, const N: usize> () -> usize {
+    let len = CHUNK_BYTES / core::mem::size_of::<T>();
+    len - len % N
+}
+
+/// Loads a whole vector from the start of the slice
+#[inline(always)]
+fn load<T: Copy, V: From<[T;N]>, const N: usize> (values: &[T]) -> V {
+    V::from(<[T;N]>::try_from(&values[..N]).unwrap())
+}
+
+/// Loads the last, partial vector of a slice, filling the missing lanes with `fill`
+#[inline(always)]
+fn pad<T: Copy, V: From<[T;N]>, const N: usize> (rest: &[T], fill: T) -> V {
+    let mut array = [fill; N];
+    array[..rest.len()].copy_from_slice(rest);
+    V::from(array)
+}
+
+/// Folds every vector of the chunk with `op`, padding the last one with `identity`
+#[inline(always)]
+fn reduce<T: Copy, V: Copy + From<[T;N]> + From<T>, const N: usize> (chunk: &[T], identity: T, op: impl Fn(V, V) -> V) -> V {
+    let mut vectors = chunk.chunks_exact(N);
+    let mut acc = V::from(identity);
+
+    for x in &mut vectors {
+        acc = op(acc, load(x));
+    }
+
+    op(acc, pad(vectors.remainder(), identity))
+}
+
+/// Replaces every vector of the chunk with the result of applying `f` to it
+#[inline(always)]
+fn apply<T: Copy + Default, V: From<[T;N]> + Into<[T;N]>, const N: usize> (chunk: &mut [T], f: &impl Fn(V) -> V) {
+    let mut vectors = chunk.chunks_exact_mut(N);
+    for x in &mut vectors {
+        let result : [T;N] = f(load(x)).into();
+        x.copy_from_slice(&result);
+    }
+
+    let rest = vectors.into_remainder();
+    if !rest.is_empty() {
+        let result : [T;N] = f(pad(rest, T::default())).into();
+        rest.copy_from_slice(&result[..rest.len()]);
+    }
+}
+
+/// Replaces every vector of the chunk with the result of applying `f` to it and the matching vector of `rhs`
+#[inline(always)]
+fn apply_zip<T: Copy + Default, V: From<[T;N]> + Into<[T;N]>, const N: usize> (chunk: &mut [T], rhs: &[T], f: &impl Fn(V, V) -> V) {
+    let mut vectors = chunk.chunks_exact_mut(N);
+    let mut rhs = rhs.chunks_exact(N);
+
+    for (x, y) in (&mut vectors).zip(&mut rhs) {
+        let result : [T;N] = f(load(x), load(y)).into();
+        x.copy_from_slice(&result);
+    }
+
+    let rest = vectors.into_remainder();
+    if !rest.is_empty() {
+        let result : [T;N] = f(pad(rest, T::default()), pad(rhs.remainder(), T::default())).into();
+        rest.copy_from_slice(&result[..rest.len()]);
+    }
+}
+
+/// Returns the lane-wise products of both chunks, added together into one vector
+#[inline(always)]
+fn dot<T: Copy + Default, V: Copy + From<[T;N]> + From<T> + Add<Output = V> + Mul<Output = V>, const N: usize> (lhs: &[T], rhs: &[T]) -> V {
+    let mut lhs = lhs.chunks_exact(N);
+    let mut rhs = rhs.chunks_exact(N);
+    let mut acc = V::from(T::default());
+
+    for (x, y) in (&mut lhs).zip(&mut rhs) {
+        acc = acc + load::<T, V, N>(x) * load(y);
+    }
+
+    acc + pad::<T, V, N>(lhs.remainder(), T::default()) * pad(rhs.remainder(), T::default())
+}
@@ -0,0 +1,414 @@
+use llml_simd_proc::*;
+use core::ops::*;
+use core::simd::{Simd, Select, Swizzle};
+use core::simd::prelude::{SimdFloat, SimdPartialEq, SimdPartialOrd};
+#[cfg(feature = "use_std")]
+use std::simd::StdFloat;
+
+mod int;
+pub use self::int::*;
+
+macro_rules! impl_portable {
+    (@arith $target:ident, $ty:ident, $($trait:ident, $fun:ident),+) => {
+        $(
+            impl $trait for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $fun (self, rhs: Self) -> Self::Output {
+                    Self(self.0.$fun(rhs.0))
+                }
+            }
+
+            impl_scal_arith!($target, $ty, $trait, $fun);
+        )*
+    };
+
+    (@std $($fun:ident: $docs:expr),+) => {
+        $(
+            #[cfg(feature = "use_std")]
+            #[doc=concat!("Returns a vector with the ", $docs, " of the original vector")]
+            #[inline(always)]
+            pub fn $fun (self) -> Self {
+                Self(self.0.$fun())
+            }
+        )*
+    };
+
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            #[allow(non_camel_case_types)]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Assign)]
+            #[assign_targets(Add, Sub, Mul, Div, Rem)]
+            #[assign_rhs(Self, $ty)]
+            pub struct $target(pub(crate) Simd<$ty, $len>);
+            impl_portable!(
+                @arith $target, $ty,
+                Add, add,
+                Sub, sub,
+                Mul, mul,
+                Div, div,
+                Rem, rem
+            );
+
+            impl Neg for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn neg (self) -> Self::Output {
+                    Self(-self.0)
+                }
+            }
+
+            impl $target {
+                #[inline(always)]
+                pub fn new (a: [$ty;$len]) -> Self {
+                    Self(Simd::from_array(a))
+                }
+
+                /// Creates a new vector with all lanes filled with the provided value
+                #[inline(always)]
+                pub fn filled_with (a: $ty) -> Self {
+                    Self(Simd::splat(a))
+                }
+
+                /// Returns an array with the vector's values
+                #[inline(always)]
+                pub fn into_array (self) -> [$ty;$len] {
+                    self.0.to_array()
+                }
+
+                /// Loads values from the pointer into the SIMD vector
+                #[inline(always)]
+                pub unsafe fn load (ptr: *const $ty) -> Self {
+                    Self(Simd::from_array(*(ptr as *const [$ty;$len])))
+                }
+
+                /// Loads values from the pointer into the SIMD vector.
+                /// The pointer must be aligned to the alignment of the vector, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn load_aligned (ptr: *const $ty) -> Self {
+                    debug_assert_eq!(ptr as usize % core::mem::align_of::<Self>(), 0, "pointer is not aligned");
+                    Self(*(ptr as *const Simd<$ty, $len>))
+                }
+
+                /// Stores the values of the vector into the pointer.
+                /// The pointer must be aligned to the alignment of the vector, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn store_aligned (self, ptr: *mut $ty) {
+                    debug_assert_eq!(ptr as usize % core::mem::align_of::<Self>(), 0, "pointer is not aligned");
+                    *(ptr as *mut Simd<$ty, $len>) = self.0
+                }
+
+                /// Returns a reference to the value in the specified lane without checking if it's within range
+                #[inline(always)]
+                pub unsafe fn get_unchecked (&self, idx: usize) -> &$ty {
+                    &*(self as *const Self as *const $ty).add(idx)
+                }
+
+                /// Returns a reference to the value in the specified lane without checking if it's within range
+                #[deprecated(since="0.1.4", note="use ```get_unchecked``` instead")]
+                #[inline(always)]
+                pub unsafe fn index_unchecked (&self, idx: usize) -> &$ty {
+                    self.get_unchecked(idx)
+                }
+
+                /// Returns a mutable reference to the value in the specified lane without checking if it's within range
+                #[inline(always)]
+                pub unsafe fn get_mut_unchecked (&mut self, idx: usize) -> &mut $ty {
+                    &mut *(self as *mut Self as *mut $ty).add(idx)
+                }
+
+                /// Returns a mutable reference to the value in the specified lane without checking if it's within range
+                #[deprecated(since="0.1.4", note="use ```get_mut_unchecked``` instead")]
+                #[inline(always)]
+                pub unsafe fn index_mut_unchecked (&mut self, idx: usize) -> &mut $ty {
+                    self.get_mut_unchecked(idx)
+                }
+
+                /// Returns a vector with the absolute values of the original vector
+                #[inline(always)]
+                pub fn abs (self) -> Self {
+                    Self(self.0.abs())
+                }
+
+                impl_portable!(
+                    @std
+                    sqrt: "square roots",
+                    trunc: "integer parts",
+                    floor: "floors"
+                );
+
+                /// Gets the smallest/minimum value of the vector
+                #[cfg(not(feature = "deterministic"))]
+                #[inline(always)]
+                pub fn min (self) -> $ty {
+                    self.0.reduce_min()
+                }
+
+                /// Gets the biggest/maximum value of the vector
+                #[cfg(not(feature = "deterministic"))]
+                #[inline(always)]
+                pub fn max (self) -> $ty {
+                    self.0.reduce_max()
+                }
+
+                /// Sums up all the values inside the vector
+                #[cfg(not(feature = "deterministic"))]
+                #[inline(always)]
+                pub fn sum (self) -> $ty {
+                    self.0.reduce_sum()
+                }
+
+                /// Multiplies all the values inside the vector
+                #[cfg(not(feature = "deterministic"))]
+                #[inline(always)]
+                pub fn prod (self) -> $ty {
+                    self.0.reduce_product()
+                }
+
+                /// Returns a vector with the smallest/minimum value of each lane
+                #[cfg(not(feature = "deterministic"))]
+                #[inline(always)]
+                pub fn vmin (self, rhs: Self) -> Self {
+                    Self(self.0.simd_min(rhs.0))
+                }
+
+                /// Returns a vector with the biggest/maximum value of each lane
+                #[cfg(not(feature = "deterministic"))]
+                #[inline(always)]
+                pub fn vmax (self, rhs: Self) -> Self {
+                    Self(self.0.simd_max(rhs.0))
+                }
+
+                /// Returns a vector with the step function (`0` if smaller than `rhs`, `1` otherwise) of each lane
+                #[inline(always)]
+                pub fn step (self, rhs: Self) -> Self {
+                    Self(self.0.simd_ge(rhs.0).select(Simd::splat(1.), Simd::splat(0.)))
+                }
+
+                impl_interp_fns!($ty);
+
+                #[cfg(feature = "use_std")]
+                impl_euclid_fns!($ty);
+
+                #[inline(always)]
+                pub(crate) fn cmp_lt (self, rhs: Self) -> Self {
+                    Self(self.0.simd_lt(rhs.0).select(Simd::<$ty, $len>::from_bits(Simd::splat(!0)), Simd::splat(0 as $ty)))
+                }
+
+                #[inline(always)]
+                pub(crate) fn cmp_eq (self, rhs: Self) -> Self {
+                    Self(self.0.simd_eq(rhs.0).select(Simd::<$ty, $len>::from_bits(Simd::splat(!0)), Simd::splat(0 as $ty)))
+                }
+
+                #[inline(always)]
+                pub(crate) fn bit_and (self, rhs: Self) -> Self {
+                    Self(Simd::<$ty, $len>::from_bits(self.0.to_bits() & rhs.0.to_bits()))
+                }
+
+                #[inline(always)]
+                pub(crate) fn bit_or (self, rhs: Self) -> Self {
+                    Self(Simd::<$ty, $len>::from_bits(self.0.to_bits() | rhs.0.to_bits()))
+                }
+
+                #[inline(always)]
+                pub(crate) fn bit_andnot (self, rhs: Self) -> Self {
+                    Self(Simd::<$ty, $len>::from_bits(!self.0.to_bits() & rhs.0.to_bits()))
+                }
+
+                impl_mask_fns!($ty);
+
+                // Combines the pairs of lanes `(2k, 2k + 1)` in place, following the tree of `reduce_tree`: every level brings in the pair
+                // twice as far as the last one, with `pad` past the end. The result is left in the first two lanes
+                #[cfg(feature = "deterministic")]
+                #[inline(always)]
+                pub(crate) fn reduce_pairs<F: Fn(Self, Self) -> Self> (self, pad: $ty, f: F) -> Self {
+                    let mut v = self;
+                    if 2 < $len { v = f(v, v.shift_down::<2>(pad)) }
+                    if 4 < $len { v = f(v, v.shift_down::<4>(pad)) }
+                    if 8 < $len { v = f(v, v.shift_down::<8>(pad)) }
+                    if 16 < $len { v = f(v, v.shift_down::<16>(pad)) }
+                    v
+                }
+
+                // Moves every lane `S` lanes down, filling the top ones with `pad`
+                #[cfg(feature = "deterministic")]
+                #[inline(always)]
+                fn shift_down<const S: usize> (self, pad: $ty) -> Self {
+                    let inside = <Simd<$ty, $len> as SimdPartialEq>::Mask::from_array(core::array::from_fn(|i| i + S < $len));
+                    Self(inside.select(self.0.rotate_elements_left::<S>(), Simd::splat(pad)))
+                }
+
+                // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+                #[inline(always)]
+                pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+                    match lanes {
+                        1 => self.shift_up_by::<1>(fill),
+                        2 => self.shift_up_by::<2>(fill),
+                        4 => self.shift_up_by::<4>(fill),
+                        8 => self.shift_up_by::<8>(fill),
+                        16 => self.shift_up_by::<16>(fill),
+                        _ => unreachable!()
+                    }
+                }
+
+                #[inline(always)]
+                fn shift_up_by<const S: usize> (self, fill: Self) -> Self {
+                    let inside = <Simd<$ty, $len> as SimdPartialEq>::Mask::from_array(core::array::from_fn(|i| i >= S));
+                    Self(inside.select(self.0.rotate_elements_right::<S>(), fill.0))
+                }
+
+                impl_scan!(@float $ty, $len);
+
+                /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
+                /// # Compatibility
+                /// The fused multiply-add operation is only available with the standard library.
+                /// Without it, a regular multiplication and addition are performed
+                #[cfg(all(feature = "use_std", not(feature = "deterministic")))]
+                #[inline(always)]
+                pub fn mul_add (self, rhs: Self, add: Self) -> Self {
+                    Self(self.0.mul_add(rhs.0, add.0))
+                }
+
+                /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
+                /// # Compatibility
+                /// The fused multiply-add operation is only available with the standard library.
+                /// Without it, a regular multiplication and addition are performed
+                #[cfg(all(not(feature = "use_std"), not(feature = "deterministic")))]
+                #[inline(always)]
+                pub fn mul_add (self, rhs: Self, add: Self) -> Self {
+                    Self(self.0 * rhs.0 + add.0)
+                }
+
+                /// Interleaves elements of both vectors into one
+                #[inline(always)]
+                pub fn zip (self, rhs: Self) -> Self {
+                    Self(self.0.interleave(rhs.0).0)
+                }
+            }
+
+            impl Index<usize> for $target {
+                type Output = $ty;
+
+                #[inline(always)]
+                fn index (&self, idx: usize) -> &$ty {
+                    self.0.index(idx)
+                }
+            }
+
+            impl IndexMut<usize> for $target {
+                #[inline(always)]
+                fn index_mut (&mut self, idx: usize) -> &mut $ty {
+                    self.0.index_mut(idx)
+                }
+            }
+
+            impl PartialEq for $target {
+                #[inline(always)]
+                fn eq (&self, other: &Self) -> bool {
+                    self.0.simd_eq(other.0).all()
+                }
+            }
+
+            impl core::fmt::Debug for $target {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_list().entries(self.into_array()).finish()
+                }
+            }
+
+            impl From<[$ty;$len]> for $target {
+                #[inline(always)]
+                fn from (x: [$ty;$len]) -> Self {
+                    Self::new(x)
+                }
+            }
+
+            impl From<$ty> for $target {
+                #[inline(always)]
+                fn from (x: $ty) -> Self {
+                    Self::filled_with(x)
+                }
+            }
+
+            impl Into<[$ty;$len]> for $target {
+                #[inline(always)]
+                fn into (self) -> [$ty;$len] {
+                    self.into_array()
+                }
+            }
+        )*
+    };
+}
+
+impl_portable!(
+    [f32;2] as f32x2,
+    [f32;3] as f32x3,
+    [f32;4] as f32x4,
+    [f32;6] as f32x6,
+    [f32;8] as f32x8,
+    [f32;10] as f32x10,
+    [f32;12] as f32x12,
+    [f32;14] as f32x14,
+    [f32;16] as f32x16,
+    [f32;24] as f32x24,
+    [f32;32] as f32x32,
+
+    [f64;2] as f64x2,
+    [f64;3] as f64x3,
+    [f64;4] as f64x4,
+    [f64;6] as f64x6,
+    [f64;8] as f64x8,
+    [f64;10] as f64x10,
+    [f64;12] as f64x12,
+    [f64;14] as f64x14,
+    [f64;16] as f64x16,
+    [f64;24] as f64x24,
+    [f64;32] as f64x32
+);
+
+// Pairs every lane `i` with the lane `i ^ J` for the sorting networks, leaving the lanes without one in place
+struct XorLanes<const J: usize>;
+
+impl<const J: usize, const N: usize> Swizzle<N> for XorLanes<J> {
+    const INDEX: [usize; N] = {
+        let mut index = [0; N];
+        let mut i = 0;
+        while i < N {
+            index[i] = if i ^ J < N { i ^ J } else { i };
+            i += 1;
+        }
+
+        index
+    };
+}
+
+macro_rules! impl_xor_lanes {
+    ($($len:literal as $target:ident),+) => {
+        $(
+            impl $target {
+                #[inline(always)]
+                pub(crate) fn xor_lanes (self, j: usize) -> Self {
+                    match j {
+                        1 => Self(<XorLanes<1> as Swizzle<$len>>::swizzle(self.0)),
+                        2 => Self(<XorLanes<2> as Swizzle<$len>>::swizzle(self.0)),
+                        4 => Self(<XorLanes<4> as Swizzle<$len>>::swizzle(self.0)),
+                        8 => Self(<XorLanes<8> as Swizzle<$len>>::swizzle(self.0)),
+                        _ => unreachable!()
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_xor_lanes!(
+    4 as f32x4,
+    8 as f32x8,
+    16 as f32x16,
+
+    4 as f64x4,
+    8 as f64x8,
+    16 as f64x16
+);
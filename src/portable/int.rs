@@ -0,0 +1,315 @@
+use llml_simd_proc::*;
+use core::ops::*;
+use core::simd::{Simd, Select};
+use core::simd::prelude::{SimdInt, SimdUint, SimdFloat, SimdOrd, SimdPartialEq, SimdPartialOrd};
+use super::{f32x4, f32x8, f64x2, f64x4, f64x8, f64x16};
+
+macro_rules! impl_portable_int {
+    (@arith $target:ident, $ty:ident, $($trait:ident, $fun:ident),+) => {
+        $(
+            impl $trait for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $fun (self, rhs: Self) -> Self::Output {
+                    Self(self.0.$fun(rhs.0))
+                }
+            }
+
+            impl_scal_arith!($target, $ty, $trait, $fun);
+        )*
+    };
+
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            #[allow(non_camel_case_types)]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Assign)]
+            #[assign_targets(Add, Sub, BitAnd, BitOr, BitXor)]
+            #[assign_rhs(Self, $ty)]
+            pub struct $target(pub(crate) Simd<$ty, $len>);
+            impl_portable_int!(
+                @arith $target, $ty,
+                Add, add,
+                Sub, sub,
+                BitAnd, bitand,
+                BitOr, bitor,
+                BitXor, bitxor
+            );
+
+            impl Not for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn not (self) -> Self::Output {
+                    Self(!self.0)
+                }
+            }
+
+            impl $target {
+                /// Loads values from the pointer into the SIMD vector
+                #[inline(always)]
+                pub unsafe fn load (ptr: *const $ty) -> Self {
+                    Self(Simd::from_array(*(ptr as *const [$ty;$len])))
+                }
+
+                /// Returns a vector with the smallest/minimum value of each lane
+                #[inline(always)]
+                pub fn vmin (self, rhs: Self) -> Self {
+                    Self(self.0.simd_min(rhs.0))
+                }
+
+                /// Returns a vector with the biggest/maximum value of each lane
+                #[inline(always)]
+                pub fn vmax (self, rhs: Self) -> Self {
+                    Self(self.0.simd_max(rhs.0))
+                }
+
+                // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+                #[inline(always)]
+                pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+                    match lanes {
+                        1 => self.shift_up_by::<1>(fill),
+                        2 => self.shift_up_by::<2>(fill),
+                        4 => self.shift_up_by::<4>(fill),
+                        8 => self.shift_up_by::<8>(fill),
+                        16 => self.shift_up_by::<16>(fill),
+                        _ => unreachable!()
+                    }
+                }
+
+                #[inline(always)]
+                fn shift_up_by<const S: usize> (self, fill: Self) -> Self {
+                    let inside = <Simd<$ty, $len> as SimdPartialEq>::Mask::from_array(core::array::from_fn(|i| i >= S));
+                    Self(inside.select(self.0.rotate_elements_right::<S>(), fill.0))
+                }
+
+                impl_scan!(@int $ty, $len);
+            }
+
+            impl From<$ty> for $target {
+                #[inline(always)]
+                fn from (x: $ty) -> Self {
+                    Self(Simd::splat(x))
+                }
+            }
+        )*
+    };
+
+    (@small $($target:ident => $wide:ident: $wty:ident, $half:literal),+) => {
+        $(
+            impl $target {
+                /// Returns a vector with the saturating addition of each lane
+                #[inline(always)]
+                pub fn saturating_add (self, rhs: Self) -> Self {
+                    Self(self.0.saturating_add(rhs.0))
+                }
+
+                /// Returns a vector with the saturating subtraction of each lane
+                #[inline(always)]
+                pub fn saturating_sub (self, rhs: Self) -> Self {
+                    Self(self.0.saturating_sub(rhs.0))
+                }
+
+                /// Returns a vector with the high half of the widened product of each lane
+                #[inline(always)]
+                pub fn mulhi (self, rhs: Self) -> Self {
+                    const BITS : $wty = (core::mem::size_of::<$wty>() * 4) as $wty;
+                    Self(((self.0.cast::<$wty>() * rhs.0.cast::<$wty>()) >> Simd::splat(BITS)).cast())
+                }
+
+                /// Returns a vector with the rounded up average of each lane
+                #[inline(always)]
+                pub fn avg (self, rhs: Self) -> Self {
+                    Self(((self.0.cast::<$wty>() + rhs.0.cast::<$wty>() + Simd::splat(1)) >> Simd::splat(1)).cast())
+                }
+
+                /// Widens the lanes into two vectors, with the lower and upper halves of the original vector respectively
+                #[inline(always)]
+                pub fn widen (self) -> ($wide, $wide) {
+                    let array = self.0.to_array();
+                    (
+                        $wide(Simd::<_, $half>::from_slice(&array[..$half]).cast()),
+                        $wide(Simd::<_, $half>::from_slice(&array[$half..]).cast())
+                    )
+                }
+            }
+        )*
+    };
+
+    (@narrow $($target:ident => $narrow:ident: $nty:ident, $half:literal),+) => {
+        $(
+            impl $target {
+                /// Narrows the lanes of both vectors into one, saturating the values that don't fit
+                #[inline(always)]
+                pub fn narrow (self, rhs: Self) -> $narrow {
+                    let (min, max) = (Simd::splat($nty::MIN as _), Simd::splat($nty::MAX as _));
+                    let lo = self.0.simd_clamp(min, max).cast::<$nty>().to_array();
+                    let hi = rhs.0.simd_clamp(min, max).cast::<$nty>().to_array();
+                    $narrow(Simd::from_array(core::array::from_fn(|i| if i < $half { lo[i] } else { hi[i - $half] })))
+                }
+            }
+        )*
+    };
+
+    (@wide $([$ty:ident;$len:literal] as $target:ident => $float:ident),+) => {
+        $(
+            impl_portable_int!(@arith $target, $ty, Mul, mul);
+
+            impl Shl<u32> for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shl (self, rhs: u32) -> Self::Output {
+                    Self(self.0 << Simd::splat((rhs & 63) as $ty))
+                }
+            }
+
+            impl Shr<u32> for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shr (self, rhs: u32) -> Self::Output {
+                    Self(self.0 >> Simd::splat((rhs & 63) as $ty))
+                }
+            }
+
+            impl Shl for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shl (self, rhs: Self) -> Self::Output {
+                    Self(self.0 << (rhs.0 & Simd::splat(63)))
+                }
+            }
+
+            impl Shr for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shr (self, rhs: Self) -> Self::Output {
+                    Self(self.0 >> (rhs.0 & Simd::splat(63)))
+                }
+            }
+
+            impl $target {
+                impl_scan!(@prod);
+
+                impl_portable_int!(
+                    @cmp $ty,
+                    veq => simd_eq: "equal to",
+                    vne => simd_ne: "not equal to",
+                    vlt => simd_lt: "less than",
+                    vle => simd_le: "less than or equal to",
+                    vgt => simd_gt: "greater than",
+                    vge => simd_ge: "greater than or equal to"
+                );
+
+                /// Converts every lane into a double-precision float
+                #[inline(always)]
+                pub fn to_f64 (self) -> $float {
+                    $float(self.0.cast())
+                }
+
+                /// Converts every lane of a double-precision float vector into an integer, truncating towards zero and saturating the values that don't fit (`NaN` becomes `0`)
+                #[inline(always)]
+                pub fn from_f64 (x: $float) -> Self {
+                    Self(x.0.cast())
+                }
+            }
+        )*
+    };
+
+    (@cmp $ty:ident, $($fun:ident => $op:ident: $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a mask with every bit set on the lanes where `self` is ", $docs, " `rhs`, and cleared otherwise")]
+            #[inline(always)]
+            pub fn $fun (self, rhs: Self) -> Self {
+                Self(self.0.$op(rhs.0).to_simd().cast())
+            }
+        )*
+    };
+
+    (@float $($target:ident => $float:ident),+) => {
+        $(
+            impl $target {
+                /// Converts every lane into a single-precision float
+                #[inline(always)]
+                pub fn to_f32 (self) -> $float {
+                    $float(self.0.cast())
+                }
+            }
+        )*
+    };
+}
+
+impl_portable_int!(
+    [i8;16] as i8x16,
+    [u8;16] as u8x16,
+    [i16;8] as i16x8,
+    [u16;8] as u16x8,
+    [i32;4] as i32x4,
+    [u32;4] as u32x4,
+
+    [i8;32] as i8x32,
+    [u8;32] as u8x32,
+    [i16;16] as i16x16,
+    [u16;16] as u16x16,
+    [i32;8] as i32x8,
+    [u32;8] as u32x8,
+
+    [i64;2] as i64x2,
+    [u64;2] as u64x2,
+    [i64;4] as i64x4,
+    [u64;4] as u64x4,
+    [i64;8] as i64x8,
+    [u64;8] as u64x8,
+    [i64;16] as i64x16,
+    [u64;16] as u64x16
+);
+
+impl_portable_int!(
+    @wide
+    [i64;2] as i64x2 => f64x2,
+    [u64;2] as u64x2 => f64x2,
+    [i64;4] as i64x4 => f64x4,
+    [u64;4] as u64x4 => f64x4,
+    [i64;8] as i64x8 => f64x8,
+    [u64;8] as u64x8 => f64x8,
+    [i64;16] as i64x16 => f64x16,
+    [u64;16] as u64x16 => f64x16
+);
+
+impl_portable_int!(
+    @small
+    i8x16 => i16x8: i16, 8,
+    u8x16 => u16x8: u16, 8,
+    i16x8 => i32x4: i32, 4,
+    u16x8 => u32x4: u32, 4,
+
+    i8x32 => i16x16: i16, 16,
+    u8x32 => u16x16: u16, 16,
+    i16x16 => i32x8: i32, 8,
+    u16x16 => u32x8: u32, 8
+);
+
+impl_portable_int!(
+    @narrow
+    i16x8 => i8x16: i8, 8,
+    u16x8 => u8x16: u8, 8,
+    i32x4 => i16x8: i16, 4,
+    u32x4 => u16x8: u16, 4,
+
+    i16x16 => i8x32: i8, 16,
+    u16x16 => u8x32: u8, 16,
+    i32x8 => i16x16: i16, 8,
+    u32x8 => u16x16: u16, 8
+);
+
+impl_portable_int!(
+    @float
+    i32x4 => f32x4,
+    u32x4 => f32x4,
+    i32x8 => f32x8,
+    u32x8 => f32x8
+);
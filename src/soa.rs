@@ -0,0 +1,185 @@
+use alloc::vec::Vec;
+use crate::float::single::f32x8;
+
+/// Number of lanes processed at once, and the length every buffer is padded to
+const WIDTH : usize = 8;
+
+macro_rules! soa_lane {
+    ($i:tt) => { f32x8 };
+}
+
+macro_rules! impl_soa {
+    ($($name:ident: $n:literal => [$($i:tt),+] as ($($axis:ident),+), $docs:expr),+) => {
+        $(
+            #[doc=concat!("Growable list of ", $docs, "-dimensional points stored in structure-of-arrays layout, with one buffer per axis.")]
+            #[doc=concat!("Every buffer is padded with zeros up to a multiple of 8 values, so that it can always be processed in whole ```f32x8``` vectors")]
+            #[derive(Debug, Clone, Default, PartialEq)]
+            pub struct $name {
+                buffers: [Vec<f32>;$n],
+                len: usize
+            }
+
+            impl $name {
+                /// Creates a new, empty list
+                #[inline(always)]
+                pub fn new () -> Self {
+                    Self::default()
+                }
+
+                /// Creates a new, empty list with room for at least `capacity` points
+                #[inline]
+                pub fn with_capacity (capacity: usize) -> Self {
+                    let capacity = capacity.next_multiple_of(WIDTH);
+                    Self {
+                        buffers: core::array::from_fn(|_| Vec::with_capacity(capacity)),
+                        len: 0
+                    }
+                }
+
+                /// Returns the number of points in the list
+                #[inline(always)]
+                pub fn len (&self) -> usize {
+                    self.len
+                }
+
+                /// Returns `true` if the list has no points
+                #[inline(always)]
+                pub fn is_empty (&self) -> bool {
+                    self.len == 0
+                }
+
+                $(
+                    #[doc=concat!("Returns the ", stringify!($axis), " coordinates of every point, without padding")]
+                    #[inline(always)]
+                    pub fn $axis (&self) -> &[f32] {
+                        &self.buffers[$i][..self.len]
+                    }
+                )+
+
+                /// Appends a point at the end of the list
+                #[inline]
+                pub fn push (&mut self, point: [f32;$n]) {
+                    if self.len % WIDTH == 0 {
+                        for buffer in self.buffers.iter_mut() {
+                            buffer.resize(self.len + WIDTH, 0.);
+                        }
+                    }
+
+                    let idx = self.len;
+                    self.len += 1;
+                    self.set(idx, point);
+                }
+
+                /// Returns the point at the specified index, or `None` if it's out of bounds
+                #[inline]
+                pub fn get (&self, idx: usize) -> Option<[f32;$n]> {
+                    if idx >= self.len {
+                        return None
+                    }
+
+                    Some([$(self.buffers[$i][idx]),+])
+                }
+
+                /// Replaces the point at the specified index
+                /// # Panics
+                /// Panics if the index is out of bounds
+                #[inline]
+                pub fn set (&mut self, idx: usize, point: [f32;$n]) {
+                    assert!(idx < self.len, "Index out of bounds");
+                    $(self.buffers[$i][idx] = point[$i];)+
+                }
+
+                /// Removes every point from the list
+                #[inline]
+                pub fn clear (&mut self) {
+                    for buffer in self.buffers.iter_mut() {
+                        buffer.clear();
+                    }
+
+                    self.len = 0;
+                }
+
+                /// Returns an iterator over the points, in bundles of eight per axis.
+                /// The lanes of the last bundle past the end of the list are zero
+                #[inline]
+                pub fn chunks (&self) -> impl Iterator<Item = ($(soa_lane!($i)),+)> + '_ {
+                    (0..self.buffers[0].len()).step_by(WIDTH).map(move |i| unsafe {
+                        ($(f32x8::load(self.buffers[$i].as_ptr().add(i))),+)
+                    })
+                }
+
+                /// Applies the function to every bundle of points, storing the result back into the list
+                #[inline]
+                fn map_chunks<F: Fn([f32x8;$n]) -> [f32x8;$n]> (&mut self, f: F) {
+                    for i in (0..self.buffers[0].len()).step_by(WIDTH) {
+                        let chunk = unsafe { [$(f32x8::load(self.buffers[$i].as_ptr().add(i))),+] };
+                        let result = f(chunk);
+                        $(self.buffers[$i][i..i + WIDTH].copy_from_slice(&result[$i].into_array());)+
+                    }
+
+                    // transforms might have filled the padding, which has to stay zeroed
+                    let len = self.len;
+                    for buffer in self.buffers.iter_mut() {
+                        buffer[len..].fill(0.);
+                    }
+                }
+
+                /// Adds the offset to every point
+                #[inline]
+                pub fn translate (&mut self, offset: [f32;$n]) {
+                    let offset = offset.map(f32x8::from);
+                    self.map_chunks(|chunk| [$(chunk[$i] + offset[$i]),+]);
+                }
+
+                /// Multiplies every coordinate by the factor of its axis
+                #[inline]
+                pub fn scale (&mut self, factors: [f32;$n]) {
+                    let factors = factors.map(f32x8::from);
+                    self.map_chunks(|chunk| [$(chunk[$i] * factors[$i]),+]);
+                }
+
+                /// Multiplies every point by the (row-major) matrix
+                #[inline]
+                pub fn apply_matrix (&mut self, matrix: [[f32;$n];$n]) {
+                    let matrix = matrix.map(|row| row.map(f32x8::from));
+                    self.map_chunks(|chunk| [$(
+                        (1..$n).fold(chunk[0] * matrix[$i][0], |acc, j| chunk[j].mul_add(matrix[$i][j], acc))
+                    ),+]);
+                }
+
+                /// Scales every point to unit length. Points of zero length become `NaN`
+                #[cfg(any(feature = "use_std", all(not(feature = "force_naive"), not(feature = "portable_simd"), any(
+                    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+                    all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"),
+                    all(target_arch = "wasm32", target_feature = "simd128")
+                ))))]
+                #[inline]
+                pub fn normalize (&mut self) {
+                    self.map_chunks(|chunk| {
+                        let length = (1..$n).fold(chunk[0] * chunk[0], |acc, j| chunk[j].mul_add(chunk[j], acc)).sqrt();
+                        [$(chunk[$i] / length),+]
+                    });
+                }
+            }
+
+            impl FromIterator<[f32;$n]> for $name {
+                #[inline]
+                fn from_iter<I: IntoIterator<Item = [f32;$n]>> (iter: I) -> Self {
+                    let iter = iter.into_iter();
+                    let mut result = Self::with_capacity(iter.size_hint().0);
+
+                    for point in iter {
+                        result.push(point);
+                    }
+
+                    result
+                }
+            }
+        )*
+    };
+}
+
+impl_soa!(
+    SoaVec3: 3 => [0, 1, 2] as (x, y, z), "three",
+    SoaVec4: 4 => [0, 1, 2, 3] as (x, y, z, w), "four"
+);
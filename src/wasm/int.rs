@@ -0,0 +1,388 @@
+use core::ops::*;
+use llml_simd_proc::*;
+use core::arch::wasm32::*;
+use crate::float::single::{f32x4, f32x8};
+use crate::float::double::{f64x2, f64x4, f64x8, f64x16};
+
+macro_rules! impl_int {
+    (@arith $target:ident, $ty:ident, $($trait:ident, $fun:ident => $op:expr),+) => {
+        $(
+            impl $trait for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $fun (self, rhs: Self) -> Self::Output {
+                    Self($op(self.0, rhs.0))
+                }
+            }
+
+            impl_scal_arith!($target, $ty, $trait, $fun);
+        )*
+    };
+
+    (@other $target:ident, $($fun:ident => $op:ident: $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a vector with the ", $docs, " of each lane")]
+            #[inline(always)]
+            pub fn $fun (self, rhs: Self) -> Self {
+                Self(concat_idents!($target, _, $op)(self.0, rhs.0))
+            }
+        )*
+    };
+
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`,
+    // by shuffling the bytes of `fill` followed by those of the vector itself
+    (@shift_up $ty:ident) => {
+        #[inline(always)]
+        pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+            Self(match lanes * core::mem::size_of::<$ty>() {
+                1 => i8x16_shuffle::<0, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30>(fill.0, self.0),
+                2 => i8x16_shuffle::<0, 1, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29>(fill.0, self.0),
+                4 => i8x16_shuffle::<0, 1, 2, 3, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27>(fill.0, self.0),
+                8 => i8x16_shuffle::<0, 1, 2, 3, 4, 5, 6, 7, 16, 17, 18, 19, 20, 21, 22, 23>(fill.0, self.0),
+                _ => unreachable!()
+            })
+        }
+    };
+
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            #[allow(non_camel_case_types)]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Assign)]
+            #[assign_targets(Add, Sub, BitAnd, BitOr, BitXor)]
+            #[assign_rhs(Self, $ty)]
+            pub struct $target(pub(crate) v128);
+            impl_int!(
+                @arith $target, $ty,
+                Add, add => (concat_idents!($target, _add)),
+                Sub, sub => (concat_idents!($target, _sub)),
+                BitAnd, bitand => v128_and,
+                BitOr, bitor => v128_or,
+                BitXor, bitxor => v128_xor
+            );
+
+            impl Not for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn not (self) -> Self::Output {
+                    Self(v128_not(self.0))
+                }
+            }
+
+            impl $target {
+                /// Loads values from the pointer into the SIMD vector
+                #[inline(always)]
+                pub unsafe fn load (ptr: *const $ty) -> Self {
+                    Self(v128_load(ptr as *const v128))
+                }
+
+                impl_int!(
+                    @other $target,
+                    vmin => min: "smallest/minimum value",
+                    vmax => max: "biggest/maximum value"
+                );
+
+                impl_int!(@shift_up $ty);
+                impl_scan!(@int $ty, $len);
+            }
+
+            impl From<$ty> for $target {
+                #[inline(always)]
+                fn from (x: $ty) -> Self {
+                    Self(concat_idents!($target, _splat)(x))
+                }
+            }
+        )*
+    };
+
+    (@small $($target:ident => $wide:ident: $avg:ident $(flip $min:expr)?, $mul_lo:ident, $mul_hi:ident, $shr:ident, $bits:literal, $pack:ident, $lo:ident, $hi:ident),+) => {
+        $(
+            impl $target {
+                impl_int!(
+                    @other $target,
+                    saturating_add => add_sat: "saturating addition",
+                    saturating_sub => sub_sat: "saturating subtraction"
+                );
+
+                /// Returns a vector with the rounded up average of each lane
+                #[inline(always)]
+                pub fn avg (self, rhs: Self) -> Self {
+                    // signed lanes are offset into the unsigned range and back
+                    let sign = Self::from(0 $(| $min)?).0;
+                    Self(v128_xor($avg(v128_xor(self.0, sign), v128_xor(rhs.0, sign)), sign))
+                }
+
+                /// Returns a vector with the high half of the widened product of each lane
+                #[inline(always)]
+                pub fn mulhi (self, rhs: Self) -> Self {
+                    let lo = $shr($mul_lo(self.0, rhs.0), $bits);
+                    let hi = $shr($mul_hi(self.0, rhs.0), $bits);
+                    Self($pack(lo, hi))
+                }
+
+                /// Widens the lanes into two vectors, with the lower and upper halves of the original vector respectively
+                #[inline(always)]
+                pub fn widen (self) -> ($wide, $wide) {
+                    ($wide($lo(self.0)), $wide($hi(self.0)))
+                }
+            }
+        )*
+    };
+
+    (@narrow $($target:ident => $narrow:ident: $pack:ident $(clamp $max:expr)?),+) => {
+        $(
+            impl $target {
+                /// Narrows the lanes of both vectors into one, saturating the values that don't fit
+                #[inline(always)]
+                pub fn narrow (self, rhs: Self) -> $narrow {
+                    let (lhs, rhs) = (self, rhs);
+                    // packing saturates signed lanes, so bigger unsigned values are clamped first
+                    $(let (lhs, rhs) = (lhs.vmin(Self::from($max)), rhs.vmin(Self::from($max)));)?
+                    $narrow($pack(lhs.0, rhs.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(
+    [i8;16] as i8x16,
+    [u8;16] as u8x16,
+    [i16;8] as i16x8,
+    [u16;8] as u16x8,
+    [i32;4] as i32x4,
+    [u32;4] as u32x4
+);
+
+impl_int!(
+    @small
+    i8x16 => i16x8: u8x16_avgr flip i8::MIN, i16x8_extmul_low_i8x16, i16x8_extmul_high_i8x16, i16x8_shr, 8, i8x16_narrow_i16x8, i16x8_extend_low_i8x16, i16x8_extend_high_i8x16,
+    u8x16 => u16x8: u8x16_avgr, u16x8_extmul_low_u8x16, u16x8_extmul_high_u8x16, u16x8_shr, 8, u8x16_narrow_i16x8, u16x8_extend_low_u8x16, u16x8_extend_high_u8x16,
+    i16x8 => i32x4: u16x8_avgr flip i16::MIN, i32x4_extmul_low_i16x8, i32x4_extmul_high_i16x8, i32x4_shr, 16, i16x8_narrow_i32x4, i32x4_extend_low_i16x8, i32x4_extend_high_i16x8,
+    u16x8 => u32x4: u16x8_avgr, u32x4_extmul_low_u16x8, u32x4_extmul_high_u16x8, u32x4_shr, 16, u16x8_narrow_i32x4, u32x4_extend_low_u16x8, u32x4_extend_high_u16x8
+);
+
+impl_int!(
+    @narrow
+    i16x8 => i8x16: i8x16_narrow_i16x8,
+    u16x8 => u8x16: u8x16_narrow_i16x8 clamp u8::MAX as u16,
+    i32x4 => i16x8: i16x8_narrow_i32x4,
+    u32x4 => u16x8: u16x8_narrow_i32x4 clamp u16::MAX as u32
+);
+
+impl i32x4 {
+    /// Converts every lane into a single-precision float
+    #[inline(always)]
+    pub fn to_f32 (self) -> f32x4 {
+        f32x4(f32x4_convert_i32x4(self.0))
+    }
+}
+
+impl u32x4 {
+    /// Converts every lane into a single-precision float
+    #[inline(always)]
+    pub fn to_f32 (self) -> f32x4 {
+        f32x4(f32x4_convert_u32x4(self.0))
+    }
+}
+
+impl_int_composite!(
+    i8x16 as i8x32: i8,
+    u8x16 as u8x32: u8,
+    i16x8 as i16x16: i16,
+    u16x8 as u16x16: u16,
+    i32x4 as i32x8: i32,
+    u32x4 as u32x8: u32
+);
+
+impl_int_composite!(
+    @small
+    i8x32 => i16x16,
+    u8x32 => u16x16,
+    i16x16 => i32x8,
+    u16x16 => u32x8
+);
+
+impl_int_composite!(
+    @narrow
+    i16x16 => i8x32,
+    u16x16 => u8x32,
+    i32x8 => i16x16,
+    u32x8 => u16x16
+);
+
+impl_int_composite!(
+    @float
+    i32x8 => to_f32 -> f32x8: f32, "single-precision",
+    u32x8 => to_f32 -> f32x8: f32, "single-precision"
+);
+
+macro_rules! impl_int64 {
+    (@flip signed $x:expr) => {
+        $x
+    };
+
+    // unsigned lanes are compared as signed ones after flipping their sign bits
+    (@flip unsigned $x:expr) => {
+        v128_xor($x, i64x2_splat(i64::MIN))
+    };
+
+    (@cmp $kind:ident, $($fun:ident => $op:ident: $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a mask with every bit set on the lanes where `self` is ", $docs, " `rhs`, and cleared otherwise")]
+            #[inline(always)]
+            pub fn $fun (self, rhs: Self) -> Self {
+                Self($op(impl_int64!(@flip $kind self.0), impl_int64!(@flip $kind rhs.0)))
+            }
+        )*
+    };
+
+    ($([$ty:ident;$len:literal] as $target:ident => $float:ident: $kind:ident),+) => {
+        $(
+            #[allow(non_camel_case_types)]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Assign)]
+            #[assign_targets(Add, Sub, BitAnd, BitOr, BitXor)]
+            #[assign_rhs(Self, $ty)]
+            pub struct $target(pub(crate) v128);
+            impl_int!(
+                @arith $target, $ty,
+                Add, add => i64x2_add,
+                Sub, sub => i64x2_sub,
+                Mul, mul => i64x2_mul,
+                BitAnd, bitand => v128_and,
+                BitOr, bitor => v128_or,
+                BitXor, bitxor => v128_xor
+            );
+
+            impl Not for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn not (self) -> Self::Output {
+                    Self(v128_not(self.0))
+                }
+            }
+
+            impl Shl<u32> for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shl (self, rhs: u32) -> Self::Output {
+                    Self(i64x2_shl(self.0, rhs))
+                }
+            }
+
+            impl Shr<u32> for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shr (self, rhs: u32) -> Self::Output {
+                    Self(concat_idents!($target, _shr)(self.0, rhs))
+                }
+            }
+
+            impl Shl for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shl (self, rhs: Self) -> Self::Output {
+                    // there are no shifts by vector, so they're done lane by lane
+                    let (alpha, beta) = (self.into_array(), rhs.into_array());
+                    Self::from([alpha[0].wrapping_shl(beta[0] as u32), alpha[1].wrapping_shl(beta[1] as u32)])
+                }
+            }
+
+            impl Shr for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shr (self, rhs: Self) -> Self::Output {
+                    // there are no shifts by vector, so they're done lane by lane
+                    let (alpha, beta) = (self.into_array(), rhs.into_array());
+                    Self::from([alpha[0].wrapping_shr(beta[0] as u32), alpha[1].wrapping_shr(beta[1] as u32)])
+                }
+            }
+
+            impl $target {
+                /// Loads values from the pointer into the SIMD vector
+                #[inline(always)]
+                pub unsafe fn load (ptr: *const $ty) -> Self {
+                    Self(v128_load(ptr as *const v128))
+                }
+
+                impl_int!(@shift_up $ty);
+                impl_scan!(@int $ty, $len);
+                impl_scan!(@prod);
+
+                impl_int64!(
+                    @cmp $kind,
+                    veq => i64x2_eq: "equal to",
+                    vne => i64x2_ne: "not equal to",
+                    vlt => i64x2_lt: "less than",
+                    vle => i64x2_le: "less than or equal to",
+                    vgt => i64x2_gt: "greater than",
+                    vge => i64x2_ge: "greater than or equal to"
+                );
+
+                /// Returns a vector with the smallest/minimum value of each lane
+                #[inline(always)]
+                pub fn vmin (self, rhs: Self) -> Self {
+                    Self(v128_bitselect(self.0, rhs.0, self.vlt(rhs).0))
+                }
+
+                /// Returns a vector with the biggest/maximum value of each lane
+                #[inline(always)]
+                pub fn vmax (self, rhs: Self) -> Self {
+                    Self(v128_bitselect(self.0, rhs.0, self.vgt(rhs).0))
+                }
+
+                /// Converts every lane into a double-precision float
+                #[inline(always)]
+                pub fn to_f64 (self) -> $float {
+                    // there's no 64-bit integer conversion, so it's done lane by lane
+                    $float::from(self.into_array().map(|x| x as f64))
+                }
+
+                /// Converts every lane of a double-precision float vector into an integer, truncating towards zero and saturating the values that don't fit (`NaN` becomes `0`)
+                #[inline(always)]
+                pub fn from_f64 (x: $float) -> Self {
+                    Self::from(x.into_array().map(|x| x as $ty))
+                }
+            }
+
+            impl From<$ty> for $target {
+                #[inline(always)]
+                fn from (x: $ty) -> Self {
+                    Self(concat_idents!($target, _splat)(x))
+                }
+            }
+        )*
+    };
+}
+
+impl_int64!(
+    [i64;2] as i64x2 => f64x2: signed,
+    [u64;2] as u64x2 => f64x2: unsigned
+);
+
+impl_int_composite!(
+    i64x2 as i64x4: i64,
+    u64x2 as u64x4: u64,
+    i64x4 as i64x8: i64,
+    u64x4 as u64x8: u64,
+    i64x8 as i64x16: i64,
+    u64x8 as u64x16: u64
+);
+
+impl_int_composite!(
+    @wide
+    i64x4: i64 => f64x4(i64x2, f64x2),
+    u64x4: u64 => f64x4(u64x2, f64x2),
+    i64x8: i64 => f64x8(i64x4, f64x4),
+    u64x8: u64 => f64x8(u64x4, f64x4),
+    i64x16: i64 => f64x16(i64x8, f64x8),
+    u64x16: u64 => f64x16(u64x8, f64x8)
+);
@@ -1 +1 @@
-flat_mod!(straight, special);
\ No newline at end of file
+flat_mod!(straight, special, int);
\ No newline at end of file
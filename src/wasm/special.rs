@@ -7,12 +7,13 @@ use core::arch::wasm::*;
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 #[derive(Copy, Assign)]
-#[assign_targets(Add, Sub, Mul, Div)]
+#[assign_targets(Add, Sub, Mul, Div, Rem)]
 #[assign_rhs(Self, f32)]
 pub struct f32x2 (pub(crate) v128);
 
 impl f32x2 {
     const DIV_MASK : v128 = unsafe { transmute([u32::MAX, u32::MAX, 0, 0]) };
+    const STEP_MASK : v128 = unsafe { transmute([1f32, 1f32, 0f32, 0f32]) };
 
     /// Loads values from the pointer into the SIMD vector
     #[inline(always)]
@@ -20,6 +21,8 @@ impl f32x2 {
         Self(core::arch::wasm::f32x4(*ptr, *ptr.add(1), 0., 0.))
     }
 
+    impl_aligned_fallback!(f32, 2);
+
     /// Returns a vector with the absolute values of the original vector
     #[inline(always)]
     pub fn abs (self) -> Self {
@@ -32,7 +35,20 @@ impl f32x2 {
         unsafe { Self(v128_and(Self::DIV_MASK, f32x4_sqrt(self.0))) }
     }
 
+    /// Returns a vector with the integer parts of the original vector
+    #[inline(always)]
+    pub fn trunc (self) -> Self {
+        unsafe { Self(f32x4_trunc(self.0)) }
+    }
+
+    /// Returns a vector with the floors of the original vector
+    #[inline(always)]
+    pub fn floor (self) -> Self {
+        unsafe { Self(f32x4_floor(self.0)) }
+    }
+
     /// Gets the smallest/minimum value of the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn min (self) -> f32 {
         unsafe {
@@ -42,6 +58,7 @@ impl f32x2 {
     }
 
     /// Gets the biggest/maximum value of the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn max (self) -> f32 {
         unsafe {
@@ -51,6 +68,7 @@ impl f32x2 {
     }
 
     /// Sums up all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn sum (self) -> f32 {
         unsafe {
@@ -60,6 +78,7 @@ impl f32x2 {
     }
 
     /// Multiplies the vector by a scalar
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn prod (self) -> f32 {
         unsafe {
@@ -69,21 +88,80 @@ impl f32x2 {
     }
 
     /// Returns a vector with the smallest/minimum value of each lane
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn vmin (self, rhs: Self) -> Self {
         unsafe { Self(f32x4_pmin(self.0, rhs.0)) }
     }
 
     /// Returns a vector with the absolute values of the original vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn vmax (self, rhs: Self) -> Self {
         unsafe { Self(f32x4_pmax(self.0, rhs.0)) }
     }
 
+    /// Returns a vector with the step function (`0` if smaller than `rhs`, `1` otherwise) of each lane
+    #[inline(always)]
+    pub fn step (self, rhs: Self) -> Self {
+        unsafe { Self(v128_and(Self::STEP_MASK, f32x4_ge(self.0, rhs.0))) }
+    }
+
+    impl_interp_fns!(f32);
+    impl_euclid_fns!(f32);
+
+    #[inline(always)]
+    pub(crate) fn cmp_lt (self, rhs: Self) -> Self {
+        unsafe { Self(f32x4_lt(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmp_eq (self, rhs: Self) -> Self {
+        unsafe { Self(f32x4_eq(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn bit_and (self, rhs: Self) -> Self {
+        unsafe { Self(v128_and(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn bit_or (self, rhs: Self) -> Self {
+        unsafe { Self(v128_or(self.0, rhs.0)) }
+    }
+
+    // WebAssembly clears the bits of its second operand instead
+    #[inline(always)]
+    pub(crate) fn bit_andnot (self, rhs: Self) -> Self {
+        unsafe { Self(v128_andnot(rhs.0, self.0)) }
+    }
+
+    impl_mask_fns!(f32);
+
+    #[cfg(feature = "deterministic")]
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f32) -> [Self; 1] {
+        [self]
+    }
+
+    impl_reduce_pairs!(f32);
+
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill` and keeping the padding lanes
+    #[inline(always)]
+    pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+        match lanes {
+            1 => unsafe { Self(u32x4_shuffle::<0, 4, 6, 7>(fill.0, self.0)) },
+            _ => unreachable!()
+        }
+    }
+
+    impl_scan!(@float f32, 2);
+
     /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
     /// # Compatibility
     /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
     /// For the rest of targets, a regular multiplication and addition are performed
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn mul_add (self, rhs: Self, add: Self) -> Self {
         (self * rhs) + add
@@ -167,4 +245,6 @@ impl_scal_arith!(
     Sub, sub,
     Mul, mul,
     Div, div
-);
\ No newline at end of file
+);
+
+impl_rem!(f32x2, f32);
\ No newline at end of file
@@ -22,6 +22,7 @@ macro_rules! impl_arith {
     };
 }
 
+#[cfg_attr(feature = "deterministic", allow(unused_macros))]
 macro_rules! f32x4_hoz {
     ($($fun:ident $(as $name:ident)?: $docs:expr),+) => {
         $(
@@ -66,6 +67,7 @@ macro_rules! impl_self_fns_stright {
     };
 }
 
+#[cfg_attr(feature = "deterministic", allow(unused_macros))]
 macro_rules! impl_other_fns_straight {
     ($target:ident, $ty:ident, $($fun:ident $(as $name:ident)? $(with $tag:ident)?: $docs:expr),+) => {
         $(
@@ -92,9 +94,9 @@ macro_rules! impl_straight {
             #[allow(non_camel_case_types)]
             #[repr(transparent)]
             #[derive(Copy, Assign)]
-            #[assign_targets(Add, Sub, Mul, Div)]
+            #[assign_targets(Add, Sub, Mul, Div, Rem)]
             #[assign_rhs(Self, $ty)]
-            pub struct $name(v128);
+            pub struct $name(pub(crate) v128);
 
             impl_arith!(
                 $name, $ty,
@@ -104,6 +106,8 @@ macro_rules! impl_straight {
                 Div, div
             );
 
+            impl_rem!($name, $ty);
+
             impl Neg for $name {
                 type Output = Self;
 
@@ -119,18 +123,75 @@ macro_rules! impl_straight {
                 pub unsafe fn load (ptr: *const $ty) -> Self {
                     Self(v128_load(ptr.cast()))
                 }
+
+                /// Loads values from the pointer into the SIMD vector.
+                /// The pointer must be aligned to 16 bytes, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn load_aligned (ptr: *const $ty) -> Self {
+                    // WebAssembly loads have no alignment requirement, so the regular one is used
+                    debug_assert_eq!(ptr as usize % 16, 0, "pointer is not aligned");
+                    Self::load(ptr)
+                }
+
+                /// Stores the values of the vector into the pointer.
+                /// The pointer must be aligned to 16 bytes, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn store_aligned (self, ptr: *mut $ty) {
+                    debug_assert_eq!(ptr as usize % 16, 0, "pointer is not aligned");
+                    v128_store(ptr.cast(), self.0)
+                }
                 
                 impl_self_fns_stright!(
                     $name, $ty,
                     abs: "absolute values",
-                    sqrt: "square roots"
+                    sqrt: "square roots",
+                    trunc: "integer parts",
+                    floor: "floors"
                 );
 
+                #[cfg(not(feature = "deterministic"))]
                 impl_other_fns_straight!(
                     $name, $ty,
                     pmin as vmin: "smallest/minimum value",
                     pmax as vmax: "biggest/maximum value"
                 );
+
+                /// Returns a vector with the step function (`0` if smaller than `rhs`, `1` otherwise) of each lane
+                #[inline(always)]
+                pub fn step (self, rhs: Self) -> Self {
+                    unsafe { Self(v128_and(concat_idents!($name, _ge)(self.0, rhs.0), Self::from(1 as $ty).0)) }
+                }
+
+                impl_interp_fns!($ty);
+                impl_euclid_fns!($ty);
+
+                #[inline(always)]
+                pub(crate) fn cmp_lt (self, rhs: Self) -> Self {
+                    unsafe { Self(concat_idents!($name, _lt)(self.0, rhs.0)) }
+                }
+
+                #[inline(always)]
+                pub(crate) fn cmp_eq (self, rhs: Self) -> Self {
+                    unsafe { Self(concat_idents!($name, _eq)(self.0, rhs.0)) }
+                }
+
+                #[inline(always)]
+                pub(crate) fn bit_and (self, rhs: Self) -> Self {
+                    unsafe { Self(v128_and(self.0, rhs.0)) }
+                }
+
+                #[inline(always)]
+                pub(crate) fn bit_or (self, rhs: Self) -> Self {
+                    unsafe { Self(v128_or(self.0, rhs.0)) }
+                }
+
+                // WebAssembly clears the bits of its second operand instead
+                #[inline(always)]
+                pub(crate) fn bit_andnot (self, rhs: Self) -> Self {
+                    unsafe { Self(v128_andnot(rhs.0, self.0)) }
+                }
+
+                impl_mask_fns!($ty);
             }
 
             impl From<$ty> for $name {
@@ -154,6 +215,7 @@ macro_rules! impl_straight {
 }
 
 impl f32x4 { 
+    #[cfg(not(feature = "deterministic"))]
     f32x4_hoz!(
         pmin as min: "Gets the smallest/minimum value of the vector",
         pmax as max: "Gets the biggest/maximum value of the vector", 
@@ -165,6 +227,7 @@ impl f32x4 {
     /// # Compatibility
     /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
     /// For the rest of targets, a regular multiplication and addition are performed
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn mul_add (self, rhs: Self, add: Self) -> Self {
         (self * rhs) + add
@@ -179,6 +242,7 @@ impl f32x4 {
 
 impl f64x2 {
     /// Gets the smallest/minimum value of the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn min (self) -> f64 {
         unsafe { 
@@ -188,6 +252,7 @@ impl f64x2 {
     }
 
     /// Gets the biggest/maximum value of the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn max (self) -> f64 {
         unsafe { 
@@ -197,6 +262,7 @@ impl f64x2 {
     }
 
     /// Sums up all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn sum (self) -> f64 {
         unsafe { 
@@ -206,6 +272,7 @@ impl f64x2 {
     }
 
     /// Multiplies all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn prod (self) -> f64 {
         unsafe { 
@@ -218,6 +285,7 @@ impl f64x2 {
     /// # Compatibility
     /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
     /// For the rest of targets, a regular multiplication and addition are performed
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn mul_add (self, rhs: Self, add: Self) -> Self {
         (self * rhs) + add
@@ -237,9 +305,90 @@ impl_straight!(
     [f64;2] as f64x2
 );
 
+// The sorting networks pair every lane `i` with the lane `i ^ j`
+impl f32x4 {
+    #[inline(always)]
+    pub(crate) fn xor_lanes (self, j: usize) -> Self {
+        unsafe {
+            match j {
+                1 => Self(u32x4_shuffle::<1, 0, 3, 2>(self.0, self.0)),
+                2 => Self(u32x4_shuffle::<2, 3, 0, 1>(self.0, self.0)),
+                _ => unreachable!()
+            }
+        }
+    }
+}
+
+impl f64x2 {
+    #[inline(always)]
+    pub(crate) fn xor_lanes (self, j: usize) -> Self {
+        match j {
+            1 => unsafe { Self(u64x2_shuffle::<1, 0>(self.0, self.0)) },
+            _ => unreachable!()
+        }
+    }
+}
+
+// The lanes moving up are shuffled from `fill` followed by the vector itself
+impl f32x4 {
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+    #[inline(always)]
+    pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+        unsafe {
+            match lanes {
+                1 => Self(u32x4_shuffle::<0, 4, 5, 6>(fill.0, self.0)),
+                2 => Self(u32x4_shuffle::<0, 1, 4, 5>(fill.0, self.0)),
+                _ => unreachable!()
+            }
+        }
+    }
+
+    impl_scan!(@float f32, 4);
+}
+
+impl f64x2 {
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+    #[inline(always)]
+    pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+        match lanes {
+            1 => unsafe { Self(u64x2_shuffle::<0, 2>(fill.0, self.0)) },
+            _ => unreachable!()
+        }
+    }
+
+    impl_scan!(@float f64, 2);
+}
+
+// The deterministic reductions split the registers in their 64-bit halves
+#[cfg(feature = "deterministic")]
+impl f32x4 {
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f32) -> [f32x2; 2] {
+        unsafe { [f32x2(self.0), f32x2(u64x2_shuffle::<1, 1>(self.0, self.0))] }
+    }
+
+    impl_reduce_pairs!(f32);
+}
+
+#[cfg(feature = "deterministic")]
+impl f64x2 {
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f64) -> [Self; 1] {
+        [self]
+    }
+
+    impl_reduce_pairs!(f64);
+}
+
 impl_composite!(
     (f32x4 => 4, f32x2 => 2) as f32x6: f32,
     (f32x4 => 4, f32x4 => 4) as f32x8: f32,
+    (f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x10: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x12: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x14: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x16: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x24: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x32: f32,
 
     (f64x2 => 2, f64x2 => 2) as f64x4: f64,
     (f64x4 => 4, f64x2 => 2) as f64x6: f64,
@@ -247,15 +396,21 @@ impl_composite!(
     (f64x6 => 6, f64x4 => 4) as f64x10: f64,
     (f64x6 => 6, f64x6 => 6) as f64x12: f64,
     (f64x8 => 8, f64x6 => 6) as f64x14: f64,
-    (f64x8 => 8, f64x8 => 8) as f64x16: f64
+    (f64x8 => 8, f64x8 => 8) as f64x16: f64,
+    (f64x8 => 8, f64x8 => 8, f64x8 => 8) as f64x24: f64,
+    (f64x8 => 8, f64x8 => 8, f64x8 => 8, f64x8 => 8) as f64x32: f64
 );
 
-impl_composite!(
-    (f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x10: f32,
-    (f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x12: f32
+impl_padded!(
+    f32x4 as f32x3: f32,
+    f64x4 as f64x3: f64
 );
 
-impl_composite!(
-    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x14: f32,
-    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x16: f32
-);
\ No newline at end of file
+impl_xor_lanes!(
+    [f32x4 => 4; 2] as f32x8,
+    [f32x4 => 4; 4] as f32x16,
+
+    [f64x2 => 2; 2] as f64x4,
+    [f64x4 => 4; 2] as f64x8,
+    [f64x8 => 8; 2] as f64x16
+);
@@ -31,6 +31,7 @@ macro_rules! abs_mask {
     };
 }
 
+#[cfg_attr(feature = "deterministic", allow(unused_macros))]
 macro_rules! impl_hoz_fns_straight {
     (f32, $($fun:ident as $name:ident: $docs:expr),+) => {
         $(
@@ -53,11 +54,10 @@ macro_rules! impl_hoz_fns_straight {
             #[inline(always)]
             pub fn $name (self) -> f64 {
                 unsafe {
-                    let shuf = _mm256_shuffle_pd(self.0, self.0, _MM_SHUFFLE(2, 3, 0, 1));
-                    let sums = _mm_concat!($fun, f64)(self.0, shuf);
-                    let shuff = _mm256_shuffle_pd(shuf, sums, _MM_SHUFFLE(0, 1, 4, 5));
-                    let sums = _mm_concat!($fun, f64)(sums, shuf);
-                    _mm256_cvtsd_f64(sums)
+                    let vlow  = _mm256_castpd256_pd128(self.0);
+                    let vhigh = _mm256_extractf128_pd(self.0, 1); // high 128
+                    let v = f64x2(concat_idents!(_mm_, $fun, _pd)(vlow, vhigh));
+                    return v.$name();
                 }
             }
         )*
@@ -65,6 +65,15 @@ macro_rules! impl_hoz_fns_straight {
 }
 
 macro_rules! impl_other_fns_straight {
+    (@mask $ty:ident, $($fun:ident as $name:ident),+) => {
+        $(
+            #[inline(always)]
+            pub(crate) fn $name (self, rhs: Self) -> Self {
+                unsafe { Self(_mm_concat!($fun, $ty)(self.0, rhs.0)) }
+            }
+        )*
+    };
+
     ($ty:ident, $($fun:ident $(as $name:ident)?: $docs:expr),+) => {
         $(
             impl_other_fns_straight!(1, $fun $(,$name)?, $ty, $docs);
@@ -80,7 +89,7 @@ macro_rules! impl_other_fns_straight {
     };
 
     (1, $fun:ident, $ty:ident, $docs:expr) => {
-        impl_other_fns!(1, $fun, $fun, $ty, $docs, $($tag)?)
+        impl_other_fns_straight!(1, $fun, $fun, $ty, $docs);
     };
 }
 
@@ -105,7 +114,7 @@ macro_rules! impl_straight {
             #[allow(non_camel_case_types)]
             #[repr(transparent)]
             #[derive(Copy, Assign)]
-            #[assign_targets(Add, Sub, Mul, Div)]
+            #[assign_targets(Add, Sub, Mul, Div, Rem)]
             #[assign_rhs(Self, $ty)]
             pub struct $target(pub(crate) $og);
             impl_straight!(
@@ -116,6 +125,8 @@ macro_rules! impl_straight {
                 Div, div, $($tag)?
             );
 
+            impl_rem!($target, $ty);
+
             impl Neg for $target {
                 type Output = Self;
 
@@ -128,7 +139,7 @@ macro_rules! impl_straight {
             impl PartialEq for $target {
                 #[inline(always)]
                 fn eq (&self, rhs: &Self) -> bool {
-                    let cmp : [u128;2] = unsafe { transmute(_mm_concat!(cmp, $ty)(self.0, rhs.0, _MM_CMPINT_EQ)) };
+                    let cmp : [u128;2] = unsafe { transmute(_mm_concat!(cmp, $ty)(self.0, rhs.0, _CMP_EQ_OQ)) };
                     cmp[0] == u128::MAX && cmp[1] == u128::MAX
                 }
             }
@@ -142,6 +153,22 @@ macro_rules! impl_straight {
                     Self(_mm_concat!(loadu, $ty)(ptr))
                 }
 
+                /// Loads values from the pointer into the SIMD vector.
+                /// The pointer must be aligned to 32 bytes, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn load_aligned (ptr: *const $ty) -> Self {
+                    debug_assert_eq!(ptr as usize % 32, 0, "pointer is not aligned");
+                    Self(_mm_concat!(load, $ty)(ptr))
+                }
+
+                /// Stores the values of the vector into the pointer.
+                /// The pointer must be aligned to 32 bytes, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn store_aligned (self, ptr: *mut $ty) {
+                    debug_assert_eq!(ptr as usize % 32, 0, "pointer is not aligned");
+                    _mm_concat!(store, $ty)(ptr, self.0)
+                }
+
                 #[doc=concat!("Returns a vector with the absolute values of the original vector")]
                 #[inline(always)]
                 pub fn abs (self) -> Self {
@@ -154,29 +181,70 @@ macro_rules! impl_straight {
                     unsafe { Self(_mm_concat!(sqrt, $ty)(self.0)) }
                 }
 
+                /// Returns a vector with the integer parts of the original vector
+                #[inline(always)]
+                pub fn trunc (self) -> Self {
+                    unsafe { Self(_mm_concat!(round, $ty)(self.0, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)) }
+                }
+
+                /// Returns a vector with the floors of the original vector
+                #[inline(always)]
+                pub fn floor (self) -> Self {
+                    unsafe { Self(_mm_concat!(floor, $ty)(self.0)) }
+                }
+
+                #[cfg(not(feature = "deterministic"))]
                 impl_other_fns_straight!(
                     $ty,
                     min as vmin: "smallest/minimum value",
                     max as vmax: "biggest/maximum value"
                 );
 
+                /// Returns a vector with the step function (`0` if smaller than `rhs`, `1` otherwise) of each lane
+                #[inline(always)]
+                pub fn step (self, rhs: Self) -> Self {
+                    unsafe { Self(_mm_concat!(and, $ty)(_mm_concat!(cmp, $ty)(self.0, rhs.0, _CMP_GE_OQ), _mm_concat!(set1, $ty)(1.))) }
+                }
+
+                impl_interp_fns!($ty);
+                impl_euclid_fns!($ty);
+
+                #[inline(always)]
+                pub(crate) fn cmp_lt (self, rhs: Self) -> Self {
+                    unsafe { Self(_mm_concat!(cmp, $ty)(self.0, rhs.0, _CMP_LT_OQ)) }
+                }
+
+                #[inline(always)]
+                pub(crate) fn cmp_eq (self, rhs: Self) -> Self {
+                    unsafe { Self(_mm_concat!(cmp, $ty)(self.0, rhs.0, _CMP_EQ_OQ)) }
+                }
+
+                impl_other_fns_straight!(
+                    @mask $ty,
+                    and as bit_and,
+                    or as bit_or,
+                    andnot as bit_andnot
+                );
+                impl_mask_fns!($ty);
+
+                #[cfg(not(feature = "deterministic"))]
                 impl_hoz_fns_straight!(
                     $ty,
                     min as min: "Gets the smallest/minimum value of the vector",
                     max as max: "Gets the biggest/maximum value of the vector",
                     add as sum: "Sums up all the values inside the vector",
-                    mul as prod: "Multiplies all the values inside the vector",
+                    mul as prod: "Multiplies all the values inside the vector"
                 );
 
                 /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
-                #[cfg(target_feature = "fma")]
+                #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
                 #[inline(always)]
                 pub fn mul_add (self, rhs: Self, add: Self) -> Self {
                     unsafe { Self(_mm_concat!(fmadd, $ty)(self.0, rhs.0, add.0)) }
                 }
 
                 /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
-                #[cfg(not(target_feature = "fma"))]
+                #[cfg(all(not(target_feature = "fma"), not(feature = "deterministic")))]
                 #[inline(always)]
                 pub fn mul_add (self, rhs: Self, add: Self) -> Self {
                     (self * rhs) + add
@@ -185,7 +253,12 @@ macro_rules! impl_straight {
                 /// Interleaves elements of both vectors into one
                 #[inline(always)]
                 pub fn zip (self, rhs: Self) -> Self {
-                    unsafe { Self(__mm_concat!(unpackhi, $ty)(self.0, rhs.0)) }
+                    unsafe {
+                        // unpacking interleaves each 128-bit half on its own, so the low halves of both are joined back
+                        let lo = _mm_concat!(unpacklo, $ty)(self.0, rhs.0);
+                        let hi = _mm_concat!(unpackhi, $ty)(self.0, rhs.0);
+                        Self(_mm_concat!(permute2f128, $ty)(lo, hi, 0x20))
+                    }
                 }
             }
 
@@ -204,6 +277,94 @@ impl_straight!(
     __m256d as f64x4 => [f64;4]
 );
 
+// The sorting networks pair every lane `i` with the lane `i ^ j`
+impl f32x8 {
+    #[inline(always)]
+    pub(crate) fn xor_lanes (self, j: usize) -> Self {
+        unsafe {
+            match j {
+                1 => Self(_mm256_permute_ps(self.0, 0b10_11_00_01)),
+                2 => Self(_mm256_permute_ps(self.0, 0b01_00_11_10)),
+                4 => Self(_mm256_permute2f128_ps(self.0, self.0, 0x01)),
+                _ => unreachable!()
+            }
+        }
+    }
+}
+
+impl f64x4 {
+    #[inline(always)]
+    pub(crate) fn xor_lanes (self, j: usize) -> Self {
+        unsafe {
+            match j {
+                1 => Self(_mm256_permute_pd(self.0, 0b0101)),
+                2 => Self(_mm256_permute2f128_pd(self.0, self.0, 0x01)),
+                _ => unreachable!()
+            }
+        }
+    }
+}
+
+// The lanes moving across the 128-bit halves come from the half below each one, and the lowest one from `fill`
+impl f32x8 {
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+    #[inline(always)]
+    pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+        unsafe {
+            let below = _mm256_permute2f128_ps(self.0, fill.0, 0x03);
+            match lanes {
+                1 => Self(_mm256_shuffle_ps(_mm256_shuffle_ps(below, self.0, 0b00_00_11_11), self.0, 0b10_01_10_00)),
+                2 => Self(_mm256_shuffle_ps(below, self.0, 0b01_00_11_10)),
+                4 => Self(below),
+                _ => unreachable!()
+            }
+        }
+    }
+
+    impl_scan!(@float f32, 8);
+}
+
+impl f64x4 {
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+    #[inline(always)]
+    pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+        unsafe {
+            let below = _mm256_permute2f128_pd(self.0, fill.0, 0x03);
+            match lanes {
+                1 => Self(_mm256_shuffle_pd(below, self.0, 0b0101)),
+                2 => Self(below),
+                _ => unreachable!()
+            }
+        }
+    }
+
+    impl_scan!(@float f64, 4);
+}
+
+// The deterministic reductions split the registers in their 128-bit halves, and those in pairs of lanes
+#[cfg(feature = "deterministic")]
+impl f32x8 {
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f32) -> [f32x2; 4] {
+        unsafe {
+            let (lo, hi) = (_mm256_castps256_ps128(self.0), _mm256_extractf128_ps(self.0, 1));
+            [f32x2(lo), f32x2(_mm_movehl_ps(lo, lo)), f32x2(hi), f32x2(_mm_movehl_ps(hi, hi))]
+        }
+    }
+
+    impl_reduce_pairs!(f32);
+}
+
+#[cfg(feature = "deterministic")]
+impl f64x4 {
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f64) -> [f64x2; 2] {
+        unsafe { [f64x2(_mm256_castpd256_pd128(self.0)), f64x2(_mm256_extractf128_pd(self.0, 1))] }
+    }
+
+    impl_reduce_pairs!(f64);
+}
+
 impl_clone!(
     f32x8, f32, 8,
     f64x4, f64, 4
@@ -213,20 +374,22 @@ impl_composite!(
     (f32x8 => 8, f32x2 => 2) as f32x10: f32,
     (f32x8 => 8, f32x4 => 4) as f32x12: f32,
     (f32x8 => 8, f32x6 => 6) as f32x14: f32,
-    (f32x8 => 8, f32x8 => 6) as f32x16: f32
-);
+    (f32x8 => 8, f32x8 => 8) as f32x16: f32,
+    (f32x8 => 8, f32x8 => 8, f32x8 => 8) as f32x24: f32,
+    (f32x8 => 8, f32x8 => 8, f32x8 => 8, f32x8 => 8) as f32x32: f32,
 
-impl_composite!(
     (f64x4 => 4, f64x2 => 2) as f64x6: f64,
-    (f64x4 => 4, f64x4 => 4) as f64x8: f64
-);
-
-impl_composite!(
+    (f64x4 => 4, f64x4 => 4) as f64x8: f64,
     (f64x4 => 4, f64x4 => 4, f64x2 => 2) as f64x10: f64,
-    (f64x4 => 4, f64x4 => 4, f64x4 => 4) as f64x12: f64
+    (f64x4 => 4, f64x4 => 4, f64x4 => 4) as f64x12: f64,
+    (f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x2 => 2) as f64x14: f64,
+    (f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x4 => 4) as f64x16: f64,
+    (f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x4 => 4) as f64x24: f64,
+    (f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x4 => 4) as f64x32: f64
 );
+impl_xor_lanes!(
+    [f32x8 => 8; 2] as f32x16,
 
-impl_composite!(
-    (f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x2 => 2) as f64x14: f64,
-    (f64x4 => 4, f64x4 => 4, f64x4 => 4, f64x4 => 4) as f64x16: f64
-);
\ No newline at end of file
+    [f64x4 => 4; 2] as f64x8,
+    [f64x4 => 4; 4] as f64x16
+);
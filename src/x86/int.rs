@@ -0,0 +1,860 @@
+use core::ops::*;
+use llml_simd_proc::*;
+use crate::float::single::{f32x4, f32x8};
+use crate::float::double::{f64x2, f64x4, f64x8, f64x16};
+arch_use!();
+
+macro_rules! _mm_int {
+    ($pre:ident, $fun:ident, $suffix:ident) => {
+        concat_idents!($pre, $fun, _, $suffix)
+    };
+}
+
+// Lane-wise minimum/maximum through a comparison mask
+macro_rules! select_cmp {
+    ($pre:ident, $si:ident, $mask:expr, $yes:expr, $no:expr) => {{
+        let mask = $mask;
+        _mm_int!($pre, or, $si)(_mm_int!($pre, and, $si)(mask, $yes), _mm_int!($pre, andnot, $si)(mask, $no))
+    }};
+}
+
+// Runs an unsigned operation on signed lanes (or vice versa) by flipping their sign bits
+macro_rules! flip_sign {
+    ($pre:ident, $si:ident, $epi:ident, $min:expr, $fun:expr, $a:expr, $b:expr) => {{
+        let sign = _mm_int!($pre, set1, $epi)($min);
+        let flip = _mm_int!($pre, xor, $si);
+        flip($fun(flip($a, sign), flip($b, sign)), sign)
+    }};
+}
+
+macro_rules! impl_int {
+    (@arith $target:ident, $ty:ident, $pre:ident, $($trait:ident, $fun:ident => $op:ident $suffix:ident),+) => {
+        $(
+            impl $trait for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn $fun (self, rhs: Self) -> Self::Output {
+                    unsafe { Self(_mm_int!($pre, $op, $suffix)(self.0, rhs.0)) }
+                }
+            }
+
+            impl_scal_arith!($target, $ty, $trait, $fun);
+        )*
+    };
+
+    (@minmax $pre:ident, $epi:ident) => {
+        /// Returns a vector with the smallest/minimum value of each lane
+        #[inline(always)]
+        pub fn vmin (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, min, $epi)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the biggest/maximum value of each lane
+        #[inline(always)]
+        pub fn vmax (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, max, $epi)(self.0, rhs.0)) }
+        }
+    };
+
+    (@saturating $pre:ident, $epi:ident) => {
+        /// Returns a vector with the saturating addition of each lane
+        #[inline(always)]
+        pub fn saturating_add (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, adds, $epi)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the saturating subtraction of each lane
+        #[inline(always)]
+        pub fn saturating_sub (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, subs, $epi)(self.0, rhs.0)) }
+        }
+    };
+
+    (@fns $pre:ident, $si:ident, i8) => {
+        impl_int!(@saturating $pre, epi8);
+
+        /// Returns a vector with the smallest/minimum value of each lane
+        #[cfg(target_feature = "sse4.1")]
+        #[inline(always)]
+        pub fn vmin (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, min, epi8)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the smallest/minimum value of each lane
+        #[cfg(not(target_feature = "sse4.1"))]
+        #[inline(always)]
+        pub fn vmin (self, rhs: Self) -> Self {
+            unsafe { Self(flip_sign!($pre, $si, epi8, i8::MIN, _mm_int!($pre, min, epu8), self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the biggest/maximum value of each lane
+        #[cfg(target_feature = "sse4.1")]
+        #[inline(always)]
+        pub fn vmax (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, max, epi8)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the biggest/maximum value of each lane
+        #[cfg(not(target_feature = "sse4.1"))]
+        #[inline(always)]
+        pub fn vmax (self, rhs: Self) -> Self {
+            unsafe { Self(flip_sign!($pre, $si, epi8, i8::MIN, _mm_int!($pre, max, epu8), self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the rounded up average of each lane
+        #[inline(always)]
+        pub fn avg (self, rhs: Self) -> Self {
+            unsafe { Self(flip_sign!($pre, $si, epi8, i8::MIN, _mm_int!($pre, avg, epu8), self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the high half of the widened product of each lane
+        #[inline(always)]
+        pub fn mulhi (self, rhs: Self) -> Self {
+            unsafe {
+                let shift = _mm_cvtsi32_si128(8);
+                let widen = |x, y| _mm_int!($pre, sra, epi16)(_mm_int!($pre, unpacklo, epi8)(x, y), shift);
+                let widen_hi = |x, y| _mm_int!($pre, sra, epi16)(_mm_int!($pre, unpackhi, epi8)(x, y), shift);
+
+                let lo = _mm_int!($pre, mullo, epi16)(widen(self.0, self.0), widen(rhs.0, rhs.0));
+                let hi = _mm_int!($pre, mullo, epi16)(widen_hi(self.0, self.0), widen_hi(rhs.0, rhs.0));
+                Self(_mm_int!($pre, packs, epi16)(_mm_int!($pre, sra, epi16)(lo, shift), _mm_int!($pre, sra, epi16)(hi, shift)))
+            }
+        }
+    };
+
+    (@fns $pre:ident, $si:ident, u8) => {
+        impl_int!(@saturating $pre, epu8);
+        impl_int!(@minmax $pre, epu8);
+
+        /// Returns a vector with the rounded up average of each lane
+        #[inline(always)]
+        pub fn avg (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, avg, epu8)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the high half of the widened product of each lane
+        #[inline(always)]
+        pub fn mulhi (self, rhs: Self) -> Self {
+            unsafe {
+                let shift = _mm_cvtsi32_si128(8);
+                let zero = _mm_int!($pre, set1, epi8)(0);
+
+                let lo = _mm_int!($pre, mullo, epi16)(_mm_int!($pre, unpacklo, epi8)(self.0, zero), _mm_int!($pre, unpacklo, epi8)(rhs.0, zero));
+                let hi = _mm_int!($pre, mullo, epi16)(_mm_int!($pre, unpackhi, epi8)(self.0, zero), _mm_int!($pre, unpackhi, epi8)(rhs.0, zero));
+                Self(_mm_int!($pre, packus, epi16)(_mm_int!($pre, srl, epi16)(lo, shift), _mm_int!($pre, srl, epi16)(hi, shift)))
+            }
+        }
+    };
+
+    (@fns $pre:ident, $si:ident, i16) => {
+        impl_int!(@saturating $pre, epi16);
+        impl_int!(@minmax $pre, epi16);
+
+        /// Returns a vector with the rounded up average of each lane
+        #[inline(always)]
+        pub fn avg (self, rhs: Self) -> Self {
+            unsafe { Self(flip_sign!($pre, $si, epi16, i16::MIN, _mm_int!($pre, avg, epu16), self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the high half of the widened product of each lane
+        #[inline(always)]
+        pub fn mulhi (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, mulhi, epi16)(self.0, rhs.0)) }
+        }
+    };
+
+    (@fns $pre:ident, $si:ident, u16) => {
+        impl_int!(@saturating $pre, epu16);
+
+        /// Returns a vector with the smallest/minimum value of each lane
+        #[cfg(target_feature = "sse4.1")]
+        #[inline(always)]
+        pub fn vmin (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, min, epu16)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the smallest/minimum value of each lane
+        #[cfg(not(target_feature = "sse4.1"))]
+        #[inline(always)]
+        pub fn vmin (self, rhs: Self) -> Self {
+            unsafe { Self(flip_sign!($pre, $si, epi16, i16::MIN, _mm_int!($pre, min, epi16), self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the biggest/maximum value of each lane
+        #[cfg(target_feature = "sse4.1")]
+        #[inline(always)]
+        pub fn vmax (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, max, epu16)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the biggest/maximum value of each lane
+        #[cfg(not(target_feature = "sse4.1"))]
+        #[inline(always)]
+        pub fn vmax (self, rhs: Self) -> Self {
+            unsafe { Self(flip_sign!($pre, $si, epi16, i16::MIN, _mm_int!($pre, max, epi16), self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the rounded up average of each lane
+        #[inline(always)]
+        pub fn avg (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, avg, epu16)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the high half of the widened product of each lane
+        #[inline(always)]
+        pub fn mulhi (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, mulhi, epu16)(self.0, rhs.0)) }
+        }
+    };
+
+    (@fns $pre:ident, $si:ident, i32) => {
+        /// Returns a vector with the smallest/minimum value of each lane
+        #[cfg(target_feature = "sse4.1")]
+        #[inline(always)]
+        pub fn vmin (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, min, epi32)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the smallest/minimum value of each lane
+        #[cfg(not(target_feature = "sse4.1"))]
+        #[inline(always)]
+        pub fn vmin (self, rhs: Self) -> Self {
+            unsafe { Self(select_cmp!($pre, $si, _mm_int!($pre, cmpgt, epi32)(self.0, rhs.0), rhs.0, self.0)) }
+        }
+
+        /// Returns a vector with the biggest/maximum value of each lane
+        #[cfg(target_feature = "sse4.1")]
+        #[inline(always)]
+        pub fn vmax (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, max, epi32)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the biggest/maximum value of each lane
+        #[cfg(not(target_feature = "sse4.1"))]
+        #[inline(always)]
+        pub fn vmax (self, rhs: Self) -> Self {
+            unsafe { Self(select_cmp!($pre, $si, _mm_int!($pre, cmpgt, epi32)(self.0, rhs.0), self.0, rhs.0)) }
+        }
+    };
+
+    (@fns $pre:ident, $si:ident, u32) => {
+        /// Returns a vector with the smallest/minimum value of each lane
+        #[cfg(target_feature = "sse4.1")]
+        #[inline(always)]
+        pub fn vmin (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, min, epu32)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the smallest/minimum value of each lane
+        #[cfg(not(target_feature = "sse4.1"))]
+        #[inline(always)]
+        pub fn vmin (self, rhs: Self) -> Self {
+            unsafe {
+                let sign = _mm_int!($pre, set1, epi32)(i32::MIN);
+                let cmp = _mm_int!($pre, cmpgt, epi32)(_mm_int!($pre, xor, $si)(self.0, sign), _mm_int!($pre, xor, $si)(rhs.0, sign));
+                Self(select_cmp!($pre, $si, cmp, rhs.0, self.0))
+            }
+        }
+
+        /// Returns a vector with the biggest/maximum value of each lane
+        #[cfg(target_feature = "sse4.1")]
+        #[inline(always)]
+        pub fn vmax (self, rhs: Self) -> Self {
+            unsafe { Self(_mm_int!($pre, max, epu32)(self.0, rhs.0)) }
+        }
+
+        /// Returns a vector with the biggest/maximum value of each lane
+        #[cfg(not(target_feature = "sse4.1"))]
+        #[inline(always)]
+        pub fn vmax (self, rhs: Self) -> Self {
+            unsafe {
+                let sign = _mm_int!($pre, set1, epi32)(i32::MIN);
+                let cmp = _mm_int!($pre, cmpgt, epi32)(_mm_int!($pre, xor, $si)(self.0, sign), _mm_int!($pre, xor, $si)(rhs.0, sign));
+                Self(select_cmp!($pre, $si, cmp, self.0, rhs.0))
+            }
+        }
+    };
+
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+    (@shift_up $pre:ident, $ty:ident) => {
+        #[inline(always)]
+        pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+            unsafe {
+                Self(match lanes * core::mem::size_of::<$ty>() {
+                    1 => shift_bytes!($pre, self.0, fill.0, 1),
+                    2 => shift_bytes!($pre, self.0, fill.0, 2),
+                    4 => shift_bytes!($pre, self.0, fill.0, 4),
+                    8 => shift_bytes!($pre, self.0, fill.0, 8),
+                    16 => shift_bytes!($pre, self.0, fill.0, 16),
+                    _ => unreachable!()
+                })
+            }
+        }
+    };
+
+    ($og:ident, $pre:ident, $si:ident => $([$ty:ident;$len:literal] as $target:ident: $epi:ident as $sty:ident),+) => {
+        $(
+            #[allow(non_camel_case_types)]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Assign)]
+            #[assign_targets(Add, Sub, BitAnd, BitOr, BitXor)]
+            #[assign_rhs(Self, $ty)]
+            pub struct $target(pub(crate) $og);
+            impl_int!(
+                @arith $target, $ty, $pre,
+                Add, add => add $epi,
+                Sub, sub => sub $epi,
+                BitAnd, bitand => and $si,
+                BitOr, bitor => or $si,
+                BitXor, bitxor => xor $si
+            );
+
+            impl Not for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn not (self) -> Self::Output {
+                    unsafe { Self(_mm_int!($pre, xor, $si)(self.0, _mm_int!($pre, set1, $epi)(-1))) }
+                }
+            }
+
+            impl $target {
+                /// Loads values from the pointer into the SIMD vector
+                #[inline(always)]
+                pub unsafe fn load (ptr: *const $ty) -> Self {
+                    Self(_mm_int!($pre, loadu, $si)(ptr as *const $og))
+                }
+
+                impl_int!(@fns $pre, $si, $ty);
+                impl_int!(@shift_up $pre, $ty);
+                impl_scan!(@int $ty, $len);
+            }
+
+            impl From<$ty> for $target {
+                #[inline(always)]
+                fn from (x: $ty) -> Self {
+                    unsafe { Self(_mm_int!($pre, set1, $epi)(x as $sty)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(
+    __m128i, _mm_, si128 =>
+    [i8;16] as i8x16: epi8 as i8,
+    [u8;16] as u8x16: epi8 as i8,
+    [i16;8] as i16x8: epi16 as i16,
+    [u16;8] as u16x8: epi16 as i16,
+    [i32;4] as i32x4: epi32 as i32,
+    [u32;4] as u32x4: epi32 as i32
+);
+
+impl i8x16 {
+    /// Widens the lanes into two vectors, with the lower and upper halves of the original vector respectively
+    #[inline(always)]
+    pub fn widen (self) -> (i16x8, i16x8) {
+        unsafe {
+            let lo = _mm_srai_epi16::<8>(_mm_unpacklo_epi8(self.0, self.0));
+            let hi = _mm_srai_epi16::<8>(_mm_unpackhi_epi8(self.0, self.0));
+            (i16x8(lo), i16x8(hi))
+        }
+    }
+}
+
+impl u8x16 {
+    /// Widens the lanes into two vectors, with the lower and upper halves of the original vector respectively
+    #[inline(always)]
+    pub fn widen (self) -> (u16x8, u16x8) {
+        unsafe {
+            let zero = _mm_setzero_si128();
+            (u16x8(_mm_unpacklo_epi8(self.0, zero)), u16x8(_mm_unpackhi_epi8(self.0, zero)))
+        }
+    }
+}
+
+impl i16x8 {
+    /// Widens the lanes into two vectors, with the lower and upper halves of the original vector respectively
+    #[inline(always)]
+    pub fn widen (self) -> (i32x4, i32x4) {
+        unsafe {
+            let lo = _mm_srai_epi32::<16>(_mm_unpacklo_epi16(self.0, self.0));
+            let hi = _mm_srai_epi32::<16>(_mm_unpackhi_epi16(self.0, self.0));
+            (i32x4(lo), i32x4(hi))
+        }
+    }
+
+    /// Narrows the lanes of both vectors into one, saturating the values that don't fit
+    #[inline(always)]
+    pub fn narrow (self, rhs: Self) -> i8x16 {
+        unsafe { i8x16(_mm_packs_epi16(self.0, rhs.0)) }
+    }
+}
+
+impl u16x8 {
+    /// Widens the lanes into two vectors, with the lower and upper halves of the original vector respectively
+    #[inline(always)]
+    pub fn widen (self) -> (u32x4, u32x4) {
+        unsafe {
+            let zero = _mm_setzero_si128();
+            (u32x4(_mm_unpacklo_epi16(self.0, zero)), u32x4(_mm_unpackhi_epi16(self.0, zero)))
+        }
+    }
+
+    /// Narrows the lanes of both vectors into one, saturating the values that don't fit
+    #[inline(always)]
+    pub fn narrow (self, rhs: Self) -> u8x16 {
+        // packing saturates signed lanes, so bigger values are clamped first
+        let max = Self::from(u8::MAX as u16);
+        unsafe { u8x16(_mm_packus_epi16(self.vmin(max).0, rhs.vmin(max).0)) }
+    }
+}
+
+impl i32x4 {
+    /// Narrows the lanes of both vectors into one, saturating the values that don't fit
+    #[inline(always)]
+    pub fn narrow (self, rhs: Self) -> i16x8 {
+        unsafe { i16x8(_mm_packs_epi32(self.0, rhs.0)) }
+    }
+
+    /// Converts every lane into a single-precision float
+    #[inline(always)]
+    pub fn to_f32 (self) -> f32x4 {
+        unsafe { f32x4(_mm_cvtepi32_ps(self.0)) }
+    }
+}
+
+impl u32x4 {
+    /// Narrows the lanes of both vectors into one, saturating the values that don't fit
+    #[cfg(target_feature = "sse4.1")]
+    #[inline(always)]
+    pub fn narrow (self, rhs: Self) -> u16x8 {
+        let max = Self::from(u16::MAX as u32);
+        unsafe { u16x8(_mm_packus_epi32(self.vmin(max).0, rhs.vmin(max).0)) }
+    }
+
+    /// Narrows the lanes of both vectors into one, saturating the values that don't fit
+    #[cfg(not(target_feature = "sse4.1"))]
+    #[inline(always)]
+    pub fn narrow (self, rhs: Self) -> u16x8 {
+        // lanes are clamped and offset into the signed range, which packs without saturating
+        let max = Self::from(u16::MAX as u32);
+        let offset = Self::from(0x8000);
+        unsafe {
+            let lo = (self.vmin(max) - offset).0;
+            let hi = (rhs.vmin(max) - offset).0;
+            u16x8(_mm_xor_si128(_mm_packs_epi32(lo, hi), _mm_set1_epi16(i16::MIN)))
+        }
+    }
+
+    /// Converts every lane into a single-precision float
+    #[inline(always)]
+    pub fn to_f32 (self) -> f32x4 {
+        // both halves are exact as signed integers, so the result is only rounded once
+        unsafe {
+            let hi = _mm_cvtepi32_ps(_mm_srli_epi32::<16>(self.0));
+            let lo = _mm_cvtepi32_ps(_mm_and_si128(self.0, _mm_set1_epi32(0xffff)));
+            f32x4(_mm_add_ps(_mm_mul_ps(hi, _mm_set1_ps(65536.)), lo))
+        }
+    }
+}
+
+// Replicates the sign bit of each 64-bit lane over the whole lane
+macro_rules! sign_mask64 {
+    ($pre:ident, $si:ident, $x:expr) => {
+        _mm_int!($pre, sub, epi64)(_mm_int!($pre, setzero, $si)(), _mm_int!($pre, srl, epi64)($x, _mm_cvtsi32_si128(63)))
+    };
+}
+
+// Shifts each 64-bit lane by its own amount, through one shift per lane
+macro_rules! shift_lanes64 {
+    ($pre:ident, $op:ident, $x:expr, $count:expr) => {{
+        let (x, count) = ($x, $count);
+        let lo = _mm_int!($pre, $op, epi64)(x, count);
+        let hi = _mm_int!($pre, $op, epi64)(x, _mm_int!($pre, unpackhi, epi64)(count, count));
+        _mm_int!($pre, unpackhi, epi64)(_mm_int!($pre, unpacklo, epi64)(lo, lo), hi)
+    }};
+}
+
+macro_rules! impl_int64 {
+    // flipping the bits of negative lanes turns the logical shift into an arithmetic one
+    (@shr signed $pre:ident, $si:ident, $x:expr, $shift:expr) => {{
+        let x = $x;
+        let sign = sign_mask64!($pre, $si, x);
+        _mm_int!($pre, xor, $si)($shift(_mm_int!($pre, xor, $si)(x, sign)), sign)
+    }};
+
+    (@shr unsigned $pre:ident, $si:ident, $x:expr, $shift:expr) => {
+        $shift($x)
+    };
+
+    // unsigned lanes are compared as signed ones after flipping their sign bits
+    (@flip signed $pre:ident, $si:ident, $x:expr) => {
+        $x
+    };
+
+    (@flip unsigned $pre:ident, $si:ident, $x:expr) => {
+        _mm_int!($pre, xor, $si)($x, _mm_int!($pre, set1, epi64x)(i64::MIN))
+    };
+
+    (@cmp $($fun:ident => $op:ident, swap: $swap:literal, not: $not:literal, $docs:expr),+) => {
+        $(
+            #[doc=concat!("Returns a mask with every bit set on the lanes where `self` is ", $docs, " `rhs`, and cleared otherwise")]
+            #[inline(always)]
+            pub fn $fun (self, rhs: Self) -> Self {
+                let (a, b) = if $swap { (rhs.0, self.0) } else { (self.0, rhs.0) };
+                let mask = unsafe { Self(Self::$op(a, b)) };
+                if $not { !mask } else { mask }
+            }
+        )*
+    };
+
+    ($og:ident, $pre:ident, $si:ident => $([$ty:ident;$len:literal] as $target:ident => $float:ident: $kind:ident),+) => {
+        $(
+            #[allow(non_camel_case_types)]
+            #[repr(transparent)]
+            #[derive(Clone, Copy, Assign)]
+            #[assign_targets(Add, Sub, BitAnd, BitOr, BitXor)]
+            #[assign_rhs(Self, $ty)]
+            pub struct $target(pub(crate) $og);
+            impl_int!(
+                @arith $target, $ty, $pre,
+                Add, add => add epi64,
+                Sub, sub => sub epi64,
+                BitAnd, bitand => and $si,
+                BitOr, bitor => or $si,
+                BitXor, bitxor => xor $si
+            );
+
+            impl Not for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn not (self) -> Self::Output {
+                    unsafe { Self(_mm_int!($pre, xor, $si)(self.0, _mm_int!($pre, set1, epi64x)(-1))) }
+                }
+            }
+
+            impl Mul for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn mul (self, rhs: Self) -> Self::Output {
+                    // there's no 64-bit multiplication, so lanes are multiplied from their 32-bit halves
+                    unsafe {
+                        let count = _mm_cvtsi32_si128(32);
+                        let cross = _mm_int!($pre, add, epi64)(
+                            _mm_int!($pre, mul, epu32)(_mm_int!($pre, srl, epi64)(self.0, count), rhs.0),
+                            _mm_int!($pre, mul, epu32)(self.0, _mm_int!($pre, srl, epi64)(rhs.0, count))
+                        );
+
+                        Self(_mm_int!($pre, add, epi64)(_mm_int!($pre, mul, epu32)(self.0, rhs.0), _mm_int!($pre, sll, epi64)(cross, count)))
+                    }
+                }
+            }
+
+            impl_scal_arith!($target, $ty, Mul, mul);
+
+            impl Shl<u32> for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shl (self, rhs: u32) -> Self::Output {
+                    unsafe { Self(_mm_int!($pre, sll, epi64)(self.0, _mm_cvtsi32_si128((rhs & 63) as i32))) }
+                }
+            }
+
+            impl Shr<u32> for $target {
+                type Output = Self;
+
+                #[inline(always)]
+                fn shr (self, rhs: u32) -> Self::Output {
+                    unsafe {
+                        let count = _mm_cvtsi32_si128((rhs & 63) as i32);
+                        Self(impl_int64!(@shr $kind $pre, $si, self.0, |x| _mm_int!($pre, srl, epi64)(x, count)))
+                    }
+                }
+            }
+
+            impl Shl for $target {
+                type Output = Self;
+
+                #[cfg(target_feature = "avx2")]
+                #[inline(always)]
+                fn shl (self, rhs: Self) -> Self::Output {
+                    unsafe { Self(_mm_int!($pre, sllv, epi64)(self.0, (rhs & 63).0)) }
+                }
+
+                #[cfg(not(target_feature = "avx2"))]
+                #[inline(always)]
+                fn shl (self, rhs: Self) -> Self::Output {
+                    unsafe { Self(shift_lanes64!($pre, sll, self.0, (rhs & 63).0)) }
+                }
+            }
+
+            impl Shr for $target {
+                type Output = Self;
+
+                #[cfg(target_feature = "avx2")]
+                #[inline(always)]
+                fn shr (self, rhs: Self) -> Self::Output {
+                    unsafe { Self(impl_int64!(@shr $kind $pre, $si, self.0, |x| _mm_int!($pre, srlv, epi64)(x, (rhs & 63).0))) }
+                }
+
+                #[cfg(not(target_feature = "avx2"))]
+                #[inline(always)]
+                fn shr (self, rhs: Self) -> Self::Output {
+                    unsafe { Self(impl_int64!(@shr $kind $pre, $si, self.0, |x| shift_lanes64!($pre, srl, x, (rhs & 63).0))) }
+                }
+            }
+
+            impl $target {
+                /// Loads values from the pointer into the SIMD vector
+                #[inline(always)]
+                pub unsafe fn load (ptr: *const $ty) -> Self {
+                    Self(_mm_int!($pre, loadu, $si)(ptr as *const $og))
+                }
+
+                impl_int!(@shift_up $pre, $ty);
+                impl_scan!(@int $ty, $len);
+                impl_scan!(@prod);
+
+                #[cfg(target_feature = "sse4.1")]
+                #[inline(always)]
+                unsafe fn eq_mask (a: $og, b: $og) -> $og {
+                    _mm_int!($pre, cmpeq, epi64)(a, b)
+                }
+
+                #[cfg(not(target_feature = "sse4.1"))]
+                #[inline(always)]
+                unsafe fn eq_mask (a: $og, b: $og) -> $og {
+                    // both 32-bit halves of the lane have to be equal
+                    let count = _mm_cvtsi32_si128(32);
+                    let eq = _mm_int!($pre, cmpeq, epi32)(a, b);
+                    let swap = _mm_int!($pre, or, $si)(_mm_int!($pre, sll, epi64)(eq, count), _mm_int!($pre, srl, epi64)(eq, count));
+                    _mm_int!($pre, and, $si)(eq, swap)
+                }
+
+                #[cfg(target_feature = "sse4.2")]
+                #[inline(always)]
+                unsafe fn gt_mask (a: $og, b: $og) -> $og {
+                    let (a, b) = (impl_int64!(@flip $kind $pre, $si, a), impl_int64!(@flip $kind $pre, $si, b));
+                    _mm_int!($pre, cmpgt, epi64)(a, b)
+                }
+
+                #[cfg(not(target_feature = "sse4.2"))]
+                #[inline(always)]
+                unsafe fn gt_mask (a: $og, b: $og) -> $og {
+                    // the sign of `b - a`, corrected for overflow (Hacker's Delight, 2-12)
+                    let (a, b) = (impl_int64!(@flip $kind $pre, $si, a), impl_int64!(@flip $kind $pre, $si, b));
+                    let diff = _mm_int!($pre, andnot, $si)(_mm_int!($pre, xor, $si)(a, b), _mm_int!($pre, sub, epi64)(b, a));
+                    sign_mask64!($pre, $si, _mm_int!($pre, or, $si)(_mm_int!($pre, andnot, $si)(a, b), diff))
+                }
+
+                impl_int64!(
+                    @cmp
+                    veq => eq_mask, swap: false, not: false, "equal to",
+                    vne => eq_mask, swap: false, not: true, "not equal to",
+                    vlt => gt_mask, swap: true, not: false, "less than",
+                    vle => gt_mask, swap: false, not: true, "less than or equal to",
+                    vgt => gt_mask, swap: false, not: false, "greater than",
+                    vge => gt_mask, swap: true, not: true, "greater than or equal to"
+                );
+
+                /// Returns a vector with the smallest/minimum value of each lane
+                #[inline(always)]
+                pub fn vmin (self, rhs: Self) -> Self {
+                    unsafe { Self(select_cmp!($pre, $si, Self::gt_mask(self.0, rhs.0), rhs.0, self.0)) }
+                }
+
+                /// Returns a vector with the biggest/maximum value of each lane
+                #[inline(always)]
+                pub fn vmax (self, rhs: Self) -> Self {
+                    unsafe { Self(select_cmp!($pre, $si, Self::gt_mask(self.0, rhs.0), self.0, rhs.0)) }
+                }
+
+                /// Converts every lane into a double-precision float
+                #[inline(always)]
+                pub fn to_f64 (self) -> $float {
+                    // there's no 64-bit integer conversion, so it's done lane by lane
+                    $float::from(self.into_array().map(|x| x as f64))
+                }
+
+                /// Converts every lane of a double-precision float vector into an integer, truncating towards zero and saturating the values that don't fit (`NaN` becomes `0`)
+                #[inline(always)]
+                pub fn from_f64 (x: $float) -> Self {
+                    Self::from(x.into_array().map(|x| x as $ty))
+                }
+            }
+
+            impl From<$ty> for $target {
+                #[inline(always)]
+                fn from (x: $ty) -> Self {
+                    unsafe { Self(_mm_int!($pre, set1, epi64x)(x as i64)) }
+                }
+            }
+        )*
+    };
+}
+
+impl_int64!(
+    __m128i, _mm_, si128 =>
+    [i64;2] as i64x2 => f64x2: signed,
+    [u64;2] as u64x2 => f64x2: unsigned
+);
+
+cfg_if::cfg_if! {
+    if #[cfg(all(feature = "use_avx", target_feature = "avx2"))] {
+        impl_int!(
+            __m256i, _mm256_, si256 =>
+            [i8;32] as i8x32: epi8 as i8,
+            [u8;32] as u8x32: epi8 as i8,
+            [i16;16] as i16x16: epi16 as i16,
+            [u16;16] as u16x16: epi16 as i16,
+            [i32;8] as i32x8: epi32 as i32,
+            [u32;8] as u32x8: epi32 as i32
+        );
+
+        impl_int64!(
+            __m256i, _mm256_, si256 =>
+            [i64;4] as i64x4 => f64x4: signed,
+            [u64;4] as u64x4 => f64x4: unsigned
+        );
+
+        impl_int_composite!(
+            i64x4 as i64x8: i64,
+            u64x4 as u64x8: u64,
+            i64x8 as i64x16: i64,
+            u64x8 as u64x16: u64
+        );
+
+        impl_int_composite!(
+            @wide
+            i64x8: i64 => f64x8(i64x4, f64x4),
+            u64x8: u64 => f64x8(u64x4, f64x4),
+            i64x16: i64 => f64x16(i64x8, f64x8),
+            u64x16: u64 => f64x16(u64x8, f64x8)
+        );
+
+        // Lane-crossing conversions are done on each 128-bit half
+        macro_rules! impl_int_halves {
+            (@widen $($target:ident as $half:ident => $wide:ident),+) => {
+                $(
+                    impl $target {
+                        /// Widens the lanes into two vectors, with the lower and upper halves of the original vector respectively
+                        #[inline(always)]
+                        pub fn widen (self) -> ($wide, $wide) {
+                            unsafe {
+                                let (a, b) = $half(_mm256_castsi256_si128(self.0)).widen();
+                                let (c, d) = $half(_mm256_extracti128_si256::<1>(self.0)).widen();
+                                ($wide(_mm256_set_m128i(b.0, a.0)), $wide(_mm256_set_m128i(d.0, c.0)))
+                            }
+                        }
+                    }
+                )*
+            };
+
+            (@narrow $($target:ident as $half:ident => $narrow:ident),+) => {
+                $(
+                    impl $target {
+                        /// Narrows the lanes of both vectors into one, saturating the values that don't fit
+                        #[inline(always)]
+                        pub fn narrow (self, rhs: Self) -> $narrow {
+                            unsafe {
+                                let lo = $half(_mm256_castsi256_si128(self.0)).narrow($half(_mm256_extracti128_si256::<1>(self.0)));
+                                let hi = $half(_mm256_castsi256_si128(rhs.0)).narrow($half(_mm256_extracti128_si256::<1>(rhs.0)));
+                                $narrow(_mm256_set_m128i(hi.0, lo.0))
+                            }
+                        }
+                    }
+                )*
+            };
+        }
+
+        impl_int_halves!(
+            @widen
+            i8x32 as i8x16 => i16x16,
+            u8x32 as u8x16 => u16x16,
+            i16x16 as i16x8 => i32x8,
+            u16x16 as u16x8 => u32x8
+        );
+
+        impl_int_halves!(
+            @narrow
+            i16x16 as i16x8 => i8x32,
+            u16x16 as u16x8 => u8x32,
+            i32x8 as i32x4 => i16x16,
+            u32x8 as u32x4 => u16x16
+        );
+
+        impl i32x8 {
+            /// Converts every lane into a single-precision float
+            #[inline(always)]
+            pub fn to_f32 (self) -> f32x8 {
+                unsafe { f32x8(_mm256_cvtepi32_ps(self.0)) }
+            }
+        }
+
+        impl u32x8 {
+            /// Converts every lane into a single-precision float
+            #[inline(always)]
+            pub fn to_f32 (self) -> f32x8 {
+                // both halves are exact as signed integers, so the result is only rounded once
+                unsafe {
+                    let hi = _mm256_cvtepi32_ps(_mm256_srli_epi32::<16>(self.0));
+                    let lo = _mm256_cvtepi32_ps(_mm256_and_si256(self.0, _mm256_set1_epi32(0xffff)));
+                    f32x8(_mm256_add_ps(_mm256_mul_ps(hi, _mm256_set1_ps(65536.)), lo))
+                }
+            }
+        }
+    } else {
+        impl_int_composite!(
+            i8x16 as i8x32: i8,
+            u8x16 as u8x32: u8,
+            i16x8 as i16x16: i16,
+            u16x8 as u16x16: u16,
+            i32x4 as i32x8: i32,
+            u32x4 as u32x8: u32,
+
+            i64x2 as i64x4: i64,
+            u64x2 as u64x4: u64,
+            i64x4 as i64x8: i64,
+            u64x4 as u64x8: u64,
+            i64x8 as i64x16: i64,
+            u64x8 as u64x16: u64
+        );
+
+        impl_int_composite!(
+            @wide
+            i64x4: i64 => f64x4(i64x2, f64x2),
+            u64x4: u64 => f64x4(u64x2, f64x2),
+            i64x8: i64 => f64x8(i64x4, f64x4),
+            u64x8: u64 => f64x8(u64x4, f64x4),
+            i64x16: i64 => f64x16(i64x8, f64x8),
+            u64x16: u64 => f64x16(u64x8, f64x8)
+        );
+
+        impl_int_composite!(
+            @small
+            i8x32 => i16x16,
+            u8x32 => u16x16,
+            i16x16 => i32x8,
+            u16x16 => u32x8
+        );
+
+        impl_int_composite!(
+            @narrow
+            i16x16 => i8x32,
+            u16x16 => u8x32,
+            i32x8 => i16x16,
+            u32x8 => u16x16
+        );
+
+        impl_int_composite!(
+            @float
+            i32x8 => to_f32 -> f32x8: f32, "single-precision",
+            u32x8 => to_f32 -> f32x8: f32, "single-precision"
+        );
+    }
+}
@@ -10,12 +10,26 @@ macro_rules! arch_use {
     };
 }
 
+// Moves the bytes of an integer register `$bytes` positions up, filling the ones left at the bottom from the (splatted) `$fill`.
+// The 256-bit registers align each 128-bit half with the one below it, the lowest one with `$fill`
+macro_rules! shift_bytes {
+    (_mm_, $v:expr, $fill:expr, $bytes:literal) => {
+        _mm_or_si128(_mm_slli_si128::<$bytes>($v), _mm_srli_si128::<{ 16 - $bytes }>($fill))
+    };
+
+    (_mm256_, $v:expr, $fill:expr, $bytes:literal) => {
+        _mm256_alignr_epi8::<{ 16 - $bytes }>($v, _mm256_permute2x128_si256::<0x03>($v, $fill))
+    };
+}
+
 use cfg_if::cfg_if;
-mod sse;
-mod special;
+pub(crate) mod sse;
+pub(crate) mod special;
+mod int;
 
-pub use self::sse::{f32x4, f64x2};
+pub use self::sse::{f32x3, f32x4, f64x2};
 pub use self::special::{f32x2};
+pub use self::int::*;
 
 cfg_if! {
     if #[cfg(all(feature = "use_avx", target_feature = "avx"))] {
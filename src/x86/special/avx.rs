@@ -2,19 +2,22 @@ use core::ops::*;
 use core::mem::transmute;
 use core::ptr::addr_of;
 use llml_simd_proc::*;
-use crate::float::{single::f32x4, double::f64x4};
+use crate::float::{single::{f32x4, f32x8}, double::f64x4};
+#[cfg(feature = "deterministic")]
+use crate::float::{single::f32x2, double::f64x2};
 arch_use!();
 
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 #[derive(Copy, Assign)]
-#[assign_targets(Add, Sub, Mul, Div)]
+#[assign_targets(Add, Sub, Mul, Div, Rem)]
 #[assign_rhs(Self, f32)]
 pub struct f32x6 (pub(crate) __m256);
 
 impl f32x6 {
     const DIV_MASK : __m256 = unsafe { transmute([u32::MAX, u32::MAX, u32::MAX, u32::MAX, u32::MAX, u32::MAX, 0, 0]) };
     const ABS_MASK : __m256 = unsafe { transmute([i32::MAX, i32::MAX, i32::MAX, i32::MAX, i32::MAX, i32::MAX, 0, 0]) };
+    const STEP_MASK : __m256 = unsafe { transmute([1f32, 1f32, 1f32, 1f32, 1f32, 1f32, 0f32, 0f32]) };
     
     const MIN_MASK : __m128 = unsafe { transmute([0., 0., f32::MAX, f32::MAX]) };
     const MAX_MASK : __m128 = unsafe { transmute([0., 0., f32::MIN, f32::MIN]) };
@@ -25,6 +28,8 @@ impl f32x6 {
         Self(_mm256_set_ps(0., 0., *ptr.add(5), *ptr.add(4), *ptr.add(3), *ptr.add(2), *ptr.add(1), *ptr))
     }
 
+    impl_aligned_fallback!(f32, 6);
+
     /// Returns a vector with the absolute values of the original vector
     #[inline(always)]
     pub fn abs (self) -> Self {
@@ -37,7 +42,20 @@ impl f32x6 {
         unsafe { Self(_mm256_and_ps(Self::DIV_MASK, _mm256_sqrt_ps(self.0))) }
     }
 
+    /// Returns a vector with the integer parts of the original vector
+    #[inline(always)]
+    pub fn trunc (self) -> Self {
+        unsafe { Self(_mm256_round_ps(self.0, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)) }
+    }
+
+    /// Returns a vector with the floors of the original vector
+    #[inline(always)]
+    pub fn floor (self) -> Self {
+        unsafe { Self(_mm256_floor_ps(self.0)) }
+    }
+
     /// Gets the smallest/minimum value of the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn min (self) -> f32 {
         unsafe {
@@ -49,6 +67,7 @@ impl f32x6 {
     }
 
     /// Gets the biggest/maximum value of the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn max (self) -> f32 {
         unsafe {
@@ -60,6 +79,7 @@ impl f32x6 {
     }
 
     /// Sums up all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn sum (self) -> f32 {
         unsafe {
@@ -70,31 +90,97 @@ impl f32x6 {
         }
     }
 
+    /// Multiplies all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
+    #[inline(always)]
+    pub fn prod (self) -> f32 {
+        unsafe { f32x8(_mm256_blend_ps(self.0, _mm256_set1_ps(1.), 0b11000000)).prod() }
+    }
+
     /// Returns a vector with the smallest/minimum value of each lane
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn vmin (self, rhs: Self) -> Self {
         unsafe { Self(_mm256_min_ps(self.0, rhs.0)) }
     }
 
     /// Returns a vector with the absolute values of the original vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn vmax (self, rhs: Self) -> Self {
         unsafe { Self(_mm256_max_ps(self.0, rhs.0)) }
     }
 
+    /// Returns a vector with the step function (`0` if smaller than `rhs`, `1` otherwise) of each lane
+    #[inline(always)]
+    pub fn step (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_and_ps(Self::STEP_MASK, _mm256_cmp_ps(self.0, rhs.0, _CMP_GE_OQ))) }
+    }
+
+    impl_interp_fns!(f32);
+    impl_euclid_fns!(f32);
+
+    #[inline(always)]
+    pub(crate) fn cmp_lt (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_cmp_ps(self.0, rhs.0, _CMP_LT_OQ)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmp_eq (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_cmp_ps(self.0, rhs.0, _CMP_EQ_OQ)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn bit_and (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_and_ps(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn bit_or (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_or_ps(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn bit_andnot (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_andnot_ps(self.0, rhs.0)) }
+    }
+
+    impl_mask_fns!(f32);
+
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill` and clearing the padding lanes
+    #[inline(always)]
+    pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+        unsafe { Self(_mm256_and_ps(Self::DIV_MASK, f32x8(self.0).shift_up(lanes, f32x8(fill.0)).0)) }
+    }
+
+    impl_scan!(@float f32, 6);
+
+    // The padding lanes are left out, as the length is even
+    #[cfg(feature = "deterministic")]
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f32) -> [f32x2; 3] {
+        unsafe {
+            let lo = _mm256_castps256_ps128(self.0);
+            [f32x2(lo), f32x2(_mm_movehl_ps(lo, lo)), f32x2(_mm256_extractf128_ps(self.0, 1))]
+        }
+    }
+
+    impl_reduce_pairs!(f32);
+
     /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
     /// # Compatibility
     /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
     /// For the rest of targets, a regular multiplication and addition are performed
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn mul_add (self, rhs: Self, add: Self) -> Self {
-        Self(f32x8(self.0).fma(f32x8(rhs.0), f32x8(add.0)).0)
+        Self(f32x8(self.0).mul_add(f32x8(rhs.0), f32x8(add.0)).0)
     }
 
     /// Interleaves elements of both vectors into one
     #[inline(always)]
     pub fn zip (self, rhs: Self) -> Self {
-        unsafe { _mm256_and_ps(__m256_unpacklo_ps(self.0, rhs.0), DIV_MASK) }
+        unsafe { Self(_mm256_and_ps(Self::DIV_MASK, f32x8(self.0).zip(f32x8(rhs.0)).0)) }
     }
 }
 
@@ -150,7 +236,7 @@ impl PartialEq for f32x6 {
     #[inline(always)]
     fn eq (&self, other: &Self) -> bool {
         unsafe {
-            let cmp = _mm256_cmp_ps(self.0, other.0, _MM_CMPINT_EQ);
+            let cmp = _mm256_cmp_ps(self.0, other.0, _CMP_EQ_OQ);
             let cmp : (u128, u64) = *addr_of!(cmp).cast();
             cmp.0 == u128::MAX && cmp.1 == u64::MAX
         }
@@ -165,6 +251,7 @@ impl From<f32> for f32x6 {
 }
 
 impl_clone!(f32x6, f32, 6);
+impl_rem!(f32x6, f32);
 impl_scal_arith!(
     f32x6, f32,
     Add, add,
@@ -177,16 +264,14 @@ impl_scal_arith!(
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 #[derive(Copy, Assign)]
-#[assign_targets(Add, Sub, Mul, Div)]
+#[assign_targets(Add, Sub, Mul, Div, Rem)]
 #[assign_rhs(Self, f64)]
 pub struct f64x3 (pub(crate) __m256d);
 
 impl f64x3 {
     const DIV_MASK : __m256d = unsafe { transmute([u64::MAX, u64::MAX, u64::MAX, 0]) };
     const ABS_MASK : __m256d = unsafe { transmute([i64::MAX, i64::MAX, i64::MAX, 0]) };
-
-    const MIN_MASK : __m256d = unsafe { transmute([0., 0., 0., f64::MAX]) };
-    const MAX_MASK : __m256d = unsafe { transmute([0., 0., 0., f64::MIN]) };
+    const STEP_MASK : __m256d = unsafe { transmute([1f64, 1f64, 1f64, 0f64]) };
 
     /// Loads values from the pointer into the SIMD vector
     #[inline(always)]
@@ -194,6 +279,8 @@ impl f64x3 {
         Self(_mm256_set_pd(0., *ptr.add(2), *ptr.add(1), *ptr))
     }
 
+    impl_aligned_fallback!(f64, 3);
+
     /// Returns a vector with the absolute values of the original vector
     #[inline(always)]
     pub fn abs (self) -> Self {
@@ -206,55 +293,132 @@ impl f64x3 {
         unsafe { Self(_mm256_sqrt_pd(self.0)) }
     }
 
+    /// Returns a vector with the integer parts of the original vector
+    #[inline(always)]
+    pub fn trunc (self) -> Self {
+        unsafe { Self(_mm256_round_pd(self.0, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)) }
+    }
+
+    /// Returns a vector with the floors of the original vector
+    #[inline(always)]
+    pub fn floor (self) -> Self {
+        unsafe { Self(_mm256_floor_pd(self.0)) }
+    }
+
     /// Gets the smallest/minimum value of the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn min (self) -> f64 {
-        unsafe { f64x4(_mm256_or_pd(Self::MIN_MASK, self.0)).min() }
+        unsafe { f64x4(_mm256_blend_pd(self.0, _mm256_set1_pd(f64::INFINITY), 0b1000)).min() }
     }
 
     /// Gets the biggest/maximum value of the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn max (self) -> f64 {
-        unsafe { f64x4(_mm256_or_pd(Self::MAX_MASK, self.0)).max() }
+        unsafe { f64x4(_mm256_blend_pd(self.0, _mm256_set1_pd(f64::NEG_INFINITY), 0b1000)).max() }
     }
 
     /// Sums up all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn sum (self) -> f64 {
-        f64x4(self.0).sum()
+        unsafe { f64x4(_mm256_blend_pd(self.0, _mm256_setzero_pd(), 0b1000)).sum() }
     }
 
-     /// Multiplies all the values inside the vector
-     #[inline(always)]
-     pub fn prod (self) -> f64 {
-         f64x4(self.0).prod()
-     }
+    /// Multiplies all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
+    #[inline(always)]
+    pub fn prod (self) -> f64 {
+        unsafe { f64x4(_mm256_blend_pd(self.0, _mm256_set1_pd(1.), 0b1000)).prod() }
+    }
 
     /// Returns a vector with the smallest/minimum value of each lane
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn vmin (self, rhs: Self) -> Self {
         unsafe { Self(_mm256_min_pd(self.0, rhs.0)) }
     }
 
     /// Returns a vector with the absolute values of the original vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn vmax (self, rhs: Self) -> Self {
         unsafe { Self(_mm256_max_pd(self.0, rhs.0)) }
     }
 
+    /// Returns a vector with the step function (`0` if smaller than `rhs`, `1` otherwise) of each lane
+    #[inline(always)]
+    pub fn step (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_and_pd(Self::STEP_MASK, _mm256_cmp_pd(self.0, rhs.0, _CMP_GE_OQ))) }
+    }
+
+    impl_interp_fns!(f64);
+    impl_euclid_fns!(f64);
+
+    #[inline(always)]
+    pub(crate) fn cmp_lt (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_cmp_pd(self.0, rhs.0, _CMP_LT_OQ)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmp_eq (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_cmp_pd(self.0, rhs.0, _CMP_EQ_OQ)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn bit_and (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_and_pd(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn bit_or (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_or_pd(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn bit_andnot (self, rhs: Self) -> Self {
+        unsafe { Self(_mm256_andnot_pd(self.0, rhs.0)) }
+    }
+
+    impl_mask_fns!(f64);
+
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill` and clearing the padding lane
+    #[inline(always)]
+    pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+        unsafe { Self(_mm256_and_pd(Self::DIV_MASK, f64x4(self.0).shift_up(lanes, f64x4(fill.0)).0)) }
+    }
+
+    impl_scan!(@float f64, 3);
+
+    // The padding lane is replaced by `pad`
+    #[cfg(feature = "deterministic")]
+    #[inline(always)]
+    pub(crate) fn pairs (self, pad: f64) -> [f64x2; 2] {
+        unsafe { [f64x2(_mm256_castpd256_pd128(self.0)), f64x2(_mm_move_sd(_mm_set1_pd(pad), _mm256_extractf128_pd(self.0, 1)))] }
+    }
+
+    impl_reduce_pairs!(f64);
+
     /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
     /// # Compatibility
     /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
     /// For the rest of targets, a regular multiplication and addition are performed
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn mul_add (self, rhs: Self, add: Self) -> Self {
-        Self(f64x4(self.0).fma(f64x4(rhs.0), f64x4(add.0)).0)
+        Self(f64x4(self.0).mul_add(f64x4(rhs.0), f64x4(add.0)).0)
     }
 
     /// Interleaves elements of both vectors into one
     #[inline(always)]
     pub fn zip (self, rhs: Self) -> Self {
-        unsafe { _mm256_and_pd(__m256_unpacklo_pd(self.0, rhs.0), DIV_MASK) }
+        unsafe {
+            let lo = _mm256_castpd256_pd128(self.0);
+            let first = _mm_unpacklo_pd(lo, _mm256_castpd256_pd128(rhs.0));
+            let second = _mm_unpackhi_pd(lo, _mm_setzero_pd());
+            Self(_mm256_set_m128d(second, first))
+        }
     }
 }
 
@@ -324,10 +488,13 @@ impl From<f64> for f64x3 {
     }
 }
 
+impl_clone!(f64x3, f64, 3);
 impl_scal_arith!(
     f64x3, f64,
     Add, add,
     Sub, sub,
     Mul, mul,
     Div, div
-);
\ No newline at end of file
+);
+
+impl_rem!(f64x3, f64);
\ No newline at end of file
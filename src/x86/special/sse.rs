@@ -8,13 +8,14 @@ arch_use!();
 #[allow(non_camel_case_types)]
 #[repr(transparent)]
 #[derive(Copy, Assign)]
-#[assign_targets(Add, Sub, Mul, Div)]
+#[assign_targets(Add, Sub, Mul, Div, Rem)]
 #[assign_rhs(Self, f32)]
 pub struct f32x2 (pub(crate) __m128);
 
 impl f32x2 {
     const DIV_MASK : __m128 = unsafe { transmute([u32::MAX, u32::MAX, 0, 0]) };
     const ABS_MASK : __m128 = unsafe { transmute([i32::MAX, i32::MAX, 0, 0]) };
+    const STEP_MASK : __m128 = unsafe { transmute([1f32, 1f32, 0f32, 0f32]) };
 
     /// Loads values from the pointer into the SIMD vector
     #[inline(always)]
@@ -22,6 +23,8 @@ impl f32x2 {
         Self(_mm_set_ps(0., 0., *ptr.add(1), *ptr))
     }
 
+    impl_aligned_fallback!(f32, 2);
+
     /// Returns a vector with the absolute values of the original vector
     #[inline(always)]
     pub fn abs (self) -> Self {
@@ -34,7 +37,20 @@ impl f32x2 {
         unsafe { Self(_mm_sqrt_ps(self.0)) }
     }
 
+    /// Returns a vector with the integer parts of the original vector
+    #[inline(always)]
+    pub fn trunc (self) -> Self {
+        Self(f32x4(self.0).trunc().0)
+    }
+
+    /// Returns a vector with the floors of the original vector
+    #[inline(always)]
+    pub fn floor (self) -> Self {
+        Self(f32x4(self.0).floor().0)
+    }
+
     /// Gets the smallest/minimum value of the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn min (self) -> f32 {
         unsafe {
@@ -44,6 +60,7 @@ impl f32x2 {
     }
 
     /// Gets the biggest/maximum value of the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn max (self) -> f32 {
         unsafe {
@@ -53,6 +70,7 @@ impl f32x2 {
     }
 
     /// Sums up all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn sum (self) -> f32 {
         unsafe {
@@ -62,6 +80,7 @@ impl f32x2 {
     }
 
     /// Multiplies all the values inside the vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn prod (self) -> f32 {
         unsafe {
@@ -71,18 +90,76 @@ impl f32x2 {
     }
 
     /// Returns a vector with the smallest/minimum value of each lane
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn vmin (self, rhs: Self) -> Self {
         unsafe { Self(_mm_min_ps(self.0, rhs.0)) }
     }
 
     /// Returns a vector with the absolute values of the original vector
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn vmax (self, rhs: Self) -> Self {
         unsafe { Self(_mm_max_ps(self.0, rhs.0)) }
     }
 
+    /// Returns a vector with the step function (`0` if smaller than `rhs`, `1` otherwise) of each lane
+    #[inline(always)]
+    pub fn step (self, rhs: Self) -> Self {
+        unsafe { Self(_mm_and_ps(Self::STEP_MASK, _mm_cmpge_ps(self.0, rhs.0))) }
+    }
+
+    impl_interp_fns!(f32);
+    impl_euclid_fns!(f32);
+
+    #[inline(always)]
+    pub(crate) fn cmp_lt (self, rhs: Self) -> Self {
+        unsafe { Self(_mm_cmplt_ps(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmp_eq (self, rhs: Self) -> Self {
+        unsafe { Self(_mm_cmpeq_ps(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn bit_and (self, rhs: Self) -> Self {
+        unsafe { Self(_mm_and_ps(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn bit_or (self, rhs: Self) -> Self {
+        unsafe { Self(_mm_or_ps(self.0, rhs.0)) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn bit_andnot (self, rhs: Self) -> Self {
+        unsafe { Self(_mm_andnot_ps(self.0, rhs.0)) }
+    }
+
+    impl_mask_fns!(f32);
+
+    // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill` and clearing the padding lanes
+    #[inline(always)]
+    pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+        match lanes {
+            1 => unsafe { Self(_mm_and_ps(Self::DIV_MASK, transmute(shift_bytes!(_mm_, transmute(self.0), transmute(fill.0), 4)))) },
+            _ => unreachable!()
+        }
+    }
+
+    impl_scan!(@float f32, 2);
+
+    #[cfg(feature = "deterministic")]
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f32) -> [Self; 1] {
+        [self]
+    }
+
+    impl_reduce_pairs!(f32);
+
     /// Fused multiply-add. Computes `(self * a) + b` with only one rounding error.
+    #[cfg(not(feature = "deterministic"))]
     #[inline(always)]
     pub fn mul_add (self, rhs: Self, add: Self) -> Self {
         Self(f32x4(self.0).mul_add(f32x4(rhs.0), f32x4(add.0)).0)
@@ -166,4 +243,6 @@ impl_scal_arith!(
     Sub, sub,
     Mul, mul,
     Div, div
-);
\ No newline at end of file
+);
+
+impl_rem!(f32x2, f32);
\ No newline at end of file
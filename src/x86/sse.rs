@@ -30,6 +30,17 @@ macro_rules! abs_mask {
     };
 }
 
+macro_rules! round_magic {
+    (f32) => {
+        8388608.
+    };
+
+    (f64) => {
+        4503599627370496.
+    };
+}
+
+#[cfg_attr(feature = "deterministic", allow(unused_macros))]
 macro_rules! impl_hoz_fns_straight {
     ($ty:ident, $($fun:ident: $docs:expr),+) => {
         $(
@@ -76,6 +87,15 @@ macro_rules! impl_hoz_fns_straight {
 }
 
 macro_rules! impl_other_fns_straight {
+    (@mask $ty:ident, $($fun:ident as $name:ident),+) => {
+        $(
+            #[inline(always)]
+            pub(crate) fn $name (self, rhs: Self) -> Self {
+                unsafe { Self(_mm_concat!($fun, $ty)(self.0, rhs.0)) }
+            }
+        )*
+    };
+
     ($ty:ident, $($fun:ident $(as $name:ident)?: $docs:expr),+) => {
         $(
             impl_other_fns_straight!(1, $fun $(,$name)?, $ty, $docs);
@@ -116,7 +136,7 @@ macro_rules! impl_straight {
             #[allow(non_camel_case_types)]
             #[repr(transparent)]
             #[derive(Copy, Assign)]
-            #[assign_targets(Add, Sub, Mul, Div)]
+            #[assign_targets(Add, Sub, Mul, Div, Rem)]
             #[assign_rhs(Self, $ty)]
             pub struct $target(pub(crate) $og);
             impl_straight!(
@@ -127,6 +147,8 @@ macro_rules! impl_straight {
                 Div, div
             );
 
+            impl_rem!($target, $ty);
+
             impl Neg for $target {
                 type Output = Self;
 
@@ -155,6 +177,22 @@ macro_rules! impl_straight {
                     //Self(_mm_concat!(loadu, $ty)(addr_of!(reverse).cast()))
                 }
 
+                /// Loads values from the pointer into the SIMD vector.
+                /// The pointer must be aligned to 16 bytes, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn load_aligned (ptr: *const $ty) -> Self {
+                    debug_assert_eq!(ptr as usize % 16, 0, "pointer is not aligned");
+                    Self(_mm_concat!(load, $ty)(ptr))
+                }
+
+                /// Stores the values of the vector into the pointer.
+                /// The pointer must be aligned to 16 bytes, which is only checked on debug builds
+                #[inline(always)]
+                pub unsafe fn store_aligned (self, ptr: *mut $ty) {
+                    debug_assert_eq!(ptr as usize % 16, 0, "pointer is not aligned");
+                    _mm_concat!(store, $ty)(ptr, self.0)
+                }
+
                 #[doc=concat!("Returns a vector with the absolute values of the original vector")]
                 #[inline(always)]
                 pub fn abs (self) -> Self {
@@ -167,18 +205,100 @@ macro_rules! impl_straight {
                     unsafe { Self(_mm_concat!(sqrt, $ty)(self.0)) }
                 }
 
+                /// Returns a vector with the integer parts of the original vector
+                #[cfg(target_feature = "sse4.1")]
+                #[inline(always)]
+                pub fn trunc (self) -> Self {
+                    unsafe { Self(_mm_concat!(round, $ty)(self.0, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC)) }
+                }
+
+                /// Returns a vector with the integer parts of the original vector
+                #[cfg(not(target_feature = "sse4.1"))]
+                #[inline(always)]
+                pub fn trunc (self) -> Self {
+                    unsafe {
+                        let abs = self.abs().0;
+                        let magic = _mm_concat!(set1, $ty)(round_magic!($ty));
+                        let one = _mm_concat!(set1, $ty)(1.);
+
+                        // adding and removing the magic number rounds to the nearest integer, so step back where it rounded up
+                        let round = _mm_concat!(sub, $ty)(_mm_concat!(add, $ty)(abs, magic), magic);
+                        let round = _mm_concat!(sub, $ty)(round, _mm_concat!(and, $ty)(_mm_concat!(cmpgt, $ty)(round, abs), one));
+
+                        // values past the magic number (alongside infinities and NaNs) are left untouched
+                        let small = _mm_concat!(cmplt, $ty)(abs, magic);
+                        let round = _mm_concat!(or, $ty)(_mm_concat!(and, $ty)(small, round), _mm_concat!(andnot, $ty)(small, abs));
+                        Self(_mm_concat!(or, $ty)(round, _mm_concat!(andnot, $ty)(Self::ABS_MASK, self.0)))
+                    }
+                }
+
+                /// Returns a vector with the floors of the original vector
+                #[cfg(target_feature = "sse4.1")]
+                #[inline(always)]
+                pub fn floor (self) -> Self {
+                    unsafe { Self(_mm_concat!(floor, $ty)(self.0)) }
+                }
+
+                /// Returns a vector with the floors of the original vector
+                #[cfg(not(target_feature = "sse4.1"))]
+                #[inline(always)]
+                pub fn floor (self) -> Self {
+                    unsafe {
+                        let trunc = self.trunc().0;
+                        let one = _mm_concat!(set1, $ty)(1.);
+                        Self(_mm_concat!(sub, $ty)(trunc, _mm_concat!(and, $ty)(_mm_concat!(cmpgt, $ty)(trunc, self.0), one)))
+                    }
+                }
+
+                #[cfg(not(feature = "deterministic"))]
                 impl_other_fns_straight!(
                     $ty,
                     min as vmin: "smallest/minimum value",
                     max as vmax: "biggest/maximum value"
                 );
 
+                /// Returns a vector with the step function (`0` if smaller than `rhs`, `1` otherwise) of each lane
+                #[inline(always)]
+                pub fn step (self, rhs: Self) -> Self {
+                    unsafe { Self(_mm_concat!(and, $ty)(_mm_concat!(cmpge, $ty)(self.0, rhs.0), _mm_concat!(set1, $ty)(1.))) }
+                }
+
+                impl_interp_fns!($ty);
+                impl_euclid_fns!($ty);
+
+                impl_other_fns_straight!(
+                    @mask $ty,
+                    cmplt as cmp_lt,
+                    cmpeq as cmp_eq,
+                    and as bit_and,
+                    or as bit_or,
+                    andnot as bit_andnot
+                );
+                impl_mask_fns!($ty);
+
+                // Moves every lane `lanes` positions up, filling the lanes left at the bottom from `fill`
+                #[inline(always)]
+                pub(crate) fn shift_up (self, lanes: usize, fill: Self) -> Self {
+                    unsafe {
+                        let (v, fill) : (__m128i, __m128i) = (transmute(self.0), transmute(fill.0));
+                        Self(transmute(match lanes * size_of::<$ty>() {
+                            4 => shift_bytes!(_mm_, v, fill, 4),
+                            8 => shift_bytes!(_mm_, v, fill, 8),
+                            _ => unreachable!()
+                        }))
+                    }
+                }
+
+                impl_scan!(@float $ty, $len);
+
+                #[cfg(not(feature = "deterministic"))]
                 impl_hoz_fns_straight!(
                     $ty,
                     min: "Gets the smallest/minimum value of the vector",
                     max: "Gets the biggest/maximum value of the vector"
                 );
 
+                #[cfg(not(feature = "deterministic"))]
                 impl_hoz_fns_straight!(
                     $ty,
                     add as sum: "Sums up all the values inside the vector",
@@ -189,7 +309,7 @@ macro_rules! impl_straight {
                 /// # Compatibility
                 /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
                 /// For the rest of targets, a regular multiplication and addition are performed
-                #[cfg(target_feature = "fma")]
+                #[cfg(all(target_feature = "fma", not(feature = "deterministic")))]
                 #[inline(always)]
                 pub fn mul_add (self, rhs: Self, add: Self) -> Self {
                     unsafe { Self(_mm_concat!(fmadd, $ty)(self.0, rhs.0, add.0)) }
@@ -199,7 +319,7 @@ macro_rules! impl_straight {
                 /// # Compatibility
                 /// The fused multiply-add operation is only available on arm/aarch64 and x86/x86-64 with the target feature ```fma```.
                 /// For the rest of targets, a regular multiplication and addition are performed
-                #[cfg(not(target_feature = "fma"))]
+                #[cfg(all(not(target_feature = "fma"), not(feature = "deterministic")))]
                 #[inline(always)]
                 pub fn mul_add (self, rhs: Self, add: Self) -> Self {
                     (self * rhs) + add
@@ -227,9 +347,61 @@ impl_straight!(
     __m128d as f64x2 => [f64;2]
 );
 
+// The sorting networks pair every lane `i` with the lane `i ^ j`
+impl f32x4 {
+    #[inline(always)]
+    pub(crate) fn xor_lanes (self, j: usize) -> Self {
+        unsafe {
+            match j {
+                1 => Self(_mm_shuffle_ps(self.0, self.0, 0b10_11_00_01)),
+                2 => Self(_mm_shuffle_ps(self.0, self.0, 0b01_00_11_10)),
+                _ => unreachable!()
+            }
+        }
+    }
+}
+
+#[cfg(not(all(feature = "use_avx", target_feature = "avx")))]
+impl f64x2 {
+    #[inline(always)]
+    pub(crate) fn xor_lanes (self, j: usize) -> Self {
+        match j {
+            1 => unsafe { Self(_mm_shuffle_pd(self.0, self.0, 0b01)) },
+            _ => unreachable!()
+        }
+    }
+}
+
+// The deterministic reductions split the registers in their halves, without any padding
+#[cfg(feature = "deterministic")]
+impl f32x4 {
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f32) -> [f32x2; 2] {
+        unsafe { [f32x2(self.0), f32x2(_mm_movehl_ps(self.0, self.0))] }
+    }
+
+    impl_reduce_pairs!(f32);
+}
+
+#[cfg(feature = "deterministic")]
+impl f64x2 {
+    #[inline(always)]
+    pub(crate) fn pairs (self, _pad: f64) -> [f64x2; 1] {
+        [self]
+    }
+
+    impl_reduce_pairs!(f64);
+}
+
 impl_composite!(
     (f32x4 => 4, f32x2 => 2) as f32x6: f32,
     (f32x4 => 4, f32x4 => 4) as f32x8: f32,
+    (f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x10: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x12: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x14: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x16: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x24: f32,
+    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x32: f32,
 
     (f64x2 => 2, f64x2 => 2) as f64x4: f64,
     (f64x4 => 4, f64x2 => 2) as f64x6: f64,
@@ -237,15 +409,27 @@ impl_composite!(
     (f64x6 => 6, f64x4 => 4) as f64x10: f64,
     (f64x6 => 6, f64x6 => 6) as f64x12: f64,
     (f64x8 => 8, f64x6 => 6) as f64x14: f64,
-    (f64x8 => 8, f64x8 => 8) as f64x16: f64
+    (f64x8 => 8, f64x8 => 8) as f64x16: f64,
+    (f64x8 => 8, f64x8 => 8, f64x8 => 8) as f64x24: f64,
+    (f64x8 => 8, f64x8 => 8, f64x8 => 8, f64x8 => 8) as f64x32: f64
 );
 
-impl_composite!(
-    (f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x10: f32,
-    (f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x12: f32
+// With AVX enabled, the exported vectors are sorted with AVX instructions instead
+#[cfg(not(all(feature = "use_avx", target_feature = "avx")))]
+impl_xor_lanes!(
+    [f32x4 => 4; 2] as f32x8,
+    [f32x4 => 4; 4] as f32x16,
+
+    [f64x2 => 2; 2] as f64x4,
+    [f64x4 => 4; 2] as f64x8,
+    [f64x8 => 8; 2] as f64x16
 );
 
-impl_composite!(
-    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x2 => 2) as f32x14: f32,
-    (f32x4 => 4, f32x4 => 4, f32x4 => 4, f32x4 => 4) as f32x16: f32
+impl_padded!(
+    f32x4 as f32x3: f32
+);
+
+// With AVX enabled, the exported `f64x3` is backed by a single 256-bit register instead
+impl_padded!(
+    f64x4 as f64x3: f64
 );
\ No newline at end of file
@@ -1,6 +1,9 @@
 #![feature(concat_idents)]
 use llml_simd::float::single::*;
 use llml_simd::float::double::*;
+use llml_simd::{ParseVectorError, BitEq};
+use std::collections::HashMap;
+use std::cmp::Ordering;
 use core::ops::*;
 use rand::random;
 
@@ -9,6 +12,7 @@ macro_rules! test_other {
         $(
             test_other!($fun, $($name,)?
                 [f32;2] as f32x2,
+                [f32;3] as f32x3,
                 [f32;4] as f32x4,
                 [f32;6] as f32x6,
                 [f32;8] as f32x8,
@@ -16,15 +20,20 @@ macro_rules! test_other {
                 [f32;12] as f32x12,
                 [f32;14] as f32x14,
                 [f32;16] as f32x16,
+                [f32;24] as f32x24,
+                [f32;32] as f32x32,
             
                 [f64;2] as f64x2,
+                [f64;3] as f64x3,
                 [f64;4] as f64x4,
                 [f64;6] as f64x6,
                 [f64;8] as f64x8,
                 [f64;10] as f64x10,
                 [f64;12] as f64x12,
                 [f64;14] as f64x14,
-                [f64;16] as f64x16
+                [f64;16] as f64x16,
+                [f64;24] as f64x24,
+                [f64;32] as f64x32
             );
         )*
     };
@@ -61,6 +70,7 @@ macro_rules! test_mappings {
         $(
             test_mappings!($fun, $($name,)?
                 [f32;2] as f32x2,
+                [f32;3] as f32x3,
                 [f32;4] as f32x4,
                 [f32;6] as f32x6,
                 [f32;8] as f32x8,
@@ -68,15 +78,20 @@ macro_rules! test_mappings {
                 [f32;12] as f32x12,
                 [f32;14] as f32x14,
                 [f32;16] as f32x16,
+                [f32;24] as f32x24,
+                [f32;32] as f32x32,
             
                 [f64;2] as f64x2,
+                [f64;3] as f64x3,
                 [f64;4] as f64x4,
                 [f64;6] as f64x6,
                 [f64;8] as f64x8,
                 [f64;10] as f64x10,
                 [f64;12] as f64x12,
                 [f64;14] as f64x14,
-                [f64;16] as f64x16
+                [f64;16] as f64x16,
+                [f64;24] as f64x24,
+                [f64;32] as f64x32
             );
         )*
     };
@@ -110,6 +125,7 @@ macro_rules! test_horiz {
         $(
             test_horiz!($fun, $($name,)?
                 [f32;2] as f32x2,
+                [f32;3] as f32x3,
                 [f32;4] as f32x4,
                 [f32;6] as f32x6,
                 [f32;8] as f32x8,
@@ -117,15 +133,20 @@ macro_rules! test_horiz {
                 [f32;12] as f32x12,
                 [f32;14] as f32x14,
                 [f32;16] as f32x16,
+                [f32;24] as f32x24,
+                [f32;32] as f32x32,
             
                 [f64;2] as f64x2,
+                [f64;3] as f64x3,
                 [f64;4] as f64x4,
                 [f64;6] as f64x6,
                 [f64;8] as f64x8,
                 [f64;10] as f64x10,
                 [f64;12] as f64x12,
                 [f64;14] as f64x14,
-                [f64;16] as f64x16
+                [f64;16] as f64x16,
+                [f64;24] as f64x24,
+                [f64;32] as f64x32
             );
         )*
     };
@@ -252,9 +273,11 @@ macro_rules! test_transpose {
             let beta : $target = beta_array.into();
 
             let mut result = [(0 as $ty);$len];
-            for i in 0..($len/2) {
+            for i in 0..(($len + 1) / 2) {
                 result[2 * i] = alpha[i];
-                result[2 * i + 1] = beta[i];
+                if 2 * i + 1 < $len {
+                    result[2 * i + 1] = beta[i];
+                }
             }
 
             assert_eq!(Into::<[$ty;$len]>::into(alpha.zip(beta)), result);
@@ -286,6 +309,279 @@ macro_rules! test_mul_add {
     }
 }
 
+#[cfg(all(feature = "deterministic", feature = "use_std"))]
+macro_rules! test_deterministic {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            let edges : [&[(usize, $ty)];3] = [&[], &[(0, -0.), (1, 0.), ($len - 1, $ty::INFINITY)], &[($len - 1, $ty::NAN)]];
+            for edges in edges {
+                let mut alpha : [$ty;$len] = random::<[$ty;$len]>().map(|x| 16. * x - 8.);
+                let beta : [$ty;$len] = random::<[$ty;$len]>().map(|x| 0.1 + x);
+                let gamma : [$ty;$len] = random();
+                for (i, x) in edges {
+                    alpha[*i] = *x;
+                }
+
+                let naive = (
+                    llml_simd::backend::naive::$target::new(alpha),
+                    llml_simd::backend::naive::$target::new(beta),
+                    llml_simd::backend::naive::$target::new(gamma)
+                );
+
+                let simd = (<$target>::new(alpha), <$target>::new(beta), <$target>::new(gamma));
+                let msg = concat!("deterministic results for ", stringify!($target));
+
+                assert_eq!(simd.0.sum().to_bits(), naive.0.sum().to_bits(), "{msg}");
+                assert_eq!(simd.0.prod().to_bits(), naive.0.prod().to_bits(), "{msg}");
+                assert_eq!(simd.0.min().to_bits(), naive.0.min().to_bits(), "{msg}");
+                assert_eq!(simd.0.max().to_bits(), naive.0.max().to_bits(), "{msg}");
+                assert_eq!(simd.0.vmin(simd.1).into_array().map($ty::to_bits), naive.0.vmin(naive.1).into_array().map($ty::to_bits), "{msg}");
+                assert_eq!(simd.0.vmax(simd.1).into_array().map($ty::to_bits), naive.0.vmax(naive.1).into_array().map($ty::to_bits), "{msg}");
+                assert_eq!(simd.0.mul_add(simd.1, simd.2).into_array().map($ty::to_bits), naive.0.mul_add(naive.1, naive.2).into_array().map($ty::to_bits), "{msg}");
+                assert_eq!(simd.1.sqrt().into_array().map($ty::to_bits), naive.1.sqrt().into_array().map($ty::to_bits), "{msg}");
+                assert_eq!((simd.0 % simd.1).into_array().map($ty::to_bits), (naive.0 % naive.1).into_array().map($ty::to_bits), "{msg}");
+                assert_eq!(simd.0.rem_euclid(simd.1).into_array().map($ty::to_bits), naive.0.rem_euclid(naive.1).into_array().map($ty::to_bits), "{msg}");
+                assert_eq!(simd.0.div_euclid(simd.1).into_array().map($ty::to_bits), naive.0.div_euclid(naive.1).into_array().map($ty::to_bits), "{msg}");
+            }
+        )*
+    }
+}
+
+macro_rules! test_interp {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            let alpha : [$ty;$len] = random();
+            let beta : [$ty;$len] = random();
+            let gamma : [$ty;$len] = random();
+            let t : $ty = random();
+
+            let simd = <$target>::new(alpha);
+            let other = <$target>::new(beta);
+            let edge = <$target>::new(gamma);
+
+            let clamp = simd.clamp(0.25, 0.75);
+            let vclamp = simd.clamp(other.vmin(edge), other.vmax(edge));
+            let saturate = (simd * 4. - 2.).saturate();
+            let lerp = simd.lerp(other, t);
+            let step = simd.step(edge);
+            let smoothstep = simd.smoothstep(0.25, 0.75);
+
+            for i in 0..$len {
+                let (x, y, z) = (alpha[i], beta[i], gamma[i]);
+                let smooth = ((x - 0.25) / 0.5).clamp(0., 1.);
+
+                assert_eq!(clamp[i], x.clamp(0.25, 0.75), concat!("clamp for ", stringify!($target)));
+                assert_eq!(vclamp[i], x.clamp(y.min(z), y.max(z)), concat!("clamp for ", stringify!($target)));
+                assert_eq!(saturate[i], (x * 4. - 2.).clamp(0., 1.), concat!("saturate for ", stringify!($target)));
+                assert!((lerp[i] - (x + (y - x) * t)).abs() <= $ty::EPSILON * 2., concat!("lerp for ", stringify!($target)));
+                assert_eq!(step[i], if x >= z { 1. } else { 0. }, concat!("step for ", stringify!($target)));
+                assert!((smoothstep[i] - smooth * smooth * (3. - 2. * smooth)).abs() <= $ty::EPSILON * 4., concat!("smoothstep for ", stringify!($target)));
+            }
+        )*
+    }
+}
+
+macro_rules! test_euclid {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            let alpha : [$ty;$len] = random::<[$ty;$len]>().map(|x| 8. * x - 4.);
+            let beta : [$ty;$len] = random::<[$ty;$len]>().map(|x| if x < 0.5 { -1. - 2. * x } else { 2. * x });
+
+            let simd = <$target>::new(alpha);
+            let other = <$target>::new(beta);
+
+            let trunc = simd.trunc();
+            let floor = simd.floor();
+            let rem = simd % other;
+            let rem_euclid = simd.rem_euclid(other);
+            let div_euclid = simd.div_euclid(other);
+
+            for i in 0..$len {
+                let (x, y) = (alpha[i], beta[i]);
+                assert_eq!(trunc[i], x.trunc(), concat!("trunc for ", stringify!($target)));
+                assert_eq!(floor[i], x.floor(), concat!("floor for ", stringify!($target)));
+                assert!((rem[i] - x % y).abs() <= $ty::EPSILON * 8., concat!("rem for ", stringify!($target)));
+                assert!((rem_euclid[i] - x.rem_euclid(y)).abs() <= $ty::EPSILON * 8., concat!("rem_euclid for ", stringify!($target)));
+                assert_eq!(div_euclid[i], x.div_euclid(y), concat!("div_euclid for ", stringify!($target)));
+            }
+
+            // infinite divisors, signed zeros, exact multiples, quotients too big for the mantissa and dividends just below a multiple, where the quotient rounds up
+            let mut edges : Vec<($ty, $ty)> = vec![
+                (5., $ty::INFINITY), (-5., $ty::INFINITY), (5., $ty::NEG_INFINITY),
+                (0., 3.), (-0., 3.), (6., 3.), (-6., 3.), (6., -3.),
+                ($ty::MAX, $ty::MIN_POSITIVE), (-$ty::MAX, 3.), (1e30, 7.), (1., 1e-30)
+            ];
+            edges.extend((1..16).map(|k| (<$ty>::from_bits((k as $ty * 0.1).to_bits() - 1), 0.1)));
+            edges.extend((1..16).map(|k| (-<$ty>::from_bits((k as $ty * 0.3).to_bits() - 1), 0.3)));
+
+            for (x, y) in edges {
+                let (simd, other) = (<$target>::from(x), <$target>::from(y));
+                let rem = (simd % other).into_array();
+                let rem_euclid = simd.rem_euclid(other).into_array();
+                let div_euclid = simd.div_euclid(other).into_array();
+
+                for i in 0..$len {
+                    for (actual, expected) in [(rem[i], x % y), (rem_euclid[i], x.rem_euclid(y))] {
+                        assert_eq!(actual.is_sign_negative(), expected.is_sign_negative(), "rem of {} by {} for {}", x, y, stringify!($target));
+                        assert!(actual == expected || (actual - expected).abs() <= $ty::EPSILON * x.abs().max(1.) * 2., "rem of {} by {} for {}", x, y, stringify!($target));
+                    }
+
+                    assert_eq!(div_euclid[i], x.div_euclid(y), "div_euclid of {} by {} for {}", x, y, stringify!($target));
+                }
+            }
+        )*
+    }
+}
+
+macro_rules! test_fmt {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            let array : [$ty;$len] = random();
+            let simd = <$target>::new(array);
+
+            let lanes = array.iter().map(|x| format!("{x:.3}")).collect::<Vec<_>>();
+            assert_eq!(format!("{simd:.3}"), format!("({})", lanes.join(", ")), concat!("Display for ", stringify!($target)));
+
+            let lanes = array.iter().map(|x| format!("{x:>12e}")).collect::<Vec<_>>();
+            assert_eq!(format!("{simd:>12e}"), format!("({})", lanes.join(", ")), concat!("LowerExp for ", stringify!($target)));
+
+            let lanes = array.iter().map(|x| format!("{x:E}")).collect::<Vec<_>>();
+            assert_eq!(format!("{simd:E}"), format!("({})", lanes.join(", ")), concat!("UpperExp for ", stringify!($target)));
+
+            assert_eq!(simd.to_string().parse::<$target>(), Ok(simd), concat!("FromStr for ", stringify!($target)));
+            assert_eq!(format!("{simd:e}").parse::<$target>(), Ok(simd), concat!("FromStr for ", stringify!($target)));
+            assert_eq!(format!("{:?}", array).parse::<$target>(), Err(ParseVectorError::Delimiters));
+            assert_eq!("()".parse::<$target>(), Err(ParseVectorError::LaneCount { expected: $len, found: 0 }));
+            assert_eq!(format!("({})", [" 1"; $len + 1].join(",")).parse::<$target>(), Err(ParseVectorError::LaneCount { expected: $len, found: $len + 1 }));
+            assert!(matches!(format!("(1, x{})", ", 1".repeat($len - 2)).parse::<$target>(), Err(ParseVectorError::Float { lane: 1, .. })));
+        )*
+    }
+}
+
+macro_rules! test_cmp {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            assert_eq!(<$target>::default().into_array(), [0 as $ty;$len]);
+
+            let alpha : [$ty;$len] = random();
+            let mut beta = alpha;
+            beta[$len - 1] += 1.;
+
+            let (simd, other) = (<$target>::new(alpha), <$target>::new(beta));
+            assert!(simd < other, concat!("PartialOrd for ", stringify!($target)));
+            assert_eq!(simd.partial_cmp(&simd), Some(Ordering::Equal));
+            assert_eq!(simd.total_cmp(&other), Ordering::Less);
+
+            let lanes = other.total_cmp_lanes(&simd);
+            assert!(lanes[..$len - 1].iter().all(|x| x.is_eq()));
+            assert_eq!(lanes[$len - 1], Ordering::Greater);
+
+            let nan = <$target>::from($ty::NAN);
+            assert_eq!(nan.partial_cmp(&nan), None);
+            assert_eq!(BitEq(nan), BitEq(nan));
+            assert_ne!(BitEq(<$target>::from(0.)), BitEq(<$target>::from(-0.)));
+
+            let mut map = HashMap::new();
+            map.insert(BitEq(simd), 1);
+            map.insert(BitEq(other), 2);
+            map.insert(BitEq(nan), 3);
+            assert_eq!(map.get(&BitEq(<$target>::new(alpha))), Some(&1));
+            assert_eq!(map.get(&BitEq(nan)), Some(&3));
+        )*
+    }
+}
+
+macro_rules! test_bytemuck {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            let mut vectors = (0..4).map(|_| <$target>::new(random())).collect::<Vec<_>>();
+            let copy = vectors.clone();
+            let scalars = <$target>::as_scalars_mut(&mut vectors);
+            assert_eq!(scalars.len(), 4 * $len);
+
+            let (simd, rem) = <$target>::as_vectors(&scalars[..4 * $len - 1]);
+            assert_eq!(simd.len() * $len + rem.len(), 4 * $len - 1);
+            assert_eq!(simd, &copy[..3]);
+            assert_eq!(rem, &copy[3].into_array()[..$len - 1]);
+
+            let (simd, rem) = <$target>::as_vectors_mut(scalars);
+            assert_eq!(simd.len() * $len + rem.len(), 4 * $len);
+            simd[0] = <$target>::from(1.);
+            assert_eq!(<$target>::as_scalars(&vectors)[..$len], [1.; $len]);
+
+            // a slice that starts in the middle of a vector can't be reinterpreted if the vector is over-aligned
+            let scalars = <$target>::as_scalars(&vectors);
+            let (simd, rem) = <$target>::as_vectors(&scalars[1..]);
+            assert_eq!(simd.len() * $len + rem.len(), 4 * $len - 1);
+            assert_eq!(<$target>::as_scalars(simd), &scalars[1..1 + simd.len() * $len]);
+            assert_eq!(rem, &scalars[1 + simd.len() * $len..]);
+
+            if core::mem::align_of::<$target>() > core::mem::align_of::<$ty>() {
+                assert!(simd.is_empty());
+                assert!(<$target>::try_as_vectors(&scalars[1..]).is_err());
+            }
+
+            assert_eq!(<$target as bytemuck::Zeroable>::zeroed(), <$target>::default());
+        )*
+    }
+}
+
+#[cfg(feature = "num-traits")]
+fn horner<T: num_traits::Num + num_traits::MulAdd<Output = T> + Copy> (x: T, coeffs: &[T]) -> T {
+    coeffs.iter().rev().fold(T::zero(), |acc, c| acc.mul_add(x, *c))
+}
+
+macro_rules! test_num {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            let alpha : [$ty;$len] = random::<[$ty;$len]>().map(|x| 2. * x - 1.);
+            let coeffs : [$ty;3] = random();
+            let simd = <$target>::new(alpha);
+
+            let poly = horner(simd, &coeffs.map(<$target>::from));
+            for i in 0..$len {
+                let naive = horner(alpha[i], &coeffs);
+                assert!((poly[i] - naive).abs() <= $ty::EPSILON * 4., concat!("MulAdd for ", stringify!($target)));
+            }
+
+            assert!(<$target>::zero().is_zero());
+            assert!(<$target>::one().is_one());
+            assert_eq!(<$target>::max_value(), <$target>::from($ty::MAX));
+            assert_eq!(<$target>::min_value(), <$target>::from($ty::MIN));
+            assert_eq!(<$target as Num>::from_str_radix("1.5", 10).ok(), Some(<$target>::from(1.5)));
+
+            assert_eq!(Signed::abs(&simd).into_array(), alpha.map(<$ty>::abs));
+            assert_eq!(Signed::signum(&simd).into_array(), alpha.map(<$ty>::signum));
+            assert!(Signed::is_positive(&Signed::abs(&simd)) && Signed::is_negative(&-Signed::abs(&simd)));
+        )*
+    }
+}
+
+macro_rules! test_approx {
+    ($([$ty:ident;$len:literal] as $target:ident),+) => {
+        $(
+            let alpha : [$ty;$len] = random::<[$ty;$len]>().map(|x| 100. * x + 1.);
+            let simd = <$target>::new(alpha);
+            let next = <$target>::new(alpha.map(|x| <$ty>::from_bits(x.to_bits() + 2)));
+            let far = simd + 0.5;
+
+            assert!(simd.abs_diff_eq(&far, 0.75) && !simd.abs_diff_eq(&far, 0.25));
+            assert!(simd.relative_eq(&next, 0., 4. * $ty::EPSILON) && !simd.relative_eq(&far, 0., 4. * $ty::EPSILON));
+            assert!(simd.ulps_eq(&next, 0., 2) && !simd.ulps_eq(&next, 0., 1));
+            assert!(!<$target>::from($ty::NAN).abs_diff_eq(&<$target>::from($ty::NAN), $ty::INFINITY));
+            assert!(<$target>::from(0.).ulps_eq(&<$target>::from(-0.), 0., 0));
+            assert!(!<$target>::from($ty::NAN).ulps_eq(&<$target>::from($ty::NAN), 0., u32::MAX));
+
+            #[cfg(feature = "approx")]
+            {
+                approx::assert_ulps_eq!(simd, next, max_ulps = 2);
+                approx::assert_relative_eq!(simd, next, max_relative = 4. * $ty::EPSILON);
+                approx::assert_abs_diff_ne!(simd, far);
+            }
+        )*
+    }
+}
+
 test_other!(
     add, sub, mul, div, 
     min as vmin, max as vmax
@@ -303,6 +599,7 @@ test_mappings!(
 pub fn index () {
     test_index!(
         [f32;2] as f32x2,
+        [f32;3] as f32x3,
         [f32;4] as f32x4,
         [f32;6] as f32x6,
         [f32;8] as f32x8,
@@ -310,15 +607,20 @@ pub fn index () {
         [f32;12] as f32x12,
         [f32;14] as f32x14,
         [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
     
         [f64;2] as f64x2,
+        [f64;3] as f64x3,
         [f64;4] as f64x4,
         [f64;6] as f64x6,
         [f64;8] as f64x8,
         [f64;10] as f64x10,
         [f64;12] as f64x12,
         [f64;14] as f64x14,
-        [f64;16] as f64x16
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
     );
 }
 
@@ -326,6 +628,7 @@ pub fn index () {
 pub fn eq () {
     test_eq!(
         [f32;2] as f32x2,
+        [f32;3] as f32x3,
         [f32;4] as f32x4,
         [f32;6] as f32x6,
         [f32;8] as f32x8,
@@ -333,15 +636,20 @@ pub fn eq () {
         [f32;12] as f32x12,
         [f32;14] as f32x14,
         [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
     
         [f64;2] as f64x2,
+        [f64;3] as f64x3,
         [f64;4] as f64x4,
         [f64;6] as f64x6,
         [f64;8] as f64x8,
         [f64;10] as f64x10,
         [f64;12] as f64x12,
         [f64;14] as f64x14,
-        [f64;16] as f64x16
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
     );
 }
 
@@ -350,6 +658,7 @@ pub fn eq () {
 pub fn rnd () {
     test_rand!(
         [f32;2] as f32x2,
+        [f32;3] as f32x3,
         [f32;4] as f32x4,
         [f32;6] as f32x6,
         [f32;8] as f32x8,
@@ -357,15 +666,20 @@ pub fn rnd () {
         [f32;12] as f32x12,
         [f32;14] as f32x14,
         [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
     
         [f64;2] as f64x2,
+        [f64;3] as f64x3,
         [f64;4] as f64x4,
         [f64;6] as f64x6,
         [f64;8] as f64x8,
         [f64;10] as f64x10,
         [f64;12] as f64x12,
         [f64;14] as f64x14,
-        [f64;16] as f64x16
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
     );
 }
 
@@ -374,6 +688,7 @@ pub fn rnd () {
 pub fn serialize () {
     test_serde!(
         [f32;2] as f32x2,
+        [f32;3] as f32x3,
         [f32;4] as f32x4,
         [f32;6] as f32x6,
         [f32;8] as f32x8,
@@ -381,15 +696,20 @@ pub fn serialize () {
         [f32;12] as f32x12,
         [f32;14] as f32x14,
         [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
     
         [f64;2] as f64x2,
+        [f64;3] as f64x3,
         [f64;4] as f64x4,
         [f64;6] as f64x6,
         [f64;8] as f64x8,
         [f64;10] as f64x10,
         [f64;12] as f64x12,
         [f64;14] as f64x14,
-        [f64;16] as f64x16
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
     );
 }
 
@@ -397,6 +717,7 @@ pub fn serialize () {
 pub fn clone () {
     test_clone!(
         [f32;2] as f32x2,
+        [f32;3] as f32x3,
         [f32;4] as f32x4,
         [f32;6] as f32x6,
         [f32;8] as f32x8,
@@ -404,15 +725,20 @@ pub fn clone () {
         [f32;12] as f32x12,
         [f32;14] as f32x14,
         [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
     
         [f64;2] as f64x2,
+        [f64;3] as f64x3,
         [f64;4] as f64x4,
         [f64;6] as f64x6,
         [f64;8] as f64x8,
         [f64;10] as f64x10,
         [f64;12] as f64x12,
         [f64;14] as f64x14,
-        [f64;16] as f64x16
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
     );
 }
 
@@ -420,6 +746,7 @@ pub fn clone () {
 pub fn into () {
     test_into!(
         [f32;2] as f32x2,
+        [f32;3] as f32x3,
         [f32;4] as f32x4,
         [f32;6] as f32x6,
         [f32;8] as f32x8,
@@ -427,15 +754,20 @@ pub fn into () {
         [f32;12] as f32x12,
         [f32;14] as f32x14,
         [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
     
         [f64;2] as f64x2,
+        [f64;3] as f64x3,
         [f64;4] as f64x4,
         [f64;6] as f64x6,
         [f64;8] as f64x8,
         [f64;10] as f64x10,
         [f64;12] as f64x12,
         [f64;14] as f64x14,
-        [f64;16] as f64x16
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
     );
 }
 
@@ -443,6 +775,7 @@ pub fn into () {
 pub fn from () {
     test_from!(
         [f32;2] as f32x2,
+        [f32;3] as f32x3,
         [f32;4] as f32x4,
         [f32;6] as f32x6,
         [f32;8] as f32x8,
@@ -450,15 +783,20 @@ pub fn from () {
         [f32;12] as f32x12,
         [f32;14] as f32x14,
         [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
     
         [f64;2] as f64x2,
+        [f64;3] as f64x3,
         [f64;4] as f64x4,
         [f64;6] as f64x6,
         [f64;8] as f64x8,
         [f64;10] as f64x10,
         [f64;12] as f64x12,
         [f64;14] as f64x14,
-        [f64;16] as f64x16
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
     );
 }
 
@@ -466,6 +804,7 @@ pub fn from () {
 pub fn zip () {
     test_transpose!(
         [f32;2] as f32x2,
+        [f32;3] as f32x3,
         [f32;4] as f32x4,
         [f32;6] as f32x6,
         [f32;8] as f32x8,
@@ -473,15 +812,20 @@ pub fn zip () {
         [f32;12] as f32x12,
         [f32;14] as f32x14,
         [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
     
         [f64;2] as f64x2,
+        [f64;3] as f64x3,
         [f64;4] as f64x4,
         [f64;6] as f64x6,
         [f64;8] as f64x8,
         [f64;10] as f64x10,
         [f64;12] as f64x12,
         [f64;14] as f64x14,
-        [f64;16] as f64x16
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
     );
 }
 
@@ -489,6 +833,7 @@ pub fn zip () {
 pub fn fma () {
     test_mul_add!(
         [f32;2] as f32x2,
+        [f32;3] as f32x3,
         [f32;4] as f32x4,
         [f32;6] as f32x6,
         [f32;8] as f32x8,
@@ -496,14 +841,989 @@ pub fn fma () {
         [f32;12] as f32x12,
         [f32;14] as f32x14,
         [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
     
         [f64;2] as f64x2,
+        [f64;3] as f64x3,
         [f64;4] as f64x4,
         [f64;6] as f64x6,
         [f64;8] as f64x8,
         [f64;10] as f64x10,
         [f64;12] as f64x12,
         [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+}
+
+#[test]
+pub fn interp () {
+    test_interp!(
+        [f32;2] as f32x2,
+        [f32;3] as f32x3,
+        [f32;4] as f32x4,
+        [f32;6] as f32x6,
+        [f32;8] as f32x8,
+        [f32;10] as f32x10,
+        [f32;12] as f32x12,
+        [f32;14] as f32x14,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+    
+        [f64;2] as f64x2,
+        [f64;3] as f64x3,
+        [f64;4] as f64x4,
+        [f64;6] as f64x6,
+        [f64;8] as f64x8,
+        [f64;10] as f64x10,
+        [f64;12] as f64x12,
+        [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+}
+
+#[test]
+pub fn euclid () {
+    test_euclid!(
+        [f32;2] as f32x2,
+        [f32;3] as f32x3,
+        [f32;4] as f32x4,
+        [f32;6] as f32x6,
+        [f32;8] as f32x8,
+        [f32;10] as f32x10,
+        [f32;12] as f32x12,
+        [f32;14] as f32x14,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+    
+        [f64;2] as f64x2,
+        [f64;3] as f64x3,
+        [f64;4] as f64x4,
+        [f64;6] as f64x6,
+        [f64;8] as f64x8,
+        [f64;10] as f64x10,
+        [f64;12] as f64x12,
+        [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+}
+
+#[test]
+pub fn fmt () {
+    test_fmt!(
+        [f32;2] as f32x2,
+        [f32;3] as f32x3,
+        [f32;4] as f32x4,
+        [f32;6] as f32x6,
+        [f32;8] as f32x8,
+        [f32;10] as f32x10,
+        [f32;12] as f32x12,
+        [f32;14] as f32x14,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+    
+        [f64;2] as f64x2,
+        [f64;3] as f64x3,
+        [f64;4] as f64x4,
+        [f64;6] as f64x6,
+        [f64;8] as f64x8,
+        [f64;10] as f64x10,
+        [f64;12] as f64x12,
+        [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+}
+
+#[test]
+pub fn cmp () {
+    test_cmp!(
+        [f32;2] as f32x2,
+        [f32;3] as f32x3,
+        [f32;4] as f32x4,
+        [f32;6] as f32x6,
+        [f32;8] as f32x8,
+        [f32;10] as f32x10,
+        [f32;12] as f32x12,
+        [f32;14] as f32x14,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+    
+        [f64;2] as f64x2,
+        [f64;3] as f64x3,
+        [f64;4] as f64x4,
+        [f64;6] as f64x6,
+        [f64;8] as f64x8,
+        [f64;10] as f64x10,
+        [f64;12] as f64x12,
+        [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+pub fn bytemuck () {
+    test_bytemuck!(
+        [f32;4] as f32x4,
+        [f32;8] as f32x8,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+    
+        [f64;2] as f64x2,
+        [f64;4] as f64x4,
+        [f64;8] as f64x8,
+        [f64;12] as f64x12,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+pub fn num () {
+    use num_traits::{Zero, One, Signed, Bounded, Num};
+
+    test_num!(
+        [f32;2] as f32x2,
+        [f32;3] as f32x3,
+        [f32;4] as f32x4,
+        [f32;6] as f32x6,
+        [f32;8] as f32x8,
+        [f32;10] as f32x10,
+        [f32;12] as f32x12,
+        [f32;14] as f32x14,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+    
+        [f64;2] as f64x2,
+        [f64;3] as f64x3,
+        [f64;4] as f64x4,
+        [f64;6] as f64x6,
+        [f64;8] as f64x8,
+        [f64;10] as f64x10,
+        [f64;12] as f64x12,
+        [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+}
+
+#[test]
+pub fn approx () {
+    test_approx!(
+        [f32;2] as f32x2,
+        [f32;3] as f32x3,
+        [f32;4] as f32x4,
+        [f32;6] as f32x6,
+        [f32;8] as f32x8,
+        [f32;10] as f32x10,
+        [f32;12] as f32x12,
+        [f32;14] as f32x14,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+    
+        [f64;2] as f64x2,
+        [f64;3] as f64x3,
+        [f64;4] as f64x4,
+        [f64;6] as f64x6,
+        [f64;8] as f64x8,
+        [f64;10] as f64x10,
+        [f64;12] as f64x12,
+        [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+}
+
+#[test]
+pub fn padding () {
+    let alpha = f32x3::new([1., 2., 3.]) / f32x3::new([1., 1., 1.]);
+    assert_eq!(alpha.sum(), 6.);
+    assert_eq!(alpha.prod(), 6.);
+    assert_eq!(alpha.min(), 1.);
+    assert_eq!(alpha.max(), 3.);
+    assert_eq!(alpha, f32x3::new([1., 2., 3.]));
+    assert_eq!(-f32x3::new([-1., -2., -3.]), alpha);
+
+    let beta = f64x3::new([1., 2., 3.]) / f64x3::new([1., 1., 1.]);
+    assert_eq!(beta.sum(), 6.);
+    assert_eq!(beta.prod(), 6.);
+    assert_eq!(beta.min(), 1.);
+    assert_eq!(beta.max(), 3.);
+    assert_eq!(beta, f64x3::new([1., 2., 3.]));
+    assert_eq!(-f64x3::new([-1., -2., -3.]), beta);
+}
+
+#[test]
+pub fn half () {
+    use llml_simd::float::half::*;
+
+    let alpha = f16x4::from_bits([0x3c00, 0x7bff, 0x0001, 0x8000]).to_f32();
+    assert_eq!(alpha.into_array(), [1., 65504., 2f32.powi(-24), -0.]);
+    assert!(f16x4::from_bits([0x7e00, 0, 0, 0]).to_f32()[0].is_nan());
+
+    let beta = f16x4::from_f32(f32x4::new([65520., 1. + 2f32.powi(-11), 2f32.powi(-25), f32::NAN])).to_bits();
+    assert_eq!(&beta[..3], &[0x7c00, 0x3c00, 0x0000]);
+    assert_eq!(beta[3] & 0x7c00, 0x7c00);
+    assert_ne!(beta[3] & 0x3ff, 0);
+
+    let gamma = bf16x8::from_f32(f32x8::new([1., 1. + 2f32.powi(-8), 1. + 3. * 2f32.powi(-8), -2., f32::INFINITY, 0., 3.0e38, -0.]));
+    assert_eq!(gamma.to_bits(), [0x3f80, 0x3f80, 0x3f82, 0xc000, 0x7f80, 0x0000, 0x7f62, 0x8000]);
+
+    // every non-NaN half-precision value survives a round trip
+    for bits in 0..=u16::MAX {
+        if bits & 0x7c00 == 0x7c00 && bits & 0x3ff != 0 {
+            continue;
+        }
+
+        let half = f16x8::from_bits([bits; 8]);
+        assert_eq!(f16x8::from_f32(half.to_f32()), half);
+    }
+
+    let values : Vec<f32> = (0..19).map(|i| i as f32 * 0.25 - 2.).collect();
+    let mut bits = vec![0; values.len()];
+    let mut result = vec![0.; values.len()];
+
+    f32_to_f16(&values, &mut bits);
+    f16_to_f32(&bits, &mut result);
+    assert_eq!(values, result);
+
+    f32_to_bf16(&values, &mut bits);
+    bf16_to_f32(&bits, &mut result);
+    assert_eq!(values, result);
+}
+
+#[test]
+pub fn int () {
+    use llml_simd::int::*;
+    use core::array::from_fn;
+
+    macro_rules! test_int {
+        ($([$ty:ident;$len:literal] as $target:ident),+) => {
+            $(
+                let alpha : [$ty;$len] = random();
+                let beta : [$ty;$len] = random();
+                let (a, b) = (<$target>::new(alpha), <$target>::new(beta));
+
+                assert_eq!((a + b).into_array(), from_fn::<_, $len, _>(|i| alpha[i].wrapping_add(beta[i])), "{}", stringify!($target));
+                assert_eq!((a - b).into_array(), from_fn::<_, $len, _>(|i| alpha[i].wrapping_sub(beta[i])), "{}", stringify!($target));
+                assert_eq!((a & b).into_array(), from_fn::<_, $len, _>(|i| alpha[i] & beta[i]), "{}", stringify!($target));
+                assert_eq!((a | b).into_array(), from_fn::<_, $len, _>(|i| alpha[i] | beta[i]), "{}", stringify!($target));
+                assert_eq!((a ^ b).into_array(), from_fn::<_, $len, _>(|i| alpha[i] ^ beta[i]), "{}", stringify!($target));
+                assert_eq!((!a).into_array(), alpha.map(|x| !x), "{}", stringify!($target));
+                assert_eq!(a.vmin(b).into_array(), from_fn::<_, $len, _>(|i| alpha[i].min(beta[i])), "{}", stringify!($target));
+                assert_eq!(a.vmax(b).into_array(), from_fn::<_, $len, _>(|i| alpha[i].max(beta[i])), "{}", stringify!($target));
+                assert_eq!((a + beta[0]).into_array(), from_fn::<_, $len, _>(|i| alpha[i].wrapping_add(beta[0])), "{}", stringify!($target));
+                assert_eq!(a[$len - 1], alpha[$len - 1]);
+                assert_eq!(a, <$target>::new(alpha));
+            )*
+        };
+
+        (@small $([$ty:ident;$len:literal] as $target:ident => $wty:ident),+) => {
+            $(
+                let alpha : [$ty;$len] = random();
+                let beta : [$ty;$len] = random();
+                let (a, b) = (<$target>::new(alpha), <$target>::new(beta));
+                let bits = 8 * core::mem::size_of::<$ty>();
+
+                assert_eq!(a.saturating_add(b).into_array(), from_fn::<_, $len, _>(|i| alpha[i].saturating_add(beta[i])), "{}", stringify!($target));
+                assert_eq!(a.saturating_sub(b).into_array(), from_fn::<_, $len, _>(|i| alpha[i].saturating_sub(beta[i])), "{}", stringify!($target));
+                assert_eq!(a.mulhi(b).into_array(), from_fn::<_, $len, _>(|i| ((alpha[i] as $wty * beta[i] as $wty) >> bits) as $ty), "{}", stringify!($target));
+                assert_eq!(a.avg(b).into_array(), from_fn::<_, $len, _>(|i| ((alpha[i] as $wty + beta[i] as $wty + 1) >> 1) as $ty), "{}", stringify!($target));
+
+                let (lo, hi) = a.widen();
+                let wide : Vec<$wty> = lo.into_array().into_iter().chain(hi.into_array()).collect();
+                assert_eq!(wide, alpha.map(|x| x as $wty), "{}", stringify!($target));
+
+                let float : Vec<f32> = a.to_f32().into_iter().flat_map(|x| x.into_array()).collect();
+                assert_eq!(float, alpha.map(|x| x as f32), "{}", stringify!($target));
+
+                let narrow = lo.narrow(hi);
+                assert_eq!(narrow, a, "{}", stringify!($target));
+            )*
+        };
+
+        (@narrow $([$ty:ident;$len:literal] as $target:ident => $nty:ident),+) => {
+            $(
+                let alpha : [$ty;$len] = random::<[$ty;$len]>().map(|x| x >> (4 * core::mem::size_of::<$ty>() - 2));
+                let beta : [$ty;$len] = random();
+                let (a, b) = (<$target>::new(alpha), <$target>::new(beta));
+
+                let narrow = a.narrow(b).into_array();
+                let expected : Vec<$nty> = alpha.into_iter().chain(beta).map(|x| x.clamp($nty::MIN as $ty, $nty::MAX as $ty) as $nty).collect();
+                assert_eq!(&narrow[..], &expected[..], "{}", stringify!($target));
+            )*
+        };
+
+        (@float $([$ty:ident;$len:literal] as $target:ident),+) => {
+            $(
+                let alpha : [$ty;$len] = random();
+                assert_eq!(<$target>::new(alpha).to_f32().into_array(), alpha.map(|x| x as f32), "{}", stringify!($target));
+            )*
+        };
+    }
+
+    test_int!(
+        [i8;16] as i8x16,
+        [u8;16] as u8x16,
+        [i16;8] as i16x8,
+        [u16;8] as u16x8,
+        [i32;4] as i32x4,
+        [u32;4] as u32x4,
+        [i8;32] as i8x32,
+        [u8;32] as u8x32,
+        [i16;16] as i16x16,
+        [u16;16] as u16x16,
+        [i32;8] as i32x8,
+        [u32;8] as u32x8
+    );
+
+    test_int!(
+        @small
+        [i8;16] as i8x16 => i16,
+        [u8;16] as u8x16 => u16,
+        [i16;8] as i16x8 => i32,
+        [u16;8] as u16x8 => u32,
+        [i8;32] as i8x32 => i16,
+        [u8;32] as u8x32 => u16,
+        [i16;16] as i16x16 => i32,
+        [u16;16] as u16x16 => u32
+    );
+
+    test_int!(
+        @narrow
+        [i16;8] as i16x8 => i8,
+        [u16;8] as u16x8 => u8,
+        [i32;4] as i32x4 => i16,
+        [u32;4] as u32x4 => u16,
+        [i16;16] as i16x16 => i8,
+        [u16;16] as u16x16 => u8,
+        [i32;8] as i32x8 => i16,
+        [u32;8] as u32x8 => u16
+    );
+
+    test_int!(
+        @float
+        [i32;4] as i32x4,
+        [u32;4] as u32x4,
+        [i32;8] as i32x8,
+        [u32;8] as u32x8
+    );
+
+    let extremes = i16x8::new([i16::MIN, i16::MAX, -1, 0, 1, i16::MIN, i16::MAX, 0]);
+    assert_eq!(extremes.saturating_add(i16x8::from(i16::MAX)).into_array(), [-1, i16::MAX, i16::MAX - 1, i16::MAX, i16::MAX, -1, i16::MAX, i16::MAX]);
+    assert_eq!(extremes.mulhi(extremes).into_array(), [0x4000, 0x3fff, 0, 0, 0, 0x4000, 0x3fff, 0]);
+    assert_eq!(u32x4::new([u32::MAX, 1 << 31, 16777217, 0]).to_f32().into_array(), [u32::MAX as f32, (1u32 << 31) as f32, 16777217u32 as f32, 0.]);
+}
+
+#[test]
+pub fn int64 () {
+    use llml_simd::int::*;
+    use core::array::from_fn;
+
+    macro_rules! test_int64 {
+        ($([$ty:ident;$len:literal] as $target:ident => $float:ident),+) => {
+            $(
+                let alpha : [$ty;$len] = random();
+                let beta : [$ty;$len] = random();
+                let shifts : [u32;$len] = random::<[u32;$len]>().map(|x| x % 64);
+                let (a, b) = (<$target>::new(alpha), <$target>::new(beta));
+
+                assert_eq!((a + b).into_array(), from_fn::<_, $len, _>(|i| alpha[i].wrapping_add(beta[i])), "{}", stringify!($target));
+                assert_eq!((a - b).into_array(), from_fn::<_, $len, _>(|i| alpha[i].wrapping_sub(beta[i])), "{}", stringify!($target));
+                assert_eq!((a * b).into_array(), from_fn::<_, $len, _>(|i| alpha[i].wrapping_mul(beta[i])), "{}", stringify!($target));
+                assert_eq!((a * beta[0]).into_array(), from_fn::<_, $len, _>(|i| alpha[i].wrapping_mul(beta[0])), "{}", stringify!($target));
+                assert_eq!((a & b).into_array(), from_fn::<_, $len, _>(|i| alpha[i] & beta[i]), "{}", stringify!($target));
+                assert_eq!((a | b).into_array(), from_fn::<_, $len, _>(|i| alpha[i] | beta[i]), "{}", stringify!($target));
+                assert_eq!((a ^ b).into_array(), from_fn::<_, $len, _>(|i| alpha[i] ^ beta[i]), "{}", stringify!($target));
+                assert_eq!((!a).into_array(), alpha.map(|x| !x), "{}", stringify!($target));
+
+                assert_eq!((a << shifts[0]).into_array(), alpha.map(|x| x << shifts[0]), "{}", stringify!($target));
+                assert_eq!((a >> shifts[0]).into_array(), alpha.map(|x| x >> shifts[0]), "{}", stringify!($target));
+                assert_eq!((a << 64).into_array(), alpha, "{}", stringify!($target));
+
+                let amounts = <$target>::new(shifts.map(|x| x as $ty));
+                assert_eq!((a << amounts).into_array(), from_fn::<_, $len, _>(|i| alpha[i] << shifts[i]), "{}", stringify!($target));
+                assert_eq!((a >> amounts).into_array(), from_fn::<_, $len, _>(|i| alpha[i] >> shifts[i]), "{}", stringify!($target));
+
+                let mut c = a;
+                c *= b;
+                c <<= 3;
+                c >>= amounts;
+                assert_eq!(c.into_array(), from_fn::<_, $len, _>(|i| (alpha[i].wrapping_mul(beta[i]) << 3) >> shifts[i]), "{}", stringify!($target));
+
+                // half of the lanes are made equal
+                let b = <$target>::new(from_fn(|i| if i % 2 == 0 { alpha[i] } else { beta[i] }));
+                let beta = b.into_array();
+                let mask = |x: bool| if x { !0 } else { 0 };
+                assert_eq!(a.veq(b).into_array(), from_fn::<_, $len, _>(|i| mask(alpha[i] == beta[i])), "{}", stringify!($target));
+                assert_eq!(a.vne(b).into_array(), from_fn::<_, $len, _>(|i| mask(alpha[i] != beta[i])), "{}", stringify!($target));
+                assert_eq!(a.vlt(b).into_array(), from_fn::<_, $len, _>(|i| mask(alpha[i] < beta[i])), "{}", stringify!($target));
+                assert_eq!(a.vle(b).into_array(), from_fn::<_, $len, _>(|i| mask(alpha[i] <= beta[i])), "{}", stringify!($target));
+                assert_eq!(a.vgt(b).into_array(), from_fn::<_, $len, _>(|i| mask(alpha[i] > beta[i])), "{}", stringify!($target));
+                assert_eq!(a.vge(b).into_array(), from_fn::<_, $len, _>(|i| mask(alpha[i] >= beta[i])), "{}", stringify!($target));
+                assert_eq!(a.vmin(b).into_array(), from_fn::<_, $len, _>(|i| alpha[i].min(beta[i])), "{}", stringify!($target));
+                assert_eq!(a.vmax(b).into_array(), from_fn::<_, $len, _>(|i| alpha[i].max(beta[i])), "{}", stringify!($target));
+
+                assert_eq!(a.to_f64().into_array(), alpha.map(|x| x as f64), "{}", stringify!($target));
+                let floats : [f64;$len] = random::<[f64;$len]>().map(|x| 1e20 * (x - 0.5));
+                assert_eq!(<$target>::from_f64(<$float>::new(floats)).into_array(), floats.map(|x| x as $ty), "{}", stringify!($target));
+            )*
+        };
+    }
+
+    test_int64!(
+        [i64;2] as i64x2 => f64x2,
+        [u64;2] as u64x2 => f64x2,
+        [i64;4] as i64x4 => f64x4,
+        [u64;4] as u64x4 => f64x4,
+        [i64;8] as i64x8 => f64x8,
+        [u64;8] as u64x8 => f64x8,
+        [i64;16] as i64x16 => f64x16,
+        [u64;16] as u64x16 => f64x16
+    );
+
+    let extremes = i64x2::new([i64::MIN, -1]);
+    assert_eq!((extremes >> 63).into_array(), [-1, -1]);
+    assert_eq!(extremes.vlt(i64x2::new([i64::MAX, 0])).into_array(), [-1, -1]);
+    assert_eq!(u64x2::new([u64::MAX, 1 << 63]).vgt(u64x2::new([0, 1])).into_array(), [u64::MAX, u64::MAX]);
+    assert_eq!(i64x2::from_f64(f64x2::new([f64::NAN, 1e300])).into_array(), [0, i64::MAX]);
+}
+
+#[test]
+pub fn sort () {
+    macro_rules! test_sort {
+        ($([$ty:ident;$len:literal] as $target:ident),+) => {
+            $(
+                let alpha : [$ty;$len] = random::<[$ty;$len]>().map(|x| 2. * x - 1.);
+                let mut sorted = alpha;
+                sorted.sort_by(|a, b| a.total_cmp(b));
+
+                let vector = <$target>::new(alpha);
+                assert_eq!(vector.sort_lanes().into_array(), sorted, "{}", stringify!($target));
+                assert_eq!(vector.sort_lanes_total().into_array(), sorted, "{}", stringify!($target));
+                assert_eq!(vector.partial_sort::<3>(), sorted[..3], "{}", stringify!($target));
+                assert_eq!(vector.median(), (sorted[$len / 2 - 1] + sorted[$len / 2]) / 2., "{}", stringify!($target));
+
+                sorted.reverse();
+                assert_eq!(vector.sort_lanes_desc().into_array(), sorted, "{}", stringify!($target));
+                assert_eq!(vector.sort_lanes_total_desc().into_array(), sorted, "{}", stringify!($target));
+
+                let mut special = alpha;
+                special[0] = $ty::NAN;
+                special[1] = -$ty::NAN;
+                special[2] = $ty::NEG_INFINITY;
+                special[3] = -0.;
+                let mut sorted = special;
+                sorted.sort_by(|a, b| a.total_cmp(b));
+
+                let result = <$target>::new(special).sort_lanes_total().into_array();
+                assert_eq!(result.map(|x| x.to_bits()), sorted.map(|x| x.to_bits()), "{}", stringify!($target));
+
+                // vectors with `NaN` lanes are still a permutation of the original one
+                let result = <$target>::new(special).sort_lanes().into_array();
+                assert_eq!(result.map(|x| x.to_bits()), sorted.map(|x| x.to_bits()), "{}", stringify!($target));
+            )*
+        };
+    }
+
+    test_sort!(
+        [f32;4] as f32x4,
+        [f32;8] as f32x8,
+        [f32;16] as f32x16,
+        [f64;4] as f64x4,
+        [f64;8] as f64x8,
         [f64;16] as f64x16
     );
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn scan () {
+    macro_rules! test_scan {
+        ($([$ty:ident;$len:literal] as $target:ident),+) => {
+            $(
+                let alpha : [$ty;$len] = random::<[$ty;$len]>().map(|x| (16. * x - 8.).round());
+                let vector = <$target>::new(alpha);
+
+                // products of powers of two are exact in any order
+                let powers = alpha.map(|x| if x < 0. { -0.5 } else { 2. });
+                let mut sums = alpha;
+                let mut prods = powers;
+                let mut maxs = alpha;
+                let mut mins = alpha;
+                for i in 1..$len {
+                    sums[i] += sums[i - 1];
+                    prods[i] *= prods[i - 1];
+                    maxs[i] = maxs[i].max(maxs[i - 1]);
+                    mins[i] = mins[i].min(mins[i - 1]);
+                }
+
+                assert_eq!(vector.prefix_sum().into_array(), sums, "{}", stringify!($target));
+                assert_eq!(<$target>::new(powers).prefix_prod().into_array(), prods, "{}", stringify!($target));
+                assert_eq!(vector.prefix_max().into_array(), maxs, "{}", stringify!($target));
+                assert_eq!(vector.prefix_min().into_array(), mins, "{}", stringify!($target));
+
+                let exclusive = vector.prefix_sum_exclusive().into_array();
+                assert_eq!(exclusive[0], 0., "{}", stringify!($target));
+                assert_eq!(exclusive[1..], sums[..$len - 1], "{}", stringify!($target));
+
+                let mut values : Vec<$ty> = (0..3 * $len + 1).map(|i| (i % 7) as $ty).collect();
+                let expected : Vec<$ty> = values.iter().scan(0., |acc, x| { *acc += x; Some(*acc) }).collect();
+                assert_eq!(<$target>::cumsum(&mut values), expected[expected.len() - 1], "{}", stringify!($target));
+                assert_eq!(values, expected, "{}", stringify!($target));
+            )*
+        };
+    }
+
+    test_scan!(
+        [f32;2] as f32x2,
+        [f32;3] as f32x3,
+        [f32;4] as f32x4,
+        [f32;6] as f32x6,
+        [f32;8] as f32x8,
+        [f32;10] as f32x10,
+        [f32;12] as f32x12,
+        [f32;14] as f32x14,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+        [f64;2] as f64x2,
+        [f64;3] as f64x3,
+        [f64;4] as f64x4,
+        [f64;6] as f64x6,
+        [f64;8] as f64x8,
+        [f64;10] as f64x10,
+        [f64;12] as f64x12,
+        [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+
+    use llml_simd::int::*;
+    let ints = i16x8::new([3, -1, 4, -1, 5, -9, 2, 6]);
+    assert_eq!(ints.prefix_sum().into_array(), [3, 2, 6, 5, 10, 1, 3, 9]);
+    assert_eq!(ints.prefix_max().into_array(), [3, 3, 4, 4, 5, 5, 5, 6]);
+    assert_eq!(ints.prefix_min().into_array(), [3, -1, -1, -1, -1, -9, -9, -9]);
+    assert_eq!(u64x4::new([1, 2, 3, 4]).prefix_prod().into_array(), [1, 2, 6, 24]);
+    assert_eq!(u8x16::from(200).prefix_sum()[1], 144);
+}
+
+#[test]
+pub fn compensated () {
+    macro_rules! test_compensated {
+        ($([$ty:ident;$len:literal] as $target:ident),+) => {
+            $(
+                // every value is exact, so the true sums are known
+                let alpha : [$ty;$len] = random::<[$ty;$len]>().map(|x| (16. * x - 8.).round());
+                let expected : $ty = alpha.iter().sum();
+                let vector = <$target>::new(alpha);
+                assert_eq!(vector.sum_kahan(), expected, "{}", stringify!($target));
+                assert_eq!(vector.sum_pairwise(), expected, "{}", stringify!($target));
+
+                // a big value followed by many small ones, which a regular sum rounds away
+                let mut values : Vec<$ty> = vec![0.1; 10_000 * $len + 3];
+                values[0] = 1e6;
+                let naive = values.iter().map(|x| *x as f64).sum::<f64>();
+                let simd = <$target>::compensated_sum(&values) as f64;
+                assert!((simd - naive).abs() <= naive * $ty::EPSILON as f64 * 2., "{}: {} != {}", stringify!($target), simd, naive);
+
+                let ones : Vec<$ty> = vec![1.; values.len()];
+                assert_eq!(<$target>::compensated_dot(&values, &ones), <$target>::compensated_sum(&values), "{}", stringify!($target));
+
+                // infinities and NaN propagate like in a regular sum, instead of turning the compensation into NaN
+                let mut alpha = alpha;
+                alpha[0] = $ty::INFINITY;
+                assert_eq!(<$target>::new(alpha).sum_kahan(), $ty::INFINITY, "{}", stringify!($target));
+                alpha[0] = $ty::NEG_INFINITY;
+                assert_eq!(<$target>::new(alpha).sum_kahan(), $ty::NEG_INFINITY, "{}", stringify!($target));
+                alpha[$len - 1] = $ty::INFINITY;
+                assert!(<$target>::new(alpha).sum_kahan().is_nan(), "{}", stringify!($target));
+                alpha[0] = $ty::NAN;
+                assert!(<$target>::new(alpha).sum_kahan().is_nan(), "{}", stringify!($target));
+
+                values[1] = $ty::INFINITY;
+                assert_eq!(<$target>::compensated_sum(&values), $ty::INFINITY, "{}", stringify!($target));
+                values[2] = $ty::NEG_INFINITY;
+                assert!(<$target>::compensated_sum(&values).is_nan(), "{}", stringify!($target));
+                values[1] = $ty::NAN;
+                assert!(<$target>::compensated_dot(&values, &ones).is_nan(), "{}", stringify!($target));
+            )*
+        };
+    }
+
+    test_compensated!(
+        [f32;2] as f32x2,
+        [f32;3] as f32x3,
+        [f32;4] as f32x4,
+        [f32;6] as f32x6,
+        [f32;8] as f32x8,
+        [f32;10] as f32x10,
+        [f32;12] as f32x12,
+        [f32;14] as f32x14,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+        [f64;2] as f64x2,
+        [f64;3] as f64x3,
+        [f64;4] as f64x4,
+        [f64;6] as f64x6,
+        [f64;8] as f64x8,
+        [f64;10] as f64x10,
+        [f64;12] as f64x12,
+        [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+
+    let values = vec![1e8f32, 1., -1e8, 1.];
+    assert_eq!(f32x2::compensated_sum(&values), 2.);
+    assert_eq!(f32x4::new([1e8, 1., -1e8, 1.]).sum_kahan(), 2.);
+}
+
+#[test]
+pub fn poly () {
+    macro_rules! test_poly {
+        ($([$ty:ident;$len:literal] as $target:ident),+) => {
+            $(
+                let alpha : [$ty;$len] = random::<[$ty;$len]>().map(|x| 2. * x - 1.);
+                let coeffs : [$ty;7] = random::<[$ty;7]>().map(|x| 2. * x - 1.);
+                let simd = <$target>::new(alpha);
+
+                let horner = simd.poly_eval(&coeffs);
+                let estrin = simd.poly(coeffs);
+                for i in 0..$len {
+                    let naive = coeffs.iter().rev().fold(0., |acc, c| acc * alpha[i] + c);
+                    assert!((horner[i] - naive).abs() <= $ty::EPSILON * 16., "Horner for {}", stringify!($target));
+                    assert!((estrin[i] - naive).abs() <= $ty::EPSILON * 16., "Estrin for {}", stringify!($target));
+                }
+
+                assert_eq!(simd.poly_eval(&[]), <$target>::from(0.));
+                assert_eq!(simd.poly([]), <$target>::from(0.));
+                assert_eq!(simd.poly([2.]), <$target>::from(2.));
+                assert_eq!(<$target>::from(3.).poly_eval(&[1., 0., 2.]), <$target>::from(19.));
+                assert_eq!(<$target>::from(3.).poly([1., 0., 2., 1.]), <$target>::from(46.));
+            )*
+        };
+    }
+
+    test_poly!(
+        [f32;2] as f32x2,
+        [f32;3] as f32x3,
+        [f32;4] as f32x4,
+        [f32;6] as f32x6,
+        [f32;8] as f32x8,
+        [f32;10] as f32x10,
+        [f32;12] as f32x12,
+        [f32;14] as f32x14,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+        [f64;2] as f64x2,
+        [f64;3] as f64x3,
+        [f64;4] as f64x4,
+        [f64;6] as f64x6,
+        [f64;8] as f64x8,
+        [f64;10] as f64x10,
+        [f64;12] as f64x12,
+        [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+}
+
+#[test]
+pub fn soa () {
+    use llml_simd::soa::*;
+
+    let points : Vec<[f32;3]> = (0..19).map(|i| [i as f32, 2. * i as f32, 1.]).collect();
+    let mut soa : SoaVec3 = points.iter().copied().collect();
+    assert_eq!(soa.len(), 19);
+    assert_eq!(soa.get(5), Some([5., 10., 1.]));
+    assert_eq!(soa.get(19), None);
+    assert_eq!(soa.y(), &points.iter().map(|p| p[1]).collect::<Vec<_>>()[..]);
+
+    let bundles : Vec<_> = soa.chunks().collect();
+    assert_eq!(bundles.len(), 3);
+    assert_eq!(bundles[2].0.into_array(), [16., 17., 18., 0., 0., 0., 0., 0.]);
+    assert_eq!(bundles[1].2, f32x8::from(1.));
+
+    soa.translate([1., -1., 0.]);
+    soa.scale([2., 1., 3.]);
+    assert_eq!(soa.get(3), Some([8., 5., 3.]));
+    assert_eq!(soa.chunks().last().unwrap().0.into_array()[3..], [0.; 5]);
+
+    soa.apply_matrix([[0., 1., 0.], [1., 0., 0.], [0., 0., 2.]]);
+    assert_eq!(soa.get(3), Some([5., 8., 6.]));
+
+    soa.set(0, [3., 0., 4.]);
+    soa.normalize();
+    assert_eq!(soa.get(0), Some([0.6, 0., 0.8]));
+    for i in 0..soa.len() {
+        let [x, y, z] = soa.get(i).unwrap();
+        assert!(((x * x + y * y + z * z) - 1.).abs() <= 1e-6);
+    }
+
+    let mut soa = SoaVec4::new();
+    soa.push([1., 2., 3., 4.]);
+    soa.apply_matrix([[1., 0., 0., 1.], [0., 1., 0., 0.], [0., 0., 1., 0.], [0., 0., 0., 1.]]);
+    assert_eq!(soa.get(0), Some([5., 2., 3., 4.]));
+    assert_eq!(soa.w(), &[4.]);
+
+    soa.clear();
+    assert!(soa.is_empty() && soa.chunks().next().is_none());
+}
+
+#[test]
+pub fn aligned () {
+    use llml_simd::aligned::*;
+
+    #[repr(C, align(64))]
+    struct Block([f64;32]);
+
+    let mut buffer : AlignedVec<f32, 64> = (0..19).map(|i| i as f32).collect();
+    assert_eq!(buffer.as_ptr() as usize % 64, 0);
+    assert_eq!(buffer.len(), 19);
+
+    let (chunks, rest) = buffer.as_vectors::<f32x8>();
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks[1].sum(), (8..16).sum::<i32>() as f32);
+    assert_eq!(rest, &[16., 17., 18.]);
+
+    let (chunks, _) = buffer.as_vectors_mut::<f32x4>();
+    chunks.iter_mut().for_each(|x| *x *= 2.);
+    assert_eq!(buffer[15], 30.);
+    assert_eq!(buffer[16], 16.);
+
+    buffer.extend_from_slice(&[1., 2.]);
+    assert_eq!(buffer.pop(), Some(2.));
+    assert_eq!(buffer.clone(), buffer);
+
+    buffer.clear();
+    assert!(buffer.is_empty() && buffer.as_vectors::<f32x8>().0.is_empty());
+
+    let mut block = Block([0.;32]);
+    let values = f64x8::new([1., 2., 3., 4., 5., 6., 7., 8.]);
+    unsafe {
+        values.store_aligned(block.0.as_mut_ptr());
+        assert_eq!(f64x8::load_aligned(block.0.as_ptr()), values);
+
+        let values = f32x6::new([1., 2., 3., 4., 5., 6.]);
+        values.store_aligned(block.0.as_mut_ptr().cast());
+        assert_eq!(f32x6::load_aligned(block.0.as_ptr().cast()), values);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+pub fn par () {
+    use llml_simd::par::*;
+
+    let x : Vec<f32> = (0..100_003).map(|i| (i % 7) as f32).collect();
+    let y : Vec<f32> = (0..100_003).map(|i| (i % 5) as f32 - 2.).collect();
+
+    assert_eq!(x.par_sum(), x.iter().sum::<f32>());
+
+    // the partial sums of non-integer values are combined in the same order, whatever the number of threads
+    let w : Vec<f32> = (0..100_003).map(|i| 1. / (i as f32 + 1.)).collect();
+    let [single, multi] = [1, 8].map(|threads| {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        pool.install(|| (w.par_sum_deterministic().to_bits(), w.par_dot_deterministic(&y).to_bits()))
+    });
+    assert_eq!(single, multi);
+
+    assert_eq!(x.par_dot(&y), x.iter().zip(&y).map(|(x, y)| x * y).sum::<f32>());
+    assert_eq!(y.par_min(), Some(-2.));
+    assert_eq!(x.par_max(), Some(6.));
+    assert_eq!(Vec::<f64>::new().par_min(), None);
+
+    let mut dst = vec![0.; x.len()];
+    x.par_map(&mut dst, |x| x * 2. + 1.);
+    assert!(dst.iter().zip(&x).all(|(d, x)| *d == x * 2. + 1.));
+
+    x.par_zip(&y, &mut dst, |x, y| x - y);
+    assert!(dst.iter().zip(x.iter().zip(&y)).all(|(d, (x, y))| *d == x - y));
+
+    let mut z : Vec<f64> = (0..10_001).map(|i| i as f64).collect();
+    z.par_apply(|x| x.sqrt());
+    z.par_apply_zip(&vec![1.; 10_001], |x, y| x * x + y);
+    assert_eq!(z[10_000], 10_001.);
+    assert_eq!(z.par_dot_deterministic(&vec![1.; 10_001]), (0..10_001).map(|i| i as f64 + 1.).sum::<f64>());
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+pub fn ndarray () {
+    use llml_simd::ndarray::*;
+    use ndarray::{Array1, Array2, s};
+
+    let x = Array1::from_iter((0..21).map(|i| i as f32));
+    let y = Array1::from_elem(21, 2f32);
+    assert_eq!(x.simd_sum(), 210.);
+    assert_eq!(x.simd_dot(&y), 420.);
+    assert_eq!(x.slice(s![..;-2]).simd_sum(), 110.);
+    assert_eq!(x.slice(s![..;2]).simd_dot(&y.slice(s![..11])), 220.);
+    assert_eq!(x.simd_map(|x| x * 2.), &x * 2.);
+
+    let mut z = x.clone();
+    z.simd_axpy(0.5, &y);
+    assert_eq!(z, &x + 1.);
+    z.slice_mut(s![..;3]).simd_map_inplace(|x| -x);
+    assert_eq!(z[3], -4.);
+    assert_eq!(z[4], 5.);
+
+    let mut m = Array2::from_shape_fn((3, 10), |(i, j)| (i * 10 + j) as f32);
+    assert_eq!(m.simd_row_sums().to_vec(), vec![45., 145., 245.]);
+    assert_eq!(m.t().simd_row_sums(), m.sum_axis(ndarray::Axis(0)));
+    assert_eq!(m.simd_row_dots(&Array1::from_elem(10, 1.)).to_vec(), vec![45., 145., 245.]);
+
+    m.simd_axpy_rows(-1., &Array1::from_iter((0..10).map(|i| i as f32)));
+    assert_eq!(m.column(9).to_vec(), vec![0., 10., 20.]);
+    m.view_mut().reversed_axes().simd_map_inplace(|x| x + 1.);
+    assert_eq!(m.simd_map(|x| x * 0.), Array2::<f32>::zeros((3, 10)));
+    assert_eq!(m[[2, 4]], 21.);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+pub fn glam () {
+    let x = f32x4::new([1., 2., 3., 4.]);
+    let y : glam::Vec4 = x.into();
+    assert_eq!(y, glam::Vec4::new(1., 2., 3., 4.));
+    assert_eq!(f32x4::from(y * 2.), x * 2.);
+
+    assert_eq!(f32x3::from(glam::Vec3A::new(1., 2., 3.)), f32x3::new([1., 2., 3.]));
+    assert_eq!(glam::Vec3A::from(f32x3::new([1., 2., 3.])).length_squared(), 14.);
+    assert_eq!(glam::Vec2::from(f32x2::new([5., 6.])), glam::Vec2::new(5., 6.));
+    assert_eq!(f64x4::from(glam::DVec4::new(1., 2., 3., 4.)).sum(), 10.);
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+pub fn nalgebra () {
+    let x = f32x4::new([1., 2., 3., 4.]);
+    let y : nalgebra::Vector4<f32> = x.into();
+    assert_eq!(y, nalgebra::Vector4::new(1., 2., 3., 4.));
+    assert_eq!(f32x4::from(y * 2.), x * 2.);
+
+    assert_eq!(f32x3::from(nalgebra::Vector3::new(1., 2., 3.)), f32x3::new([1., 2., 3.]));
+    assert_eq!(nalgebra::Vector2::from(f32x2::new([5., 6.])), nalgebra::Vector2::new(5., 6.));
+    assert_eq!(f64x4::from(nalgebra::Vector4::new(1., 2., 3., 4.)).sum(), 10.);
+}
+
+#[cfg(all(feature = "portable_simd", not(feature = "force_naive")))]
+#[test]
+pub fn portable () {
+    use llml_simd::LlmlImpl;
+    use llml_simd::int::*;
+
+    assert!(matches!(LlmlImpl::CURRENT, LlmlImpl::PORTABLE));
+    assert!(!LlmlImpl::CURRENT.is_64bit() && !LlmlImpl::CURRENT.is_128bit() && !LlmlImpl::CURRENT.is_256bit());
+
+    let x = f32x6::new([1., -2., 3., -4., 5., -6.]);
+    assert_eq!(x.abs().sum(), 21.);
+    assert_eq!(x.zip(-x).into_array(), [1., -1., -2., 2., 3., -3.]);
+    assert_eq!(x.step(f32x6::default()).into_array(), [1., 0., 1., 0., 1., 0.]);
+
+    let y = i64x4::new([1, -2, 3, -4]);
+    assert_eq!((y >> 1).into_array(), [0, -1, 1, -2]);
+    assert_eq!(y.vlt(i64x4::default()).into_array(), [0, -1, 0, -1]);
+    assert_eq!(u8x16::from(200).saturating_add(u8x16::from(100)), u8x16::from(255));
+    assert_eq!(i32x4::new([1, 2, 3, 4]).narrow(i32x4::from(100_000)).into_array(), [1, 2, 3, 4, 32767, 32767, 32767, 32767]);
+}
+
+#[cfg(feature = "conformance")]
+#[test]
+pub fn conformance () {
+    use llml_simd::conformance::*;
+
+    if let Err(e) = check_all(1000) {
+        panic!("{e}")
+    }
+
+    // the first pair of edge cases that tells both apart is `inf` and `inf`
+    let failure = check::<f32, f32x4, f32x4, 4, 2>("add", Inputs::Unit, Tolerance::Exact, Allow::Nothing, 10, |[x, y]| x + y, |[x, y]| x - y).unwrap_err();
+    assert_eq!(failure.lane, Some(3));
+    assert_eq!(failure.inputs, vec![f64::INFINITY, f64::INFINITY]);
+    assert!(failure.expected.is_nan());
+    assert_eq!(failure.actual, f64::INFINITY);
+    assert!(failure.to_string().starts_with("llml_simd::"));
+}
+
+#[cfg(all(not(feature = "force_naive"), any(
+    feature = "portable_simd",
+    all(any(target_arch = "x86", target_arch = "x86_64"), target_feature = "sse"),
+    all(any(target_arch = "arm", target_arch = "aarch64"), target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128")
+)))]
+#[test]
+pub fn backends () {
+    use llml_simd::backend::naive;
+
+    let alpha = naive::f32x4::new([1., 2., 3., 4.]);
+    let beta : f32x4 = alpha.into();
+    assert_eq!(beta.into_array(), alpha.into_array());
+    assert_eq!(naive::f32x4::from(beta * beta).into_array(), (alpha * alpha).into_array());
+
+    let alpha = f64x6::new([1., 2., 3., 4., 5., 6.]);
+    let beta = naive::f64x6::from(alpha);
+    assert_eq!(beta.sum(), alpha.sum());
+}
+
+#[cfg(all(feature = "deterministic", feature = "use_std"))]
+#[test]
+pub fn deterministic () {
+    test_deterministic!(
+        [f32;2] as f32x2,
+        [f32;3] as f32x3,
+        [f32;4] as f32x4,
+        [f32;6] as f32x6,
+        [f32;8] as f32x8,
+        [f32;10] as f32x10,
+        [f32;12] as f32x12,
+        [f32;14] as f32x14,
+        [f32;16] as f32x16,
+        [f32;24] as f32x24,
+        [f32;32] as f32x32,
+
+        [f64;2] as f64x2,
+        [f64;3] as f64x3,
+        [f64;4] as f64x4,
+        [f64;6] as f64x6,
+        [f64;8] as f64x8,
+        [f64;10] as f64x10,
+        [f64;12] as f64x12,
+        [f64;14] as f64x14,
+        [f64;16] as f64x16,
+        [f64;24] as f64x24,
+        [f64;32] as f64x32
+    );
+
+    // the same inputs give the same bits regardless of how the lanes are split into registers
+    let x = f32x8::new([1e8, 1., -1e8, 1., 3., 1e-8, -3., 7.]);
+    assert_eq!(x.sum().to_bits(), (((1e8f32 + 1.) + (-1e8 + 1.)) + ((3. + 1e-8) + (-3. + 7.))).to_bits());
+    assert!(f32x4::new([1., f32::NAN, 2., 3.]).min().is_nan());
+    assert_eq!(f32x2::new([0., -0.]).min().to_bits(), (-0f32).to_bits());
+}